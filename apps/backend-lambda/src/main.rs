@@ -2,8 +2,50 @@ use lambda_http::{run, service_fn, Body, Error, Request, RequestExt, Response};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use tracing::info;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// 起動時に一度だけ環境変数から読み込む設定
+#[derive(Debug, Clone)]
+struct Config {
+    /// 実行環境（dev / staging / prod）
+    environment: String,
+    /// CORS許可するオリジン（フロントエンドURL）
+    allowed_origin: String,
+}
+
+impl Config {
+    /// 環境変数から設定を読み込んで検証する
+    ///
+    /// 💡 prod で ALLOWED_ORIGIN が未設定の場合は "*" にフォールバックせず起動を中止する
+    fn from_env() -> Result<Self, String> {
+        let environment = std::env::var("ENVIRONMENT")
+            .map_err(|_| "ENVIRONMENT must be set (dev, staging or prod)".to_string())?;
+        if !["dev", "staging", "prod"].contains(&environment.as_str()) {
+            return Err(format!(
+                "ENVIRONMENT must be one of dev, staging, prod (got '{}')",
+                environment
+            ));
+        }
+
+        let allowed_origin = match std::env::var("ALLOWED_ORIGIN") {
+            Ok(origin) if !origin.trim().is_empty() => origin.trim().to_string(),
+            _ if environment == "prod" => {
+                return Err("ALLOWED_ORIGIN must be set in prod".to_string());
+            }
+            _ => "*".to_string(),
+        };
+        if environment == "prod" && allowed_origin == "*" {
+            return Err("ALLOWED_ORIGIN must not be '*' in prod".to_string());
+        }
+
+        Ok(Self {
+            environment,
+            allowed_origin,
+        })
+    }
+}
+
 /// Hello World APIのレスポンス構造
 #[derive(Serialize, Deserialize)]
 struct HelloResponse {
@@ -14,12 +56,12 @@ struct HelloResponse {
 
 /// メインのハンドラー関数
 /// Lambda関数が呼び出されるたびに実行される
-async fn function_handler(event: Request) -> Result<Response<Body>, Error> {
+async fn function_handler(event: Request, config: &Config) -> Result<Response<Body>, Error> {
     // リクエスト情報をログに記録
     info!("Handling request: {:?}", event.uri());
     
-    // 環境変数から環境名を取得（dev/prod）
-    let environment = std::env::var("ENVIRONMENT").unwrap_or_else(|_| "unknown".to_string());
+    // 環境名（dev/staging/prod）
+    let environment = config.environment.clone();
     
     // パスパラメータやクエリパラメータを取得する例
     let path = event.uri().path();
@@ -62,9 +104,7 @@ async fn function_handler(event: Request) -> Result<Response<Body>, Error> {
     };
 
     // CORS対応のレスポンスヘッダーを設定
-    // 環境変数からフロントエンドURLを取得
-    let allowed_origin = std::env::var("ALLOWED_ORIGIN")
-        .unwrap_or_else(|_| "*".to_string());
+    let allowed_origin = config.allowed_origin.as_str();
     
     let response = Response::builder()
         .status(if path == "/" || path == "/hello" || path == "/health" { 200 } else { 404 })
@@ -109,6 +149,14 @@ async fn main() -> Result<(), Error> {
 
     info!("🦀 Starting Rust Lambda Function...");
 
+    // 設定の読み込み（不正な設定ならここで起動を中止）
+    let config = Arc::new(Config::from_env()?);
+    info!("⚙️ Environment: {}", config.environment);
+
     // Lambda Runtimeを起動
-    run(service_fn(function_handler)).await
+    run(service_fn(move |event| {
+        let config = Arc::clone(&config);
+        async move { function_handler(event, &config).await }
+    }))
+    .await
 }
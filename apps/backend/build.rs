@@ -0,0 +1,4 @@
+fn main() {
+    // migrations/ にファイルを追加・変更したら sqlx::migrate! を再コンパイルする
+    println!("cargo:rerun-if-changed=migrations");
+}
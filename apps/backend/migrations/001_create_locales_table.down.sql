@@ -0,0 +1,5 @@
+-- ============================================================
+-- Migration 001 (revert): Localesテーブル削除
+-- ============================================================
+-- 💡 インデックス・コメントはテーブルと一緒に削除される
+DROP TABLE IF EXISTS locales;
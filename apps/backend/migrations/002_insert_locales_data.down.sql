@@ -0,0 +1,6 @@
+-- ============================================================
+-- Migration 002 (revert): 初期データ（ja, en）の削除
+-- ============================================================
+-- 💡 他のテーブルから参照されている場合は外部キー制約で失敗する
+--   （先に 003 以降を revert すること）
+DELETE FROM locales WHERE code IN ('ja', 'en');
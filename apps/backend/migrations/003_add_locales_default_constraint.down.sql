@@ -0,0 +1,5 @@
+-- ============================================================
+-- Migration 003 (revert): デフォルト言語の制約を削除
+-- ============================================================
+ALTER TABLE locales DROP CONSTRAINT IF EXISTS chk_locales_default_is_active;
+DROP INDEX IF EXISTS idx_locales_single_default;
@@ -0,0 +1,53 @@
+-- ============================================================
+-- Migration 003: デフォルト言語を1つに制限する制約を追加
+-- ============================================================
+-- 目的: is_default = TRUE の行が常に1つだけになるよう保証する
+-- 作成日: 2026-02-17
+-- ============================================================
+
+-- ============================================================
+-- 💡 なぜ制約が必要?
+-- ============================================================
+-- Locales管理API（POST/PATCH）で言語を追加・更新できるようになったため、
+-- アプリケーション側のバグや同時リクエストで「デフォルト言語が2つ」
+-- という状態が起こりうる。
+--
+-- データベース側で制約を付けておけば、どんなコードから更新されても
+-- 2つ目の is_default = TRUE は必ずエラーになる。
+-- ============================================================
+
+-- ============================================================
+-- 部分ユニークインデックス
+-- ============================================================
+-- WHERE is_default を付けると、is_default = TRUE の行だけが
+-- インデックスの対象になる。
+--
+-- 💡 結果:
+-- - is_default = TRUE の行は最大1つ
+-- - is_default = FALSE の行はいくつでもOK
+CREATE UNIQUE INDEX idx_locales_single_default
+    ON locales (is_default)
+    WHERE is_default;
+
+-- ============================================================
+-- デフォルト言語は必ず有効
+-- ============================================================
+-- 無効化された言語がデフォルトだと、ユーザーに表示できる言語が
+-- 存在しなくなるため、CHECK制約で禁止する。
+ALTER TABLE locales
+    ADD CONSTRAINT chk_locales_default_is_active
+    CHECK (NOT is_default OR is_active);
+
+-- ============================================================
+-- 💡 デフォルト言語の切り替え方
+-- ============================================================
+-- 制約があるため、「新しい言語をTRUEにしてから古い言語をFALSEにする」
+-- 順番だとエラーになる。必ず1つのトランザクションで:
+--
+-- BEGIN;
+-- UPDATE locales SET is_default = FALSE WHERE is_default = TRUE;
+-- UPDATE locales SET is_default = TRUE  WHERE code = 'en';
+-- COMMIT;
+--
+-- API経由なら POST /api/v1/locales/{code}/default がこれを行う。
+-- ============================================================
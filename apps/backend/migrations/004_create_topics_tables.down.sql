@@ -0,0 +1,6 @@
+-- ============================================================
+-- Migration 004 (revert): Topics + Topic_Translations 削除
+-- ============================================================
+-- 💡 子テーブル（翻訳）から先に削除する
+DROP TABLE IF EXISTS topic_translations;
+DROP TABLE IF EXISTS topics;
@@ -0,0 +1,88 @@
+-- ============================================================
+-- Migration 004: Topics + Topic_Translationsテーブル作成
+-- ============================================================
+-- 目的: 技術トピック（Rust、React、Dockerなど）を多言語で管理
+-- 作成日: 2026-02-24
+-- 設計: docs/database-schema-v2.md「2. Topics + Topic_Translations」
+-- ============================================================
+
+-- ============================================================
+-- 💡 親テーブル + 翻訳テーブル パターン
+-- ============================================================
+-- topics             : 言語に依存しない情報（slug、公開フラグ）
+-- topic_translations : 言語ごとの情報（表示名）
+--
+-- topic_id: 1 (slug: rust)
+--   ├── locale_id: 1 (ja) → name: "Rust"
+--   └── locale_id: 2 (en) → name: "Rust"
+--
+-- 💡 メリット:
+-- - 言語を追加してもテーブル定義を変更しなくてよい
+-- - 「この言語の翻訳がない」ことを判定できる
+-- ============================================================
+
+-- ============================================================
+-- 1. topics（親テーブル）
+-- ============================================================
+CREATE TABLE topics (
+    topic_id            SERIAL PRIMARY KEY,
+    -- URL用の識別子（rust, react, docker）
+    slug                VARCHAR(100) UNIQUE NOT NULL,
+    is_published        BOOLEAN DEFAULT TRUE NOT NULL,
+    -- 将来: administrators(admin_id) への外部キー
+    created_by          INTEGER,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+-- ============================================================
+-- 2. topic_translations（翻訳テーブル）
+-- ============================================================
+-- 💡 ON DELETE CASCADE : トピックを削除すると翻訳も削除される
+-- 💡 ON DELETE RESTRICT: 翻訳が残っている言語は削除できない
+-- 💡 UNIQUE(topic_id, locale_id): 1トピック × 1言語 = 翻訳は1つだけ
+CREATE TABLE topic_translations (
+    translation_id      SERIAL PRIMARY KEY,
+    topic_id            INTEGER NOT NULL REFERENCES topics(topic_id) ON DELETE CASCADE,
+    locale_id           INTEGER NOT NULL REFERENCES locales(locale_id) ON DELETE RESTRICT,
+    name                VARCHAR(100) NOT NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(topic_id, locale_id)
+);
+
+-- ============================================================
+-- インデックス
+-- ============================================================
+-- 💡 UNIQUE(topic_id, locale_id) は topic_id から始まる検索に使える
+-- 💡 locale_id 単体の検索（言語ごとの翻訳数など）用に追加
+CREATE INDEX idx_topic_translations_locale_id ON topic_translations(locale_id);
+CREATE INDEX idx_topics_is_published ON topics(is_published);
+
+COMMENT ON TABLE topics IS
+'技術トピック（親テーブル）: 言語に依存しない情報';
+
+COMMENT ON TABLE topic_translations IS
+'技術トピックの翻訳: 言語ごとの表示名';
+
+-- ============================================================
+-- 💡 よく使うクエリ
+-- ============================================================
+--
+-- 【1】日本語のトピック名一覧（JOIN）
+-- SELECT t.slug, tt.name
+-- FROM topics t
+-- JOIN topic_translations tt ON tt.topic_id = t.topic_id
+-- JOIN locales l ON l.locale_id = tt.locale_id
+-- WHERE l.code = 'ja';
+--
+-- 【2】英語の翻訳がないトピック
+-- SELECT t.slug
+-- FROM topics t
+-- WHERE NOT EXISTS (
+--     SELECT 1 FROM topic_translations tt
+--     JOIN locales l ON l.locale_id = tt.locale_id
+--     WHERE tt.topic_id = t.topic_id AND l.code = 'en'
+-- );
+-- ============================================================
@@ -0,0 +1,7 @@
+-- ============================================================
+-- Migration 005 (revert): Blog_Posts 関連テーブル削除
+-- ============================================================
+-- 💡 参照している側（中間テーブル・翻訳）から先に削除する
+DROP TABLE IF EXISTS blog_post_topics;
+DROP TABLE IF EXISTS blog_post_translations;
+DROP TABLE IF EXISTS blog_posts;
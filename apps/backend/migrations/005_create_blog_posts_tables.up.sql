@@ -0,0 +1,95 @@
+-- ============================================================
+-- Migration 005: Blog_Posts + Blog_Post_Translations + Blog_Post_Topics
+-- ============================================================
+-- 目的: ブログ記事を「親テーブル + 翻訳テーブル」で管理する
+-- 作成日: 2026-03-03
+-- 設計: docs/database-schema-v2.md「4. Blog_Posts + ...」
+-- ============================================================
+
+-- ============================================================
+-- 💡 旧設計（archive/old-migrations）との違い
+-- ============================================================
+-- 旧: posts テーブルに title_ja, title_en, content_ja, content_en ...
+--     → 言語を追加するたびにカラム追加が必要
+--
+-- 新: blog_posts（言語に依存しない情報）
+--     blog_post_translations（言語ごとのタイトル・本文）
+--     → localesに言語を追加するだけで、何言語でも対応できる
+-- ============================================================
+
+-- ============================================================
+-- 1. blog_posts（親テーブル）
+-- ============================================================
+-- 💡 book_id / chapter_id:
+-- - Books（Step 3）の実装時に外部キー制約を追加する
+CREATE TABLE blog_posts (
+    post_id                 SERIAL PRIMARY KEY,
+    slug                    VARCHAR(255) UNIQUE NOT NULL,
+    meta_image_url          VARCHAR(500),
+    estimated_reading_time  INTEGER,
+    book_id                 INTEGER,
+    chapter_id              INTEGER,
+    is_published            BOOLEAN DEFAULT FALSE NOT NULL,
+    views_count             INTEGER DEFAULT 0 NOT NULL,
+    -- この記事の原文の言語（翻訳がない場合のフォールバック先）
+    default_locale_id       INTEGER REFERENCES locales(locale_id),
+    -- 将来: administrators(admin_id) への外部キー
+    created_by              INTEGER,
+    last_updated_by         INTEGER,
+    created_at              TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at              TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    published_at            TIMESTAMP WITH TIME ZONE
+);
+
+-- ============================================================
+-- 2. blog_post_translations（翻訳テーブル）
+-- ============================================================
+-- 💡 content は Markdown 形式
+CREATE TABLE blog_post_translations (
+    translation_id      SERIAL PRIMARY KEY,
+    post_id             INTEGER NOT NULL REFERENCES blog_posts(post_id) ON DELETE CASCADE,
+    locale_id           INTEGER NOT NULL REFERENCES locales(locale_id) ON DELETE RESTRICT,
+    title               VARCHAR(255) NOT NULL,
+    summary             TEXT,
+    meta_title          VARCHAR(255),
+    meta_description    TEXT,
+    content             TEXT NOT NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(post_id, locale_id)
+);
+
+-- ============================================================
+-- 3. blog_post_topics（記事 × トピックの多対多）
+-- ============================================================
+-- 💡 中間テーブル:
+-- - 1つの記事に複数のトピック、1つのトピックに複数の記事
+CREATE TABLE blog_post_topics (
+    post_topic_id       SERIAL PRIMARY KEY,
+    post_id             INTEGER NOT NULL REFERENCES blog_posts(post_id) ON DELETE CASCADE,
+    topic_id            INTEGER NOT NULL REFERENCES topics(topic_id) ON DELETE CASCADE,
+    assigned_at         TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(post_id, topic_id)
+);
+
+-- ============================================================
+-- インデックス
+-- ============================================================
+-- 💡 公開記事一覧（WHERE is_published ORDER BY published_at DESC）用
+CREATE INDEX idx_blog_posts_published_at
+    ON blog_posts(published_at DESC)
+    WHERE is_published;
+
+CREATE INDEX idx_blog_post_translations_locale_id ON blog_post_translations(locale_id);
+CREATE INDEX idx_blog_post_topics_topic_id ON blog_post_topics(topic_id);
+
+COMMENT ON TABLE blog_posts IS
+'ブログ記事（親テーブル）: 言語に依存しない情報';
+
+COMMENT ON TABLE blog_post_translations IS
+'ブログ記事の翻訳: 言語ごとのタイトル・要約・本文（Markdown）';
+
+COMMENT ON TABLE blog_post_topics IS
+'ブログ記事とトピックの中間テーブル（多対多）';
@@ -0,0 +1,8 @@
+-- ============================================================
+-- Migration 006 (revert): キーセット用インデックスを元に戻す
+-- ============================================================
+DROP INDEX IF EXISTS idx_blog_posts_published_keyset;
+
+CREATE INDEX idx_blog_posts_published_at
+    ON blog_posts(published_at DESC)
+    WHERE is_published;
@@ -0,0 +1,17 @@
+-- ============================================================
+-- Migration 006: 記事一覧のキーセットページネーション用インデックス
+-- ============================================================
+-- 💡 記事一覧の並び順:
+--   ORDER BY COALESCE(published_at, '-infinity') DESC, post_id DESC
+-- - 未公開（published_at が NULL）の記事を末尾に並べるため -infinity に置き換える
+-- - 同じ公開日時の記事は post_id で順序を固定する
+--
+-- 💡 次のページは「最後に見た行より後」をWHEREで絞る:
+--   WHERE (COALESCE(published_at, '-infinity'), post_id) < ($最後の公開日時, $最後のID)
+-- - ORDER BY と同じ式のインデックスがあれば、何ページ目でもインデックスを辿るだけで済む
+
+DROP INDEX IF EXISTS idx_blog_posts_published_at;
+
+CREATE INDEX idx_blog_posts_published_keyset
+    ON blog_posts ((COALESCE(published_at, '-infinity'::timestamptz)) DESC, post_id DESC)
+    WHERE is_published;
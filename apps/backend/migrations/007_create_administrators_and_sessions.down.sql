@@ -0,0 +1,9 @@
+-- ============================================================
+-- Migration 007 (revert): Administrators + Sessions テーブル削除
+-- ============================================================
+ALTER TABLE blog_posts DROP CONSTRAINT IF EXISTS blog_posts_last_updated_by_fkey;
+ALTER TABLE blog_posts DROP CONSTRAINT IF EXISTS blog_posts_created_by_fkey;
+ALTER TABLE topics DROP CONSTRAINT IF EXISTS topics_created_by_fkey;
+
+DROP TABLE IF EXISTS sessions;
+DROP TABLE IF EXISTS administrators;
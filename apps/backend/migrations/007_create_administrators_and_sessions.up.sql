@@ -0,0 +1,73 @@
+-- ============================================================
+-- Migration 007: Administrators + Sessions テーブル作成
+-- ============================================================
+-- 目的: 管理画面のログイン（管理者アカウントとサーバー側セッション）
+-- 設計: docs/database-schema-v2.md「6. Administrators + Sessions」
+-- ============================================================
+
+-- ============================================================
+-- 1. administrators（管理者）
+-- ============================================================
+-- 💡 password_hash:
+-- - Argon2id の PHC 文字列（$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>）
+-- - ソルトとパラメータも含むので、これだけで検証できる
+CREATE TABLE administrators (
+    admin_id            SERIAL PRIMARY KEY,
+    username            VARCHAR(100) UNIQUE NOT NULL,
+    email               VARCHAR(255) UNIQUE NOT NULL,
+    password_hash       VARCHAR(255) NOT NULL,
+    full_name           VARCHAR(200),
+    role                VARCHAR(50) DEFAULT 'author' NOT NULL,
+    is_active           BOOLEAN DEFAULT TRUE NOT NULL,
+    last_login_at       TIMESTAMP WITH TIME ZONE,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    CONSTRAINT administrators_role_check CHECK (role IN ('admin', 'editor', 'author'))
+);
+
+-- ============================================================
+-- 2. sessions（ログインセッション）
+-- ============================================================
+-- 💡 token_hash:
+-- - クライアントに渡すトークンそのものは保存しない（SHA-256 の16進文字列だけ保存）
+-- - DBが漏れても、そこからログイン状態を乗っ取れない
+--
+-- 💡 有効期限は2種類:
+-- - expires_at       : ログインからの絶対的な期限
+-- - last_activity_at : 最後に使われた時刻（一定時間使われなければ無効）
+CREATE TABLE sessions (
+    session_id          SERIAL PRIMARY KEY,
+    admin_id            INTEGER NOT NULL REFERENCES administrators(admin_id) ON DELETE CASCADE,
+    token_hash          CHAR(64) UNIQUE NOT NULL,
+    ip_address          VARCHAR(45),
+    user_agent          TEXT,
+    expires_at          TIMESTAMP WITH TIME ZONE NOT NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    last_activity_at    TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX idx_sessions_admin_id ON sessions(admin_id);
+CREATE INDEX idx_sessions_expires_at ON sessions(expires_at);
+
+-- ============================================================
+-- 3. 作成者・更新者の外部キー（管理者テーブルができたので追加）
+-- ============================================================
+-- 💡 ON DELETE SET NULL: 管理者を削除しても記事・トピックは残す
+ALTER TABLE topics
+    ADD CONSTRAINT topics_created_by_fkey
+    FOREIGN KEY (created_by) REFERENCES administrators(admin_id) ON DELETE SET NULL;
+
+ALTER TABLE blog_posts
+    ADD CONSTRAINT blog_posts_created_by_fkey
+    FOREIGN KEY (created_by) REFERENCES administrators(admin_id) ON DELETE SET NULL;
+
+ALTER TABLE blog_posts
+    ADD CONSTRAINT blog_posts_last_updated_by_fkey
+    FOREIGN KEY (last_updated_by) REFERENCES administrators(admin_id) ON DELETE SET NULL;
+
+COMMENT ON TABLE administrators IS
+'管理者アカウント: role は admin / editor / author';
+
+COMMENT ON TABLE sessions IS
+'ログインセッション: トークンは SHA-256 ハッシュのみ保存';
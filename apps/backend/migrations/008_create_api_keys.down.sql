@@ -0,0 +1,4 @@
+-- ============================================================
+-- Migration 008 (revert): API_Keys テーブル削除
+-- ============================================================
+DROP TABLE IF EXISTS api_keys;
@@ -0,0 +1,35 @@
+-- ============================================================
+-- Migration 008: API_Keys テーブル作成
+-- ============================================================
+-- 目的: 静的サイトジェネレーター・CI など、人がログインしないクライアントの認証
+-- 設計: docs/database-schema-v2.md「7. API_Keys」
+-- ============================================================
+
+-- 💡 api_key_hash:
+-- - 発行時にだけ平文のキーを返し、DBには SHA-256 の16進文字列だけ保存する
+-- - key_prefix はキーの先頭部分（一覧でどのキーか見分けるための表示用）
+--
+-- 💡 permissions:
+-- - 設計では VARCHAR(500) のカンマ区切りだったが、検索・検証しやすいよう TEXT[] にした
+-- - read（下書きの取得）/ write（作成・更新・公開）/ delete（削除）
+--
+-- 💡 無効化（revoke）しても行は削除しない（いつ・誰が使っていたか追えるように残す）
+CREATE TABLE api_keys (
+    api_key_id          SERIAL PRIMARY KEY,
+    key_name            VARCHAR(100) NOT NULL,
+    key_prefix          VARCHAR(20) NOT NULL,
+    api_key_hash        CHAR(64) UNIQUE NOT NULL,
+    permissions         TEXT[] NOT NULL DEFAULT '{}',
+    is_active           BOOLEAN DEFAULT TRUE NOT NULL,
+    expires_at          TIMESTAMP WITH TIME ZONE,
+    created_by          INTEGER REFERENCES administrators(admin_id) ON DELETE SET NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    last_used_at        TIMESTAMP WITH TIME ZONE,
+    revoked_at          TIMESTAMP WITH TIME ZONE,
+
+    CONSTRAINT api_keys_permissions_check
+        CHECK (permissions <@ ARRAY['read', 'write', 'delete']::TEXT[])
+);
+
+COMMENT ON TABLE api_keys IS
+'機械クライアント用のAPIキー: キーは SHA-256 ハッシュのみ保存、permissions は read / write / delete';
@@ -0,0 +1,4 @@
+-- ============================================================
+-- Migration 009 (revert): Audit_Logs テーブル削除
+-- ============================================================
+DROP TABLE IF EXISTS audit_logs;
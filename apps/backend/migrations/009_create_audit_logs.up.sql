@@ -0,0 +1,49 @@
+-- ============================================================
+-- Migration 009: Audit_Logs テーブル作成
+-- ============================================================
+-- 目的: 言語・トピック・記事・管理者・APIキーの変更を、誰が・いつ・何から何に変えたか記録する
+-- 設計: docs/database-schema-v2.md「8. Audit_Logs」
+-- ============================================================
+
+-- 💡 old_values / new_values:
+-- - 設計では TEXT（JSON形式）だったが、中身で検索できるよう JSONB にした
+-- - INSERT は old_values が NULL、DELETE は new_values が NULL
+-- - パスワード・APIキーのハッシュは含めない（リポジトリ側で取り除く）
+--
+-- 💡 changed_by / api_key_id:
+-- - ログインした管理者の操作: changed_by = 管理者, api_key_id = NULL
+-- - APIキーの操作: changed_by = キーを発行した管理者, api_key_id = キー
+-- - CLIの操作: どちらも NULL
+-- - 管理者・APIキーが削除されてもログは残す（ON DELETE SET NULL）
+--
+-- 💡 changed_via: web（ログインセッション）/ api（APIキー）/ cli（backend コマンド）
+CREATE TABLE audit_logs (
+    log_id              SERIAL PRIMARY KEY,
+    table_name          VARCHAR(100) NOT NULL,
+    record_id           INTEGER NOT NULL,
+    action              VARCHAR(50) NOT NULL,
+    old_values          JSONB,
+    new_values          JSONB,
+    changed_by          INTEGER REFERENCES administrators(admin_id) ON DELETE SET NULL,
+    api_key_id          INTEGER REFERENCES api_keys(api_key_id) ON DELETE SET NULL,
+    changed_via         VARCHAR(50) NOT NULL,
+    ip_address          VARCHAR(45),
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    CONSTRAINT audit_logs_action_check
+        CHECK (action IN ('INSERT', 'UPDATE', 'DELETE')),
+    CONSTRAINT audit_logs_changed_via_check
+        CHECK (changed_via IN ('web', 'api', 'cli'))
+);
+
+-- 💡 一覧は新しい順（created_at DESC, log_id DESC）のキーセット方式で読む
+CREATE INDEX idx_audit_logs_created_at ON audit_logs (created_at DESC, log_id DESC);
+
+-- 💡 「この言語を誰が変えたか」: table_name + record_id で絞り込む
+CREATE INDEX idx_audit_logs_record ON audit_logs (table_name, record_id, created_at DESC);
+
+-- 💡 「この管理者が何を変えたか」
+CREATE INDEX idx_audit_logs_changed_by ON audit_logs (changed_by, created_at DESC);
+
+COMMENT ON TABLE audit_logs IS
+'変更履歴の監査ログ: 変更と同じトランザクションで記録、old_values / new_values は変更前後のJSONスナップショット';
@@ -0,0 +1,14 @@
+-- ============================================================
+-- Migration 010 (revert): Books 関連テーブル削除
+-- ============================================================
+ALTER TABLE blog_posts
+    DROP CONSTRAINT IF EXISTS blog_posts_chapter_requires_book,
+    DROP CONSTRAINT IF EXISTS blog_posts_chapter_fkey,
+    DROP CONSTRAINT IF EXISTS blog_posts_book_id_fkey;
+
+DROP INDEX IF EXISTS idx_blog_posts_chapter_id;
+
+DROP TABLE IF EXISTS book_chapter_translations;
+DROP TABLE IF EXISTS book_chapters;
+DROP TABLE IF EXISTS book_translations;
+DROP TABLE IF EXISTS books;
@@ -0,0 +1,129 @@
+-- ============================================================
+-- Migration 010: Books + Book_Translations + Book_Chapters + Book_Chapter_Translations
+-- ============================================================
+-- 目的: 本（チュートリアルなど）と、入れ子にできるチャプターを多言語で管理する
+-- 設計: docs/database-schema-v2.md「3. Books + ...」
+-- ============================================================
+
+-- ============================================================
+-- 💡 チャプターの階層構造（隣接リスト）
+-- ============================================================
+-- book_id: 1 (rust-tutorial)
+--   ├── chapter 1 (getting-started)          parent_chapter_id = NULL, chapter_number = 1
+--   │     ├── chapter 1-1 (installation)     parent_chapter_id = 1,    chapter_number = 1
+--   │     └── chapter 1-2 (hello-world)      parent_chapter_id = 1,    chapter_number = 2
+--   └── chapter 2 (ownership)                parent_chapter_id = NULL, chapter_number = 2
+--
+-- 💡 chapter_number は「同じ親の中での順番」（1始まり）
+-- - 設計では UNIQUE(book_id, chapter_number) だったが、入れ子・並び替えをしやすいよう
+--   (book_id, parent_chapter_id, chapter_number) で一意にした
+-- - ツリー全体は WITH RECURSIVE で1回のクエリで取得する（BookRepository::find_chapter_tree）
+-- ============================================================
+
+-- ============================================================
+-- 1. books（親テーブル）
+-- ============================================================
+CREATE TABLE books (
+    book_id             SERIAL PRIMARY KEY,
+    slug                VARCHAR(255) UNIQUE NOT NULL,
+    emoji               VARCHAR(10),
+    top_image_url       VARCHAR(500),
+    display_order       INTEGER,
+    is_published        BOOLEAN DEFAULT FALSE NOT NULL,
+    -- この本の原文の言語（翻訳がない場合のフォールバック先）
+    default_locale_id   INTEGER REFERENCES locales(locale_id),
+    created_by          INTEGER REFERENCES administrators(admin_id) ON DELETE SET NULL,
+    last_updated_by     INTEGER REFERENCES administrators(admin_id) ON DELETE SET NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    published_at        TIMESTAMP WITH TIME ZONE
+);
+
+-- ============================================================
+-- 2. book_translations（翻訳テーブル）
+-- ============================================================
+CREATE TABLE book_translations (
+    translation_id      SERIAL PRIMARY KEY,
+    book_id             INTEGER NOT NULL REFERENCES books(book_id) ON DELETE CASCADE,
+    locale_id           INTEGER NOT NULL REFERENCES locales(locale_id) ON DELETE RESTRICT,
+    title               VARCHAR(255) NOT NULL,
+    introduction        TEXT,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(book_id, locale_id)
+);
+
+-- ============================================================
+-- 3. book_chapters（自己参照でツリーにする）
+-- ============================================================
+-- 💡 FOREIGN KEY (book_id, parent_chapter_id):
+-- - 親チャプターが同じ本に属することをDBで保証する（そのため UNIQUE(book_id, chapter_id) が必要）
+-- - 親を削除すると子孫もまとめて削除する（設計の SET NULL だと子がトップレベルに移り、順番が重複するため）
+--
+-- 💡 book_chapters_position_key:
+-- - NULLS NOT DISTINCT: トップレベル（parent_chapter_id = NULL）同士でも順番の重複を禁止する
+-- - DEFERRABLE INITIALLY DEFERRED: 並び替え中の一時的な重複を許し、COMMIT 時にチェックする
+CREATE TABLE book_chapters (
+    chapter_id          SERIAL PRIMARY KEY,
+    book_id             INTEGER NOT NULL REFERENCES books(book_id) ON DELETE CASCADE,
+    chapter_number      INTEGER NOT NULL CHECK (chapter_number > 0),
+    slug                VARCHAR(255) NOT NULL,
+    parent_chapter_id   INTEGER,
+    is_published        BOOLEAN DEFAULT FALSE NOT NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(book_id, slug),
+    UNIQUE(book_id, chapter_id),
+    FOREIGN KEY (book_id, parent_chapter_id)
+        REFERENCES book_chapters(book_id, chapter_id) ON DELETE CASCADE,
+    CONSTRAINT book_chapters_position_key
+        UNIQUE NULLS NOT DISTINCT (book_id, parent_chapter_id, chapter_number)
+        DEFERRABLE INITIALLY DEFERRED
+);
+
+-- ============================================================
+-- 4. book_chapter_translations（翻訳テーブル）
+-- ============================================================
+CREATE TABLE book_chapter_translations (
+    translation_id      SERIAL PRIMARY KEY,
+    chapter_id          INTEGER NOT NULL REFERENCES book_chapters(chapter_id) ON DELETE CASCADE,
+    locale_id           INTEGER NOT NULL REFERENCES locales(locale_id) ON DELETE RESTRICT,
+    title               VARCHAR(255) NOT NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(chapter_id, locale_id)
+);
+
+-- ============================================================
+-- 5. blog_posts の book_id / chapter_id に外部キーを追加
+-- ============================================================
+-- 💡 migration 005 で「Books の実装時に追加する」としていた制約
+-- - (book_id, chapter_id) の複合外部キーで、チャプターが同じ本に属することを保証する
+-- - チャプターを削除したら chapter_id だけ NULL にする（SET NULL (chapter_id)、PostgreSQL 15+）
+ALTER TABLE blog_posts
+    ADD CONSTRAINT blog_posts_book_id_fkey
+        FOREIGN KEY (book_id) REFERENCES books(book_id) ON DELETE SET NULL,
+    ADD CONSTRAINT blog_posts_chapter_fkey
+        FOREIGN KEY (book_id, chapter_id)
+        REFERENCES book_chapters(book_id, chapter_id) ON DELETE SET NULL (chapter_id),
+    ADD CONSTRAINT blog_posts_chapter_requires_book
+        CHECK (chapter_id IS NULL OR book_id IS NOT NULL);
+
+-- ============================================================
+-- インデックス
+-- ============================================================
+CREATE INDEX idx_book_translations_locale_id ON book_translations(locale_id);
+CREATE INDEX idx_book_chapter_translations_locale_id ON book_chapter_translations(locale_id);
+-- 💡 子チャプターをたどる（WITH RECURSIVE の JOIN）
+CREATE INDEX idx_book_chapters_parent ON book_chapters(parent_chapter_id);
+-- 💡 チャプターに紐づく記事の一覧
+CREATE INDEX idx_blog_posts_chapter_id ON blog_posts(chapter_id) WHERE chapter_id IS NOT NULL;
+
+COMMENT ON TABLE books IS
+'本（親テーブル）: 言語に依存しない情報';
+
+COMMENT ON TABLE book_chapters IS
+'本のチャプター: parent_chapter_id による入れ子、chapter_number は同じ親の中での順番';
@@ -0,0 +1,10 @@
+-- ============================================================
+-- Migration 011 (revert): Media 関連テーブル削除
+-- ============================================================
+-- 💡 ストレージに保存したファイル本体は削除しない（必要なら手動で消す）
+DROP INDEX IF EXISTS idx_blog_posts_featured_image_id;
+
+ALTER TABLE blog_posts DROP COLUMN IF EXISTS featured_image_id;
+
+DROP TABLE IF EXISTS media_translations;
+DROP TABLE IF EXISTS media;
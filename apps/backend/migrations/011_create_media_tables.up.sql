@@ -0,0 +1,74 @@
+-- ============================================================
+-- Migration 011: Media + Media_Translations
+-- ============================================================
+-- 目的: アップロードした画像・ファイルと、言語ごとの代替テキスト・キャプションを管理する
+-- 設計: docs/database-schema-v2.md「5. Media + Media_Translations」
+-- ============================================================
+
+-- ============================================================
+-- 💡 ファイル本体はデータベースに入れない
+-- ============================================================
+-- - 本体はストレージ（storage::MediaStorage、今はローカルディスク）に保存する
+-- - file_path はストレージ内のキー（例: 2026/10/3f2a....png）
+-- - 公開URLは「media.public_base_url + / + file_path」で組み立てる（保存しない）
+--   → ストレージやCDNを変えてもURLを書き換えるだけで済む
+-- ============================================================
+
+-- ============================================================
+-- 1. media（親テーブル）
+-- ============================================================
+-- 💡 設計からの変更:
+-- - file_type / file_size は NOT NULL（アップロード時に必ず判定・計測する）
+-- - file_path は UNIQUE（1つのファイルを2つの行が指さないように）
+CREATE TABLE media (
+    media_id            SERIAL PRIMARY KEY,
+    filename            VARCHAR(255) NOT NULL,
+    original_filename   VARCHAR(255) NOT NULL,
+    file_path           VARCHAR(500) UNIQUE NOT NULL,
+    file_type           VARCHAR(100) NOT NULL,
+    file_size           BIGINT NOT NULL CHECK (file_size >= 0),
+    width               INTEGER CHECK (width > 0),
+    height              INTEGER CHECK (height > 0),
+    uploaded_by         INTEGER REFERENCES administrators(admin_id) ON DELETE SET NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL
+);
+
+-- ============================================================
+-- 2. media_translations（翻訳テーブル）
+-- ============================================================
+CREATE TABLE media_translations (
+    translation_id      SERIAL PRIMARY KEY,
+    media_id            INTEGER NOT NULL REFERENCES media(media_id) ON DELETE CASCADE,
+    locale_id           INTEGER NOT NULL REFERENCES locales(locale_id) ON DELETE RESTRICT,
+    alt_text            VARCHAR(255),
+    caption             TEXT,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+    updated_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(media_id, locale_id)
+);
+
+-- ============================================================
+-- 3. blog_posts.featured_image_id（アイキャッチ画像）
+-- ============================================================
+-- 💡 旧Post（archive/old-code）にあった featured_image_id を media への外部キーとして追加
+-- - ON DELETE RESTRICT: 記事で使われている画像は削除できない
+ALTER TABLE blog_posts
+    ADD COLUMN featured_image_id INTEGER
+        CONSTRAINT blog_posts_featured_image_id_fkey REFERENCES media(media_id) ON DELETE RESTRICT;
+
+-- ============================================================
+-- インデックス
+-- ============================================================
+-- 💡 一覧（新しい順、キーセット方式）
+CREATE INDEX idx_media_created_at ON media(created_at DESC, media_id DESC);
+CREATE INDEX idx_media_translations_locale_id ON media_translations(locale_id);
+-- 💡 削除前の参照チェック（この画像をアイキャッチにしている記事）
+CREATE INDEX idx_blog_posts_featured_image_id ON blog_posts(featured_image_id)
+    WHERE featured_image_id IS NOT NULL;
+
+COMMENT ON TABLE media IS
+'アップロードしたファイル: 本体はストレージに保存し、ここにはメタ情報だけを持つ';
+
+COMMENT ON COLUMN media.file_path IS
+'ストレージ内のキー（公開URLは media.public_base_url と組み合わせて作る）';
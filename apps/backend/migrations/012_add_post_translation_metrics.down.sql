@@ -0,0 +1,6 @@
+-- ============================================================
+-- Migration 012 (revert): 翻訳ごとの読了目安・抜粋を削除
+-- ============================================================
+ALTER TABLE blog_post_translations
+    DROP COLUMN IF EXISTS excerpt,
+    DROP COLUMN IF EXISTS reading_time_minutes;
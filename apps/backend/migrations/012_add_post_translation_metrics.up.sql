@@ -0,0 +1,26 @@
+-- ============================================================
+-- Migration 012: 翻訳ごとの読了目安・抜粋
+-- ============================================================
+-- 目的: 翻訳の保存時にバックエンドで計算した読了目安（分）と抜粋を持つ
+-- 作成日: 2026-10-18
+-- ============================================================
+
+-- ============================================================
+-- 💡 旧設計（archive/old-code）との違い
+-- ============================================================
+-- 旧: posts.reading_time_ja / reading_time_en / excerpt_ja / excerpt_en（手入力）
+-- 新: blog_post_translations.reading_time_minutes / excerpt（保存時に自動計算）
+--     → 日本語は文字数、英語などは単語数から計算する（locales.code で決める）
+--
+-- 💡 blog_posts.estimated_reading_time は残す
+-- - 翻訳の値がない（このマイグレーションより前に保存した）ときの代わりに使う
+-- ============================================================
+ALTER TABLE blog_post_translations
+    ADD COLUMN reading_time_minutes INTEGER CHECK (reading_time_minutes > 0),
+    ADD COLUMN excerpt              TEXT;
+
+COMMENT ON COLUMN blog_post_translations.reading_time_minutes IS
+'読了目安（分）: 翻訳の保存時に本文から計算する';
+
+COMMENT ON COLUMN blog_post_translations.excerpt IS
+'抜粋: 本文の最初の段落から作る。summary がないときの要約の代わり';
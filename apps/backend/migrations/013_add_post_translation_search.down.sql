@@ -0,0 +1,6 @@
+-- ============================================================
+-- Migration 013 (revert): 記事の全文検索を削除
+-- ============================================================
+DROP INDEX IF EXISTS idx_blog_post_translations_search_vector;
+
+ALTER TABLE blog_post_translations DROP COLUMN IF EXISTS search_vector;
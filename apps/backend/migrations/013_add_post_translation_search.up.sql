@@ -0,0 +1,27 @@
+-- ============================================================
+-- Migration 013: 記事の全文検索（blog_post_translations.search_vector）
+-- ============================================================
+-- 目的: GET /api/v1/search でタイトル・要約・本文を検索できるようにする
+-- 作成日: 2026-10-18
+-- ============================================================
+
+-- ============================================================
+-- 💡 search_vector の作り方（src/search.rs）
+-- ============================================================
+-- - 英語など: to_tsvector('english', ...) のように言語ごとの設定で単語・語幹に分ける
+-- - 日本語・中国語: 2文字ずつ（バイグラム）に分けた tsvector をアプリで作って保存する
+--   → pg_trgm は使わない（拡張機能の権限が要る・LC_CTYPE が C だと日本語を扱えない）
+-- - 重み: タイトル A、要約 B、本文 C
+--
+-- 💡 既存の翻訳は空の tsvector になる
+-- → `backend search reindex` で作り直す（翻訳を保存し直しても作られる）
+-- ============================================================
+ALTER TABLE blog_post_translations
+    ADD COLUMN search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector;
+
+-- 💡 GIN インデックス: tsvector の「語 → 行」の転置インデックス（@@ の検索に使う）
+CREATE INDEX idx_blog_post_translations_search_vector
+    ON blog_post_translations USING GIN (search_vector);
+
+COMMENT ON COLUMN blog_post_translations.search_vector IS
+'全文検索用: タイトル(A)・要約(B)・本文(C)。日本語・中国語は2文字ずつのバイグラム';
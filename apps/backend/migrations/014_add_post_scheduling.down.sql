@@ -0,0 +1,15 @@
+-- ============================================================
+-- Migration 014 (revert): 記事の予約公開を削除
+-- ============================================================
+-- 💡 scheduler の監査ログは cli として残す（ログ自体は消さない）
+UPDATE audit_logs SET changed_via = 'cli' WHERE changed_via = 'scheduler';
+
+ALTER TABLE audit_logs DROP CONSTRAINT audit_logs_changed_via_check;
+ALTER TABLE audit_logs ADD CONSTRAINT audit_logs_changed_via_check
+    CHECK (changed_via IN ('web', 'api', 'cli'));
+
+DROP INDEX IF EXISTS idx_blog_posts_scheduled_at;
+
+ALTER TABLE blog_posts
+    DROP CONSTRAINT IF EXISTS blog_posts_scheduled_check,
+    DROP COLUMN IF EXISTS scheduled_at;
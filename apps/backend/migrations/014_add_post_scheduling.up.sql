@@ -0,0 +1,37 @@
+-- ============================================================
+-- Migration 014: 記事の予約公開（blog_posts.scheduled_at）
+-- ============================================================
+-- 目的: 指定した日時に記事を自動で公開する（バックグラウンドの scheduler）
+-- 作成日: 2026-10-18
+-- ============================================================
+
+-- ============================================================
+-- 💡 旧設計（archive/old-code）との違い
+-- ============================================================
+-- 旧: posts.status（draft / published / scheduled / archived）+ scheduled_at
+--     → scheduled から published に変える処理がなかった
+-- 新: is_published と scheduled_at の組み合わせで状態を表す
+--     | is_published | scheduled_at | 状態     |
+--     |--------------|--------------|----------|
+--     | FALSE        | NULL         | 下書き   |
+--     | FALSE        | 日時         | 予約済み |
+--     | TRUE         | NULL         | 公開済み |
+--     → scheduler（src/scheduler.rs）が scheduled_at を過ぎた記事を公開する
+-- ============================================================
+ALTER TABLE blog_posts
+    ADD COLUMN scheduled_at TIMESTAMP WITH TIME ZONE,
+    ADD CONSTRAINT blog_posts_scheduled_check
+        CHECK (NOT (is_published AND scheduled_at IS NOT NULL));
+
+-- 💡 scheduler が「期限が来た予約」を探すためのインデックス（予約済みの記事だけ）
+CREATE INDEX idx_blog_posts_scheduled_at
+    ON blog_posts(scheduled_at)
+    WHERE scheduled_at IS NOT NULL;
+
+-- 💡 scheduler による変更を監査ログに記録する（changed_via = 'scheduler'）
+ALTER TABLE audit_logs DROP CONSTRAINT audit_logs_changed_via_check;
+ALTER TABLE audit_logs ADD CONSTRAINT audit_logs_changed_via_check
+    CHECK (changed_via IN ('web', 'api', 'cli', 'scheduler'));
+
+COMMENT ON COLUMN blog_posts.scheduled_at IS
+'予約公開の日時: この日時を過ぎると scheduler が公開する（公開済みの記事では NULL）';
@@ -0,0 +1,5 @@
+-- ============================================================
+-- Migration 015 (revert): 翻訳の変更履歴を削除
+-- ============================================================
+DROP TABLE IF EXISTS blog_post_translation_revisions;
+DROP FUNCTION IF EXISTS forbid_revision_content_update();
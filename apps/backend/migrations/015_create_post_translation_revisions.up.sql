@@ -0,0 +1,77 @@
+-- ============================================================
+-- Migration 015: Blog_Post_Translation_Revisions（翻訳の変更履歴）
+-- ============================================================
+-- 目的: 翻訳を保存するたびに、その時点のタイトル・本文などを1行として残す
+--       （上書きした本文を差分で確認し、古い版に戻せるようにする）
+-- 設計: docs/database-schema-v2.md「Blog_Post_Translation_Revisions」
+-- 作成日: 2026-10-18
+-- ============================================================
+
+-- 💡 audit_logs との違い:
+-- - audit_logs は記事全体のJSONスナップショット（誰が何を変えたかの記録）
+-- - revisions は翻訳ごとの版（本文の差分・復元に使う）
+--
+-- 💡 revision_number: 記事 + 言語ごとの通し番号（1, 2, 3...）
+-- 💡 restored_from: 古い版から復元した場合、その版の revision_number
+-- 💡 created_by / api_key_id: audit_logs と同じ（CLIの操作はどちらも NULL）
+CREATE TABLE blog_post_translation_revisions (
+    revision_id         SERIAL PRIMARY KEY,
+    post_id             INTEGER NOT NULL REFERENCES blog_posts(post_id) ON DELETE CASCADE,
+    locale_id           INTEGER NOT NULL REFERENCES locales(locale_id) ON DELETE RESTRICT,
+    revision_number     INTEGER NOT NULL CHECK (revision_number > 0),
+    title               VARCHAR(255) NOT NULL,
+    summary             TEXT,
+    meta_title          VARCHAR(255),
+    meta_description    TEXT,
+    content             TEXT NOT NULL,
+    restored_from       INTEGER,
+    created_by          INTEGER REFERENCES administrators(admin_id) ON DELETE SET NULL,
+    api_key_id          INTEGER REFERENCES api_keys(api_key_id) ON DELETE SET NULL,
+    created_at          TIMESTAMP WITH TIME ZONE DEFAULT NOW() NOT NULL,
+
+    UNIQUE(post_id, locale_id, revision_number)
+);
+
+-- ============================================================
+-- 版の内容は変更できない（トリガー）
+-- ============================================================
+-- 💡 タイトル・本文などを UPDATE しようとするとエラーにする
+-- - 作成者（created_by / api_key_id）は管理者の削除で NULL になるので対象外
+-- - 記事の削除（ON DELETE CASCADE）では版もまとめて消える
+CREATE FUNCTION forbid_revision_content_update() RETURNS TRIGGER AS $$
+BEGIN
+    IF (NEW.post_id, NEW.locale_id, NEW.revision_number, NEW.title, NEW.summary, NEW.meta_title,
+        NEW.meta_description, NEW.content, NEW.restored_from, NEW.created_at)
+       IS DISTINCT FROM
+       (OLD.post_id, OLD.locale_id, OLD.revision_number, OLD.title, OLD.summary, OLD.meta_title,
+        OLD.meta_description, OLD.content, OLD.restored_from, OLD.created_at)
+    THEN
+        RAISE EXCEPTION 'blog_post_translation_revisions are immutable (revision_id %)', OLD.revision_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER blog_post_translation_revisions_immutable
+    BEFORE UPDATE ON blog_post_translation_revisions
+    FOR EACH ROW EXECUTE FUNCTION forbid_revision_content_update();
+
+-- ============================================================
+-- 既存の翻訳を版1として登録
+-- ============================================================
+-- 💡 作成者は記事を最後に更新した管理者、日時は翻訳の更新日時
+INSERT INTO blog_post_translation_revisions
+    (post_id, locale_id, revision_number, title, summary, meta_title, meta_description,
+     content, created_by, created_at)
+SELECT tr.post_id, tr.locale_id, 1, tr.title, tr.summary, tr.meta_title, tr.meta_description,
+       tr.content, p.last_updated_by, tr.updated_at
+FROM blog_post_translations tr
+JOIN blog_posts p ON p.post_id = tr.post_id;
+
+-- 💡 locale_id の外部キー用（言語の削除時の RESTRICT の確認・無効化の影響の一覧・付け替え）
+-- - 記事 + 言語ごとの版の一覧は UNIQUE(post_id, locale_id, revision_number) のインデックスを使う
+CREATE INDEX idx_blog_post_translation_revisions_locale_id
+    ON blog_post_translation_revisions(locale_id);
+
+COMMENT ON TABLE blog_post_translation_revisions IS
+'記事の翻訳の版: 翻訳を保存するたびに1行追加（内容は変更不可）。差分の表示・古い版の復元に使う';
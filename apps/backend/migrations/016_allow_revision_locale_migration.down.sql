@@ -0,0 +1,16 @@
+-- ============================================================
+-- Migration 016 (revert): 版の言語をどの場合も変更できないように戻す
+-- ============================================================
+CREATE OR REPLACE FUNCTION forbid_revision_content_update() RETURNS TRIGGER AS $$
+BEGIN
+    IF (NEW.post_id, NEW.locale_id, NEW.revision_number, NEW.title, NEW.summary, NEW.meta_title,
+        NEW.meta_description, NEW.content, NEW.restored_from, NEW.created_at)
+       IS DISTINCT FROM
+       (OLD.post_id, OLD.locale_id, OLD.revision_number, OLD.title, OLD.summary, OLD.meta_title,
+        OLD.meta_description, OLD.content, OLD.restored_from, OLD.created_at)
+    THEN
+        RAISE EXCEPTION 'blog_post_translation_revisions are immutable (revision_id %)', OLD.revision_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
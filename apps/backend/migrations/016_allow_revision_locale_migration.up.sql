@@ -0,0 +1,28 @@
+-- ============================================================
+-- Migration 016: 言語の無効化で版の言語を付け替えられるようにする
+-- ============================================================
+-- 目的: 言語を無効化して翻訳を別の言語に移すとき、版（revisions）も一緒に移す
+--       （移した翻訳の変更履歴・復元が、移行先の言語でそのまま使えるようにする）
+-- 作成日: 2026-10-18
+-- ============================================================
+
+-- 💡 版の内容は今まで通り変更できない（015 のトリガー）
+-- - locale_id だけ、blog.migrating_locale = 'on' のトランザクションでは変更できる
+-- - 設定するのは言語の無効化（LocaleRepository::migrate_references）だけ
+--     SELECT set_config('blog.migrating_locale', 'on', true);  -- true: トランザクションの間だけ
+-- - current_setting(..., true) は未設定なら NULL（一度設定した接続では ''）なので IS DISTINCT FROM で比べる
+CREATE OR REPLACE FUNCTION forbid_revision_content_update() RETURNS TRIGGER AS $$
+BEGIN
+    IF (NEW.post_id, NEW.revision_number, NEW.title, NEW.summary, NEW.meta_title,
+        NEW.meta_description, NEW.content, NEW.restored_from, NEW.created_at)
+       IS DISTINCT FROM
+       (OLD.post_id, OLD.revision_number, OLD.title, OLD.summary, OLD.meta_title,
+        OLD.meta_description, OLD.content, OLD.restored_from, OLD.created_at)
+       OR (NEW.locale_id IS DISTINCT FROM OLD.locale_id
+           AND current_setting('blog.migrating_locale', true) IS DISTINCT FROM 'on')
+    THEN
+        RAISE EXCEPTION 'blog_post_translation_revisions are immutable (revision_id %)', OLD.revision_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,69 @@
+// ============================================
+// 管理者アカウントの作成
+// ============================================
+// CLI（backend admin create）と API（POST /api/v1/admins）で共通の処理
+//
+// 💡 入力チェック → パスワードのハッシュ化 → INSERT の順に行う
+// - 平文のパスワードはこの関数の外に出さない
+
+use sqlx::PgPool;
+
+use crate::{
+    auth::password::{hash_password, validate_password},
+    entities::{Administrator, Role},
+    error::{AppError, AppResult},
+    repositories::{AdminRepository, AuditContext, NewAdmin},
+    validation::{is_valid_email, is_valid_username},
+};
+
+/// 管理者アカウントの作成に必要な情報
+#[derive(Debug, Clone)]
+pub struct AdminAccountInput {
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub full_name: Option<String>,
+    pub role: Role,
+}
+
+/// 管理者アカウントを作成
+pub async fn create_admin(
+    pool: &PgPool,
+    input: AdminAccountInput,
+    audit: &AuditContext,
+) -> AppResult<Administrator> {
+    let username = input.username.trim().to_string();
+    let email = input.email.trim().to_lowercase();
+
+    if !is_valid_username(&username) {
+        return Err(AppError::BadRequest(format!(
+            "Invalid username '{}': use 3-100 letters, digits, '-', '_' or '.'",
+            username
+        )));
+    }
+    if !is_valid_email(&email) {
+        return Err(AppError::BadRequest(format!("Invalid email '{}'", email)));
+    }
+    validate_password(&input.password).map_err(AppError::BadRequest)?;
+
+    let password_hash = hash_password(input.password).await?;
+    let full_name = input
+        .full_name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+
+    let admin = AdminRepository::new(pool.clone())
+        .create(
+            &NewAdmin {
+                username,
+                email,
+                password_hash,
+                full_name,
+                role: input.role,
+            },
+            audit,
+        )
+        .await?;
+
+    Ok(admin)
+}
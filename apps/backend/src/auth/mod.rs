@@ -0,0 +1,20 @@
+// ============================================
+// Auth Module（認証）
+// ============================================
+//
+// 💡 構成:
+// - accounts.rs : 管理者アカウントの作成（CLI・APIで共通）
+// - password.rs : パスワードのハッシュ化・検証（Argon2id）
+// - policy.rs   : 役割（admin / editor / author）ごとの権限
+// - token.rs    : セッショントークンの生成・ハッシュ化
+//
+// 💡 ログインの流れ:
+// 1. POST /api/v1/auth/login でユーザー名とパスワードを送る
+// 2. パスワードを検証し、ランダムなトークンを発行（DBにはハッシュだけ保存）
+// 3. 以降のリクエストは Authorization: Bearer <token> または Cookie で送る
+// 4. extractors::CurrentAdmin がトークンを検証し、ハンドラーに管理者を渡す
+
+pub mod accounts;
+pub mod password;
+pub mod policy;
+pub mod token;
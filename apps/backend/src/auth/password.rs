@@ -0,0 +1,118 @@
+// ============================================
+// パスワードのハッシュ化（Argon2id）
+// ============================================
+//
+// 💡 なぜ Argon2id?
+// - パスワード専用のハッシュ関数（わざと遅く、メモリも多く使う）
+// - GPU での総当たりが難しい
+// - SHA-256 などの汎用ハッシュは速すぎるのでパスワードには使わない
+//
+// 💡 保存形式（PHC文字列）:
+//   $argon2id$v=19$m=19456,t=2,p=1$<ソルト>$<ハッシュ>
+// - アルゴリズム・パラメータ・ソルトも含まれるので、この文字列だけで検証できる
+//
+// 💡 ハッシュ計算は重い（数十ms）ので、非同期ハンドラーからは
+//    spawn_blocking で別スレッドに逃がす（*_blocking ではない方の関数を使う）
+
+use std::sync::LazyLock;
+
+use anyhow::Context;
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+
+/// パスワードの最小文字数
+pub const MIN_PASSWORD_LEN: usize = 12;
+
+/// パスワードの最大文字数（長すぎる入力でハッシュ計算を重くさせない）
+pub const MAX_PASSWORD_LEN: usize = 128;
+
+/// 存在しないユーザーでログインされたときに検証するハッシュ
+///
+/// 💡 ユーザーが存在しないときだけ応答が速いと、
+///    応答時間からユーザー名の有無がわかってしまうため
+static DUMMY_HASH: LazyLock<String> = LazyLock::new(|| {
+    hash_password_blocking("dummy password for timing").expect("failed to hash dummy password")
+});
+
+/// パスワードの長さをチェック
+pub fn validate_password(password: &str) -> Result<(), String> {
+    let len = password.chars().count();
+    if !(MIN_PASSWORD_LEN..=MAX_PASSWORD_LEN).contains(&len) {
+        return Err(format!(
+            "Password must be {}-{} characters",
+            MIN_PASSWORD_LEN, MAX_PASSWORD_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// パスワードをハッシュ化（PHC文字列を返す）
+pub fn hash_password_blocking(password: &str) -> anyhow::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow::anyhow!("failed to hash password: {}", e))?;
+    Ok(hash.to_string())
+}
+
+/// パスワードを検証（ハッシュの形式が壊れている場合も false）
+pub fn verify_password_blocking(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|parsed| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+/// hash_password_blocking を別スレッドで実行
+pub async fn hash_password(password: String) -> anyhow::Result<String> {
+    tokio::task::spawn_blocking(move || hash_password_blocking(&password))
+        .await
+        .context("password hashing task failed")?
+}
+
+// --------------------------------------------------------
+// verify_password: パスワードを検証（別スレッド）
+// --------------------------------------------------------
+//
+// 💡 password_hash が None（ユーザーが存在しない）でも DUMMY_HASH で検証し、
+//    同じくらいの時間をかけてから false を返す
+pub async fn verify_password(password: String, password_hash: Option<String>) -> anyhow::Result<bool> {
+    tokio::task::spawn_blocking(move || match password_hash {
+        Some(hash) => verify_password_blocking(&password, &hash),
+        None => {
+            verify_password_blocking(&password, &DUMMY_HASH);
+            false
+        }
+    })
+    .await
+    .context("password verification task failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_and_verify() {
+        let hash = hash_password_blocking("correct horse battery").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(verify_password_blocking("correct horse battery", &hash));
+        assert!(!verify_password_blocking("wrong password!!", &hash));
+        assert!(!verify_password_blocking("correct horse battery", "not-a-hash"));
+
+        // 同じパスワードでもソルトが違うのでハッシュは毎回変わる
+        assert_ne!(hash, hash_password_blocking("correct horse battery").unwrap());
+    }
+
+    #[test]
+    fn test_validate_password() {
+        assert!(validate_password("short").is_err());
+        assert!(validate_password("long enough pw").is_ok());
+        assert!(validate_password(&"x".repeat(MAX_PASSWORD_LEN + 1)).is_err());
+    }
+}
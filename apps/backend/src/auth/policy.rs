@@ -0,0 +1,278 @@
+// ============================================
+// 権限ポリシー（役割ごとに呼べるAPI）
+// ============================================
+//
+// 💡 役割（administrators.role）:
+// | 役割   | できること                                                    |
+// |--------|---------------------------------------------------------------|
+// | admin  | すべて（言語の変更・管理者の作成を含む）                      |
+// | editor | トピックの作成・更新、すべての記事の作成・更新・公開、メディアの削除 |
+// | author | 記事の作成、自分が作成した下書きの更新（公開はできない）      |
+// |        | メディアのアップロード、自分がアップロードしたメディアの翻訳の更新 |
+//
+// 💡 APIキー（auth::token、api_keys テーブル）は役割ではなくスコープで判定する
+// | スコープ | できること                                             |
+// |----------|--------------------------------------------------------|
+// | read     | 非公開（下書き）のトピック・記事の取得                 |
+// | write    | トピック・記事の作成・更新・公開（editor 相当）        |
+// | delete   | 削除（メディア）                                       |
+// - 言語の変更・管理者・APIキーの管理は、APIキーでは呼べない（人がログインして行う）
+//
+// 💡 ルートごとのポリシーは、ハンドラーの引数の型で宣言する
+//   pub async fn create_locale(admin: Authorized<AdminOnly>, ...) -> ... { ... }
+//   → 役割が AdminOnly::ROLES に含まれなければ 403（extractors::Authorized）
+//
+// 💡 OpenAPI には security の「必要な役割・スコープ」として同じ値を書く
+//   security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+//   security(..., ("api_key" = ["write"]))
+//
+// 💡 「自分の下書きだけ」のように対象のデータで決まるルールは、
+//    データを読んだ後にハンドラーから ensure_can_edit_post などで確認する
+
+use crate::{
+    entities::{ApiScope, Media, Post, Role},
+    error::AppError,
+    extractors::Actor,
+};
+
+/// ルートを呼べる役割・スコープの定義
+pub trait Policy: Send + Sync + 'static {
+    /// 呼び出しを許可する役割
+    const ROLES: &'static [Role];
+    /// APIキーで呼ぶ場合に必要なスコープ（None ならAPIキーでは呼べない）
+    const API_KEY_SCOPE: Option<ApiScope>;
+}
+
+/// 管理者のみ（言語・管理者アカウント・APIキーの変更）
+pub struct AdminOnly;
+
+impl Policy for AdminOnly {
+    const ROLES: &'static [Role] = &[Role::Admin];
+    const API_KEY_SCOPE: Option<ApiScope> = None;
+}
+
+/// 編集者以上（トピック・本・チャプターの変更）
+pub struct EditorOrAdmin;
+
+impl Policy for EditorOrAdmin {
+    const ROLES: &'static [Role] = &[Role::Admin, Role::Editor];
+    const API_KEY_SCOPE: Option<ApiScope> = Some(ApiScope::Write);
+}
+
+/// 編集者以上の削除（メディア。APIキーは delete スコープが必要）
+pub struct EditorOrAdminDelete;
+
+impl Policy for EditorOrAdminDelete {
+    const ROLES: &'static [Role] = &[Role::Admin, Role::Editor];
+    const API_KEY_SCOPE: Option<ApiScope> = Some(ApiScope::Delete);
+}
+
+/// ログインしていれば誰でも（記事の作成・更新。細かい制限はハンドラーで確認）
+pub struct AnyRole;
+
+impl Policy for AnyRole {
+    const ROLES: &'static [Role] = &[Role::Admin, Role::Editor, Role::Author];
+    const API_KEY_SCOPE: Option<ApiScope> = Some(ApiScope::Write);
+}
+
+/// ログインしていれば誰でも読める（記事の版。APIキーは read スコープ）
+pub struct AnyRoleRead;
+
+impl Policy for AnyRoleRead {
+    const ROLES: &'static [Role] = &[Role::Admin, Role::Editor, Role::Author];
+    const API_KEY_SCOPE: Option<ApiScope> = Some(ApiScope::Read);
+}
+
+// --------------------------------------------------------
+// authorize: 実行者がポリシーを満たすか確認
+// --------------------------------------------------------
+pub fn authorize<P: Policy>(actor: &Actor) -> Result<(), AppError> {
+    match actor {
+        Actor::Admin(admin) => authorize_role::<P>(admin.role),
+        Actor::ApiKey(key) => match P::API_KEY_SCOPE {
+            Some(scope) if key.has_scope(scope) => Ok(()),
+            Some(scope) => Err(AppError::Forbidden(format!(
+                "API key is missing the '{}' scope",
+                scope
+            ))),
+            None => Err(AppError::Forbidden(
+                "API keys cannot be used for this endpoint".to_string(),
+            )),
+        },
+    }
+}
+
+fn authorize_role<P: Policy>(role: Role) -> Result<(), AppError> {
+    if P::ROLES.contains(&role) {
+        return Ok(());
+    }
+
+    let allowed: Vec<&str> = P::ROLES.iter().map(Role::as_str).collect();
+    Err(AppError::Forbidden(format!(
+        "This action requires one of the roles: {} (current role: {})",
+        allowed.join(", "),
+        role
+    )))
+}
+
+// --------------------------------------------------------
+// ensure_can_publish: 公開の操作を含む変更か確認
+// --------------------------------------------------------
+//
+// 💡 author は下書き（is_published = false）しか作れない
+// 💡 APIキーは write スコープ（Authorized で確認済み）があれば公開できる
+pub fn ensure_can_publish(actor: &Actor, is_published: Option<bool>) -> Result<(), AppError> {
+    let Actor::Admin(admin) = actor else {
+        return Ok(());
+    };
+    if is_published == Some(true) && !admin.role.can_publish() {
+        return Err(AppError::Forbidden(format!(
+            "Role '{}' cannot publish posts; save as a draft and ask an editor",
+            admin.role
+        )));
+    }
+    Ok(())
+}
+
+// --------------------------------------------------------
+// ensure_can_edit_post: 記事を更新できるか確認
+// --------------------------------------------------------
+//
+// 💡 admin / editor / APIキーはすべての記事、author は自分が作成した下書きだけ
+// 💡 予約済みの記事も author は更新できない（予約した人の確認なしに、公開される内容が変わるため）
+pub fn ensure_can_edit_post(actor: &Actor, post: &Post) -> Result<(), AppError> {
+    let Actor::Admin(admin) = actor else {
+        return Ok(());
+    };
+    let role = admin.role;
+    if role.can_publish() {
+        return Ok(());
+    }
+    if post.created_by != Some(admin.admin_id) {
+        return Err(AppError::Forbidden(format!(
+            "Role '{}' can only edit posts they created",
+            role
+        )));
+    }
+    if post.is_published {
+        return Err(AppError::Forbidden(format!(
+            "Role '{}' cannot edit published posts",
+            role
+        )));
+    }
+    if post.scheduled_at.is_some() {
+        return Err(AppError::Forbidden(format!(
+            "Role '{}' cannot edit scheduled posts; ask an editor to cancel the schedule first",
+            role
+        )));
+    }
+    Ok(())
+}
+
+// --------------------------------------------------------
+// ensure_can_edit_media: メディアの翻訳（代替テキスト・キャプション）を更新できるか確認
+// --------------------------------------------------------
+//
+// 💡 admin / editor / APIキーはすべてのメディア、author は自分がアップロードしたものだけ
+pub fn ensure_can_edit_media(actor: &Actor, media: &Media) -> Result<(), AppError> {
+    let Actor::Admin(admin) = actor else {
+        return Ok(());
+    };
+    if admin.role.can_publish() || media.uploaded_by == Some(admin.admin_id) {
+        return Ok(());
+    }
+    Err(AppError::Forbidden(format!(
+        "Role '{}' can only edit media they uploaded",
+        admin.role
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::Utc;
+
+    use crate::extractors::auth::{CurrentAdmin, CurrentApiKey};
+
+    fn admin(role: Role) -> Actor {
+        Actor::Admin(CurrentAdmin {
+            admin_id: 1,
+            username: "kohta".to_string(),
+            role,
+            session_id: 1,
+        })
+    }
+
+    fn post(created_by: i32) -> Post {
+        Post {
+            post_id: 1,
+            slug: "hello".to_string(),
+            meta_image_url: None,
+            estimated_reading_time: None,
+            book_id: None,
+            chapter_id: None,
+            is_published: false,
+            views_count: 0,
+            featured_image_id: None,
+            default_locale_id: None,
+            created_by: Some(created_by),
+            last_updated_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            published_at: None,
+            scheduled_at: None,
+        }
+    }
+
+    fn api_key(scopes: &[ApiScope]) -> Actor {
+        Actor::ApiKey(CurrentApiKey {
+            api_key_id: 1,
+            key_name: "ssg".to_string(),
+            scopes: scopes.to_vec(),
+            created_by: Some(1),
+        })
+    }
+
+    #[test]
+    fn test_authorize() {
+        assert!(authorize::<AdminOnly>(&admin(Role::Admin)).is_ok());
+        assert!(authorize::<AdminOnly>(&admin(Role::Editor)).is_err());
+        assert!(authorize::<EditorOrAdmin>(&admin(Role::Editor)).is_ok());
+        assert!(authorize::<EditorOrAdmin>(&admin(Role::Author)).is_err());
+        assert!(authorize::<AnyRole>(&admin(Role::Author)).is_ok());
+    }
+
+    #[test]
+    fn test_authorize_api_key() {
+        let all = [ApiScope::Read, ApiScope::Write, ApiScope::Delete];
+        assert!(authorize::<AdminOnly>(&api_key(&all)).is_err());
+        assert!(authorize::<AnyRole>(&api_key(&[ApiScope::Write])).is_ok());
+        assert!(authorize::<AnyRole>(&api_key(&[ApiScope::Read])).is_err());
+        assert!(authorize::<EditorOrAdminDelete>(&api_key(&[ApiScope::Write])).is_err());
+        assert!(authorize::<EditorOrAdminDelete>(&api_key(&[ApiScope::Delete])).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_can_publish() {
+        assert!(ensure_can_publish(&admin(Role::Author), Some(true)).is_err());
+        assert!(ensure_can_publish(&admin(Role::Author), Some(false)).is_ok());
+        assert!(ensure_can_publish(&admin(Role::Author), None).is_ok());
+        assert!(ensure_can_publish(&admin(Role::Editor), Some(true)).is_ok());
+        assert!(ensure_can_publish(&api_key(&[ApiScope::Write]), Some(true)).is_ok());
+    }
+
+    #[test]
+    fn test_ensure_can_edit_post() {
+        let draft = post(1);
+        let published = Post { is_published: true, ..post(1) };
+        let scheduled = Post { scheduled_at: Some(Utc::now()), ..post(1) };
+
+        assert!(ensure_can_edit_post(&admin(Role::Author), &draft).is_ok());
+        assert!(ensure_can_edit_post(&admin(Role::Author), &post(2)).is_err());
+        assert!(ensure_can_edit_post(&admin(Role::Author), &published).is_err());
+        assert!(ensure_can_edit_post(&admin(Role::Author), &scheduled).is_err());
+        assert!(ensure_can_edit_post(&admin(Role::Editor), &scheduled).is_ok());
+        assert!(ensure_can_edit_post(&api_key(&[ApiScope::Write]), &scheduled).is_ok());
+    }
+}
@@ -0,0 +1,80 @@
+// ============================================
+// セッショントークン
+// ============================================
+//
+// 💡 トークン:
+// - OSの乱数から32バイト（256ビット）を作り、URLで使える base64 にする
+// - 推測できないので、これ自体が「ログインしている証明」になる
+//
+// 💡 APIキーも同じ仕組みで、先頭に "blog_" を付ける
+// - Authorization: Bearer で送られたときにセッショントークンと区別するため
+// - GitHub などのシークレットスキャンで見つけやすくするため
+//
+// 💡 DBに保存するのは SHA-256 ハッシュだけ:
+// - トークンは十分ランダムなので、パスワードと違って速いハッシュで問題ない
+// - 検索は token_hash の一致で行う（UNIQUEインデックス）
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use sha2::{Digest, Sha256};
+
+/// トークンのバイト数
+const TOKEN_BYTES: usize = 32;
+
+/// APIキーの接頭辞
+pub const API_KEY_PREFIX: &str = "blog_";
+
+/// 一覧表示用に残すAPIキーの先頭の文字数（接頭辞を含む）
+const API_KEY_DISPLAY_LEN: usize = 12;
+
+/// 新しいトークンを生成（クライアントに一度だけ渡す）
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// 新しいAPIキーを生成（発行時に一度だけ返す）
+pub fn generate_api_key() -> String {
+    format!("{}{}", API_KEY_PREFIX, generate_token())
+}
+
+/// APIキーの形式か（セッショントークンとの区別に使う）
+pub fn is_api_key(token: &str) -> bool {
+    token.starts_with(API_KEY_PREFIX)
+}
+
+/// 一覧で見分けるためのAPIキーの先頭部分（例: blog_q1Xo3c9）
+pub fn api_key_display_prefix(key: &str) -> String {
+    key.chars().take(API_KEY_DISPLAY_LEN).collect()
+}
+
+/// トークンの SHA-256（16進64文字）
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_and_hash_token() {
+        let token = generate_token();
+        assert_eq!(token.len(), 43);
+        assert_ne!(token, generate_token());
+
+        let hash = hash_token(&token);
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, hash_token(&token));
+    }
+
+    #[test]
+    fn test_generate_api_key() {
+        let key = generate_api_key();
+        assert!(is_api_key(&key));
+        assert_eq!(key.len(), API_KEY_PREFIX.len() + 43);
+        assert_eq!(api_key_display_prefix(&key).len(), API_KEY_DISPLAY_LEN);
+        assert!(key.starts_with(&api_key_display_prefix(&key)));
+    }
+}
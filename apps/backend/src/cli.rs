@@ -0,0 +1,112 @@
+// ============================================
+// CLI（コマンドライン引数）
+// ============================================
+//
+// 💡 使い方:
+//   backend                      → APIサーバーを起動（serve と同じ）
+//   backend serve                → APIサーバーを起動
+//   backend migrate up           → 未適用のマイグレーションを適用
+//   backend migrate status       → 適用状況を表示
+//   backend migrate revert       → 最新のマイグレーションを1つ取り消す
+//   backend migrate revert --target 3 → バージョン3の状態まで取り消す
+//   backend migrate baseline     → 手でSQLを流したDBの適用済みマイグレーションを記録する
+//   backend admin create --username kohta --email kohta@example.com --role admin
+//                                → 管理者アカウントを作成（パスワードは ADMIN_PASSWORD か標準入力）
+//   backend search reindex       → 記事の全文検索用の search_vector をすべて作り直す
+//   backend legacy import-posts --dry-run → 旧スキーマの posts を移行した場合の結果を表示（書き込まない）
+//   backend legacy import-posts  → 旧スキーマの posts を blog_posts + 翻訳に移行する
+
+use clap::{Parser, Subcommand};
+
+use crate::entities::Role;
+
+#[derive(Debug, Parser)]
+#[command(name = "backend", version, about = "Rust製テックブログバックエンド")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// APIサーバーを起動する（デフォルト）
+    Serve,
+    /// データベースマイグレーションを操作する
+    Migrate {
+        #[command(subcommand)]
+        action: MigrateAction,
+    },
+    /// 管理者アカウントを操作する
+    Admin {
+        #[command(subcommand)]
+        action: AdminAction,
+    },
+    /// 記事の全文検索を操作する
+    Search {
+        #[command(subcommand)]
+        action: SearchAction,
+    },
+    /// 旧スキーマ（archive/old-migrations）のデータを移行する
+    Legacy {
+        #[command(subcommand)]
+        action: LegacyAction,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MigrateAction {
+    /// 未適用のマイグレーションをすべて適用する
+    Up,
+    /// マイグレーションの適用状況を表示する
+    Status,
+    /// マイグレーションを取り消す（省略時は最新の1つ）
+    Revert {
+        /// このバージョンの状態まで戻す（このバージョン自体は残る）
+        #[arg(long)]
+        target: Option<i64>,
+    },
+    /// 手でSQLを流して作ったDBに、適用済みのマイグレーションを記録する（_sqlx_migrations がないDB用）
+    Baseline {
+        /// このバージョンまでを適用済みとして記録する（省略時はテーブル・列から判定）
+        #[arg(long)]
+        version: Option<i64>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AdminAction {
+    /// 管理者アカウントを作成する
+    Create {
+        /// ユーザー名（ログインに使う）
+        #[arg(long)]
+        username: String,
+        /// メールアドレス（ログインにも使える）
+        #[arg(long)]
+        email: String,
+        /// 役割（admin / editor / author）
+        #[arg(long, default_value = "admin")]
+        role: Role,
+        /// 表示名
+        #[arg(long)]
+        full_name: Option<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum SearchAction {
+    /// すべての記事の翻訳の search_vector を作り直す（マイグレーション後・分け方を変えた後）
+    Reindex,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LegacyAction {
+    /// 旧 posts（title_ja / content_en などの言語別カラム）を blog_posts + 翻訳に移行する
+    ImportPosts {
+        /// 書き込まずに、移行する行・スキップする行だけを表示する
+        #[arg(long)]
+        dry_run: bool,
+        /// 旧 posts があるデータベースの接続URL（省略時は DATABASE_URL と同じデータベース）
+        #[arg(long)]
+        source_url: Option<String>,
+    },
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use similar::{ChangeTag, TextDiff};
+use utoipa::ToSchema;
+
+// ============================================
+// テキストの差分（翻訳の版の比較）
+// ============================================
+//
+// 💡 行単位の差分（similar クレート、Myers のアルゴリズム）
+// - unified: git diff と同じ形式のテキスト（そのまま表示・コピーできる）
+// - hunks  : 変更のまとまりごとの行（フロントエンドで色分けして表示する用）
+//
+// 💡 変更のない行は、変更の前後 CONTEXT_LINES 行だけ含める
+
+/// 変更の前後に含める変更のない行の数
+const CONTEXT_LINES: usize = 3;
+
+/// テキスト全体の差分
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TextChanges {
+    /// unified 形式の差分（変更がなければ空文字）
+    #[schema(example = "--- revision 1\n+++ revision 2\n@@ -1,2 +1,2 @@\n # はじめに\n-Rustを始めよう。\n+Rustを始めましょう。\n")]
+    pub unified: String,
+    /// 変更のまとまり
+    pub hunks: Vec<DiffHunk>,
+    /// 追加した行数
+    #[schema(example = 1)]
+    pub lines_added: usize,
+    /// 削除した行数
+    #[schema(example = 1)]
+    pub lines_removed: usize,
+}
+
+/// 変更のまとまり（@@ -1,2 +1,2 @@ の1つ分）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DiffHunk {
+    /// 範囲（例: @@ -1,2 +1,2 @@）
+    #[schema(example = "@@ -1,2 +1,2 @@")]
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// 差分の1行
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DiffLine {
+    pub op: DiffOp,
+    /// 比較元での行番号（1から、追加した行は null）
+    pub old_line: Option<usize>,
+    /// 比較先での行番号（1から、削除した行は null）
+    pub new_line: Option<usize>,
+    /// 行の内容（改行は含めない）
+    pub text: String,
+}
+
+/// 行の変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+// --------------------------------------------------------
+// diff_text: 2つのテキストを行単位で比較
+// --------------------------------------------------------
+//
+// 💡 old_label / new_label は unified の --- / +++ 行に入る名前
+pub fn diff_text(old: &str, new: &str, old_label: &str, new_label: &str) -> TextChanges {
+    let diff = TextDiff::from_lines(old, new);
+
+    let mut lines_added = 0;
+    let mut lines_removed = 0;
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            ChangeTag::Insert => lines_added += 1,
+            ChangeTag::Delete => lines_removed += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+
+    let mut unified_diff = diff.unified_diff();
+    unified_diff.context_radius(CONTEXT_LINES).header(old_label, new_label);
+
+    let hunks = unified_diff
+        .iter_hunks()
+        .map(|hunk| DiffHunk {
+            header: hunk.header().to_string(),
+            lines: hunk
+                .iter_changes()
+                .map(|change| DiffLine {
+                    op: match change.tag() {
+                        ChangeTag::Equal => DiffOp::Equal,
+                        ChangeTag::Insert => DiffOp::Insert,
+                        ChangeTag::Delete => DiffOp::Delete,
+                    },
+                    old_line: change.old_index().map(|index| index + 1),
+                    new_line: change.new_index().map(|index| index + 1),
+                    text: change.value().trim_end_matches(['\n', '\r']).to_string(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    TextChanges {
+        unified: unified_diff.to_string(),
+        hunks,
+        lines_added,
+        lines_removed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_text() {
+        let old = "# Title\n\nfirst\nsecond\n";
+        let new = "# Title\n\nfirst\nsecond (edited)\nthird\n";
+        let changes = diff_text(old, new, "revision 1", "revision 2");
+
+        assert_eq!(changes.lines_added, 2);
+        assert_eq!(changes.lines_removed, 1);
+        assert!(changes.unified.starts_with("--- revision 1\n+++ revision 2\n@@ -1,4 +1,5 @@"));
+        assert_eq!(changes.hunks.len(), 1);
+
+        let deleted = changes.hunks[0]
+            .lines
+            .iter()
+            .find(|line| line.op == DiffOp::Delete)
+            .unwrap();
+        assert_eq!(deleted.text, "second");
+        assert_eq!((deleted.old_line, deleted.new_line), (Some(4), None));
+    }
+
+    #[test]
+    fn test_diff_text_unchanged() {
+        let changes = diff_text("same\n", "same\n", "a", "b");
+        assert!(changes.unified.is_empty());
+        assert!(changes.hunks.is_empty());
+        assert_eq!((changes.lines_added, changes.lines_removed), (0, 0));
+    }
+}
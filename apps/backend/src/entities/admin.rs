@@ -0,0 +1,112 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+// --------------------------------------------------------
+// Role: 管理者の役割（administrators.role）
+// --------------------------------------------------------
+//
+// 💡 #[sqlx(type_name = "varchar")]:
+// - DBでは VARCHAR（'admin' / 'editor' / 'author'）として保存
+// - 読み書きのときに自動で enum と文字列を変換する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    Editor,
+    Author,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+            Self::Editor => "editor",
+            Self::Author => "author",
+        }
+    }
+
+    /// 記事の公開・非公開の切り替えや、他人の記事の更新ができるか
+    pub fn can_publish(&self) -> bool {
+        matches!(self, Self::Admin | Self::Editor)
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "admin" => Ok(Self::Admin),
+            "editor" => Ok(Self::Editor),
+            "author" => Ok(Self::Author),
+            other => Err(format!(
+                "role must be one of admin, editor, author (got '{}')",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// --------------------------------------------------------
+// Administrator: administratorsテーブルの1行
+// --------------------------------------------------------
+//
+// 💡 Serialize を付けない理由:
+// - password_hash をうっかりレスポンスに含めないため
+// - APIで返すときは handlers::auth::AdminResponse に変換する
+#[derive(Debug, Clone, FromRow)]
+pub struct Administrator {
+    pub admin_id: i32,
+    pub username: String,
+    pub email: String,
+    pub password_hash: String,
+    pub full_name: Option<String>,
+    pub role: Role,
+    pub is_active: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// Session: sessionsテーブルの1行
+// --------------------------------------------------------
+//
+// 💡 認証では SessionWithAdmin を使うので、ここでは作成結果の受け取りにだけ使う
+#[allow(dead_code)]
+#[derive(Debug, Clone, FromRow)]
+pub struct Session {
+    pub session_id: i32,
+    pub admin_id: i32,
+    pub token_hash: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub last_activity_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// SessionWithAdmin: セッション + 管理者（認証用）
+// --------------------------------------------------------
+#[derive(Debug, Clone, FromRow)]
+pub struct SessionWithAdmin {
+    pub session_id: i32,
+    pub expires_at: DateTime<Utc>,
+    pub last_activity_at: DateTime<Utc>,
+    pub admin_id: i32,
+    pub username: String,
+    pub role: Role,
+    pub is_active: bool,
+}
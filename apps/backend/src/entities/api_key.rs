@@ -0,0 +1,84 @@
+use std::{fmt, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+// --------------------------------------------------------
+// ApiScope: APIキーの権限（api_keys.permissions の要素）
+// --------------------------------------------------------
+//
+// 💡 スコープの意味:
+// - read   : 非公開（下書き）のデータを取得できる
+// - write  : 記事・トピックの作成・更新・公開ができる
+// - delete : 削除ができる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum ApiScope {
+    Read,
+    Write,
+    Delete,
+}
+
+impl ApiScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+impl FromStr for ApiScope {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "read" => Ok(Self::Read),
+            "write" => Ok(Self::Write),
+            "delete" => Ok(Self::Delete),
+            other => Err(format!(
+                "scope must be one of read, write, delete (got '{}')",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ApiScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// --------------------------------------------------------
+// ApiKey: api_keysテーブルの1行
+// --------------------------------------------------------
+//
+// 💡 Serialize を付けない理由（Administrator と同じ）:
+// - api_key_hash をレスポンスに含めないため（handlers::api_keys::ApiKeyResponse に変換する）
+#[derive(Debug, Clone, FromRow)]
+pub struct ApiKey {
+    pub api_key_id: i32,
+    pub key_name: String,
+    pub key_prefix: String,
+    #[allow(dead_code)]
+    pub api_key_hash: String,
+    pub permissions: Vec<ApiScope>,
+    pub is_active: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    /// 期限切れかどうか（expires_at が None なら無期限）
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
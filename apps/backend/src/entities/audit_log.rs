@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+// --------------------------------------------------------
+// AuditTable: 監査ログを記録するテーブル（audit_logs.table_name）
+// --------------------------------------------------------
+//
+// 💡 文字列ではなく列挙型にする理由:
+// - スナップショットを取るSQL（テーブル名・主キー名）を固定の文字列だけで組み立てられる
+// - GET /api/v1/audit-logs?table= に存在しないテーブル名を渡すと 400 にできる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum AuditTable {
+    Locales,
+    Topics,
+    BlogPosts,
+    Administrators,
+    ApiKeys,
+    Books,
+    BookChapters,
+    Media,
+}
+
+// --------------------------------------------------------
+// AuditAction: 変更の種類（audit_logs.action）
+// --------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "UPPERCASE")]
+#[sqlx(type_name = "varchar", rename_all = "UPPERCASE")]
+pub enum AuditAction {
+    Insert,
+    Update,
+    Delete,
+}
+
+// --------------------------------------------------------
+// ChangeSource: 変更の経路（audit_logs.changed_via）
+// --------------------------------------------------------
+//
+// 💡 web: ログインセッション / api: APIキー / cli: backend コマンド
+//    scheduler: バックグラウンドの予約公開（src/scheduler.rs）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+pub enum ChangeSource {
+    Web,
+    Api,
+    Cli,
+    Scheduler,
+}
+
+// --------------------------------------------------------
+// AuditLog: audit_logsテーブルの1行 + 変更した管理者のユーザー名
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AuditLog {
+    pub log_id: i32,
+    pub table_name: AuditTable,
+    pub record_id: i32,
+    pub action: AuditAction,
+    pub old_values: Option<Value>,
+    pub new_values: Option<Value>,
+    pub changed_by: Option<i32>,
+    /// administrators.username（管理者が削除されていれば NULL）
+    pub changed_by_username: Option<String>,
+    pub api_key_id: Option<i32>,
+    pub changed_via: ChangeSource,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
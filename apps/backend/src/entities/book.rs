@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+// --------------------------------------------------------
+// Book: booksテーブルの1行
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Book {
+    pub book_id: i32,
+    pub slug: String,
+    pub emoji: Option<String>,
+    pub top_image_url: Option<String>,
+    pub display_order: Option<i32>,
+    pub is_published: bool,
+    pub default_locale_id: Option<i32>,
+    pub created_by: Option<i32>,
+    pub last_updated_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+// --------------------------------------------------------
+// BookTranslation: book_translations + locales.code
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BookTranslation {
+    pub translation_id: i32,
+    pub book_id: i32,
+    pub locale_id: i32,
+    pub locale_code: String,
+    pub title: String,
+    pub introduction: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// LocalizedBook: 指定した言語に解決済みの本
+// --------------------------------------------------------
+//
+// 💡 title / locale_code が Option の理由:
+// - 要求言語・本の原文言語・サイトのデフォルト言語の
+//   いずれにも翻訳がない場合は NULL になる
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LocalizedBook {
+    pub book_id: i32,
+    pub slug: String,
+    pub emoji: Option<String>,
+    pub top_image_url: Option<String>,
+    pub display_order: Option<i32>,
+    pub is_published: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub title: Option<String>,
+    pub introduction: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+impl LocalizedBook {
+    /// 要求した言語ではなく、フォールバック先の言語で解決されたか
+    pub fn is_fallback(&self, requested_code: &str) -> bool {
+        self.locale_code.as_deref() != Some(requested_code)
+    }
+}
+
+// --------------------------------------------------------
+// BookChapter: book_chaptersテーブルの1行
+// --------------------------------------------------------
+//
+// 💡 chapter_number は同じ親（parent_chapter_id）の中での順番（1始まり）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BookChapter {
+    pub chapter_id: i32,
+    pub book_id: i32,
+    pub chapter_number: i32,
+    pub slug: String,
+    pub parent_chapter_id: Option<i32>,
+    pub is_published: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// BookChapterTranslation: book_chapter_translations + locales.code
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BookChapterTranslation {
+    pub translation_id: i32,
+    pub chapter_id: i32,
+    pub locale_id: i32,
+    pub locale_code: String,
+    pub title: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// LocalizedChapter: チャプターツリーの1行（言語解決済み）
+// --------------------------------------------------------
+//
+// 💡 ツリーをそのまま返すのではなく、深さ優先の順に並んだ平らな行で取得する
+// - depth: トップレベルが 0
+// - 入れ子の形にするのはハンドラー側（handlers::books::build_chapter_tree）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LocalizedChapter {
+    pub chapter_id: i32,
+    pub parent_chapter_id: Option<i32>,
+    pub chapter_number: i32,
+    pub depth: i32,
+    pub slug: String,
+    pub is_published: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub title: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+impl LocalizedChapter {
+    /// 要求した言語ではなく、フォールバック先の言語で解決されたか
+    pub fn is_fallback(&self, requested_code: &str) -> bool {
+        self.locale_code.as_deref() != Some(requested_code)
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+// --------------------------------------------------------
+// TranslatableEntity: 翻訳テーブルを持つ親テーブルの種類
+// --------------------------------------------------------
+//
+// 💡 親テーブル + 翻訳テーブル（docs/database-schema-v2.md「多言語対応パターン」）:
+// | 種類         | 親テーブル    | 翻訳テーブル              | 原文の言語                          |
+// |--------------|---------------|---------------------------|-------------------------------------|
+// | post         | blog_posts    | blog_post_translations    | default_locale_id → デフォルト言語  |
+// | topic        | topics        | topic_translations        | デフォルト言語                      |
+// | book         | books         | book_translations         | default_locale_id → デフォルト言語  |
+// | book_chapter | book_chapters | book_chapter_translations | 本の default_locale_id → デフォルト言語 |
+// | media        | media         | media_translations        | デフォルト言語                      |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum TranslatableEntity {
+    Post,
+    Topic,
+    Book,
+    BookChapter,
+    Media,
+}
+
+// --------------------------------------------------------
+// TranslationCoverageRow: 1つのデータ × 1つの有効な言語
+// --------------------------------------------------------
+//
+// 💡 翻訳がなければ translation_updated_at が NULL
+// 💡 source_updated_at は原文の言語の翻訳の更新日時（原文の翻訳がなければ NULL）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TranslationCoverageRow {
+    pub entity_type: TranslatableEntity,
+    pub entity_id: i32,
+    /// 画面に出す識別子（slug、チャプターは 本のslug/チャプターのslug、メディアは元のファイル名）
+    pub label: String,
+    pub source_locale_code: Option<String>,
+    pub source_updated_at: Option<DateTime<Utc>>,
+    pub locale_code: String,
+    pub translation_updated_at: Option<DateTime<Utc>>,
+}
+
+// --------------------------------------------------------
+// LocaleReferenceKind: 言語を参照している列の種類
+// --------------------------------------------------------
+//
+// | 種類           | テーブル.列                                                    |
+// |----------------|----------------------------------------------------------------|
+// | translation    | 5つの翻訳テーブルの locale_id（TranslatableEntity の表を参照） |
+// | revision       | blog_post_translation_revisions.locale_id（記事ごとにまとめる）|
+// | default_locale | blog_posts / books の default_locale_id（原文の言語）          |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum LocaleReferenceKind {
+    Translation,
+    Revision,
+    DefaultLocale,
+}
+
+// --------------------------------------------------------
+// LocaleReference: 1つの言語を参照している行（言語の無効化・削除の影響）
+// --------------------------------------------------------
+//
+// 💡 conflict: 移行先の言語に、同じデータの行がすでにある
+// - 付け替えると UNIQUE(…, locale_id) にぶつかるので、先にどちらかを削除する必要がある
+// - 移行先を指定しないときは常に false
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LocaleReference {
+    pub table_name: String,
+    pub kind: LocaleReferenceKind,
+    pub entity_type: TranslatableEntity,
+    pub entity_id: i32,
+    /// 画面に出す識別子（TranslationCoverageRow.label と同じ）
+    pub label: String,
+    /// 参照している行数（版は記事ごとにまとめるので2以上になる）
+    pub row_count: i64,
+    pub conflict: bool,
+}
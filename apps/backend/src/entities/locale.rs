@@ -15,9 +15,11 @@ pub struct Locale {
 }
 
 impl Locale {
+    #[allow(dead_code)]
     pub fn is_japanese(&self) -> bool {
         self.code == "ja"
     }
+    #[allow(dead_code)]
     pub fn is_english(&self) -> bool {
         self.code == "en"
     }
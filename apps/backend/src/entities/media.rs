@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+// --------------------------------------------------------
+// Media: mediaテーブルの1行
+// --------------------------------------------------------
+//
+// 💡 ファイル本体はストレージにあり、file_path はそのキー
+// 💡 width / height は画像のときだけ入る（PDFなどは NULL）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Media {
+    pub media_id: i32,
+    pub filename: String,
+    pub original_filename: String,
+    pub file_path: String,
+    pub file_type: String,
+    pub file_size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub uploaded_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// MediaTranslation: media_translations + locales.code
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MediaTranslation {
+    pub translation_id: i32,
+    pub media_id: i32,
+    pub locale_id: i32,
+    pub locale_code: String,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// LocalizedMedia: 指定した言語に解決済みのメディア
+// --------------------------------------------------------
+//
+// 💡 alt_text / caption / locale_code が Option の理由:
+// - 要求した言語にもデフォルト言語にも翻訳がない場合は NULL になる
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LocalizedMedia {
+    pub media_id: i32,
+    pub filename: String,
+    pub original_filename: String,
+    pub file_path: String,
+    pub file_type: String,
+    pub file_size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub uploaded_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+impl LocalizedMedia {
+    /// 要求した言語ではなく、フォールバック先の言語で解決されたか
+    pub fn is_fallback(&self, requested_code: &str) -> bool {
+        self.locale_code.as_deref() != Some(requested_code)
+    }
+}
+
+// --------------------------------------------------------
+// MediaUsage / MediaReference: メディアを使っている場所（削除前のチェック用）
+// --------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum MediaUsage {
+    /// 記事のアイキャッチ画像（blog_posts.featured_image_id）
+    FeaturedImage,
+    /// 記事のOGP画像（blog_posts.meta_image_url）
+    MetaImage,
+    /// 記事の本文（blog_post_translations.content）
+    PostContent,
+    /// 本のトップ画像（books.top_image_url）
+    BookTopImage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MediaReference {
+    pub usage: MediaUsage,
+    /// 使っている記事・本のslug
+    pub slug: String,
+}
@@ -1,4 +1,25 @@
+pub mod admin;
+pub mod api_key;
+pub mod audit_log;
+pub mod book;
+pub mod coverage;
 pub mod locale;
+pub mod media;
+pub mod post;
+pub mod topic;
 
 
-pub use locale::Locale;
\ No newline at end of file
+pub use admin::{Administrator, Role, Session, SessionWithAdmin};
+pub use api_key::{ApiKey, ApiScope};
+pub use audit_log::{AuditAction, AuditLog, AuditTable, ChangeSource};
+pub use book::{
+    Book, BookChapter, BookChapterTranslation, BookTranslation, LocalizedBook, LocalizedChapter,
+};
+pub use coverage::{LocaleReference, LocaleReferenceKind, TranslatableEntity, TranslationCoverageRow};
+pub use locale::Locale;
+pub use media::{LocalizedMedia, Media, MediaReference, MediaTranslation, MediaUsage};
+pub use post::{
+    LocalizedPost, Post, PostFeedItem, PostRevision, PostSearchHit, PostSummary, PostTranslation,
+    ScheduledPost,
+};
+pub use topic::{LocalizedTopic, Topic, TopicTranslation};
@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+// --------------------------------------------------------
+// Post: blog_postsテーブルの1行
+// --------------------------------------------------------
+//
+// 💡 旧Post（archive/old-code）との違い:
+// - title_ja / title_en などの言語別カラムはない
+// - 言語ごとの内容は PostTranslation（blog_post_translations）に分離
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Post {
+    pub post_id: i32,
+    pub slug: String,
+    pub meta_image_url: Option<String>,
+    pub estimated_reading_time: Option<i32>,
+    pub book_id: Option<i32>,
+    pub chapter_id: Option<i32>,
+    pub is_published: bool,
+    pub views_count: i32,
+    /// アイキャッチ画像（media.media_id）
+    pub featured_image_id: Option<i32>,
+    pub default_locale_id: Option<i32>,
+    pub created_by: Option<i32>,
+    pub last_updated_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    /// 予約公開の日時（この日時を過ぎると scheduler が公開する）
+    pub scheduled_at: Option<DateTime<Utc>>,
+}
+
+// --------------------------------------------------------
+// PostTranslation: blog_post_translations + locales.code
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostTranslation {
+    pub translation_id: i32,
+    pub post_id: i32,
+    pub locale_id: i32,
+    pub locale_code: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub content: String,
+    /// 本文から計算した読了目安（分、migration 012 より前に保存した翻訳は NULL）
+    pub reading_time_minutes: Option<i32>,
+    /// 本文から計算した抜粋
+    pub excerpt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// PostSummary: 一覧用（言語解決済み、本文なし）
+// --------------------------------------------------------
+//
+// 💡 title / locale_code が Option の理由:
+// - 要求言語・記事の原文言語・サイトのデフォルト言語の
+//   いずれにも翻訳がない場合は NULL になる
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostSummary {
+    pub post_id: i32,
+    pub slug: String,
+    pub meta_image_url: Option<String>,
+    pub estimated_reading_time: Option<i32>,
+    pub is_published: bool,
+    pub views_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    /// 要約（summary）か、なければ本文から計算した抜粋
+    pub excerpt: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+// --------------------------------------------------------
+// LocalizedPost: 詳細用（言語解決済み、本文あり）
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LocalizedPost {
+    pub post_id: i32,
+    pub slug: String,
+    pub meta_image_url: Option<String>,
+    pub estimated_reading_time: Option<i32>,
+    pub book_id: Option<i32>,
+    pub chapter_id: Option<i32>,
+    pub featured_image_id: Option<i32>,
+    pub is_published: bool,
+    pub views_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub scheduled_at: Option<DateTime<Utc>>,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    /// 要約（summary）か、なければ本文から計算した抜粋
+    pub excerpt: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub content: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+impl PostSummary {
+    /// 要求した言語ではなく、フォールバック先の言語で解決されたか
+    pub fn is_fallback(&self, requested_code: &str) -> bool {
+        self.locale_code.as_deref() != Some(requested_code)
+    }
+}
+
+impl LocalizedPost {
+    /// 要求した言語ではなく、フォールバック先の言語で解決されたか
+    pub fn is_fallback(&self, requested_code: &str) -> bool {
+        self.locale_code.as_deref() != Some(requested_code)
+    }
+}
+
+// --------------------------------------------------------
+// PostSearchHit: 全文検索で一致した記事の翻訳
+// --------------------------------------------------------
+//
+// 💡 content は抜粋（snippet）を作るために取る（レスポンスには含めない）
+#[derive(Debug, Clone, FromRow)]
+pub struct PostSearchHit {
+    pub post_id: i32,
+    pub slug: String,
+    pub published_at: Option<DateTime<Utc>>,
+    pub title: String,
+    pub excerpt: Option<String>,
+    pub content: String,
+    pub locale_code: String,
+    /// ts_rank_cd の値（大きいほど一致度が高い）
+    pub rank: f32,
+}
+
+// --------------------------------------------------------
+// ScheduledPost: 予約公開の一覧用（言語解決済みのタイトル付き）
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ScheduledPost {
+    pub post_id: i32,
+    pub slug: String,
+    pub scheduled_at: DateTime<Utc>,
+    pub created_by: Option<i32>,
+    pub last_updated_by: Option<i32>,
+    pub updated_at: DateTime<Utc>,
+    pub title: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+// --------------------------------------------------------
+// PostFeedItem: RSS / Atom フィードの1記事（1つの言語の翻訳だけ）
+// --------------------------------------------------------
+//
+// 💡 フィードはフォールバックしない（その言語の翻訳がある記事だけ）
+// 💡 updated_at は記事・翻訳のうち新しいほう（翻訳だけ直した場合も更新扱い）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostFeedItem {
+    pub post_id: i32,
+    pub slug: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub published_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// トピック名（その言語の名前、なければslug）
+    pub topics: Vec<String>,
+}
+
+// --------------------------------------------------------
+// PostRevision: blog_post_translation_revisions の1行（翻訳の版）
+// --------------------------------------------------------
+//
+// 💡 翻訳を保存するたびに1行増える（内容は変更されない）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PostRevision {
+    pub revision_id: i32,
+    pub post_id: i32,
+    pub locale_code: String,
+    /// 記事 + 言語ごとの通し番号（1から）
+    pub revision_number: i32,
+    pub title: String,
+    pub summary: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub content: String,
+    /// 古い版から復元した場合、その版の番号
+    pub restored_from: Option<i32>,
+    pub created_by: Option<i32>,
+    /// 作成した管理者のユーザー名（管理者が削除された場合は NULL）
+    pub created_by_username: Option<String>,
+    pub api_key_id: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+// --------------------------------------------------------
+// Topic: topicsテーブルの1行
+// --------------------------------------------------------
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Topic {
+    pub topic_id: i32,
+    pub slug: String,
+    pub is_published: bool,
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// TopicTranslation: topic_translations + locales.code
+// --------------------------------------------------------
+//
+// 💡 locale_code:
+// - topic_translations には locale_id しかないため、
+//   locales を JOIN して言語コード（ja, en）も一緒に取得する
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TopicTranslation {
+    pub translation_id: i32,
+    pub topic_id: i32,
+    pub locale_id: i32,
+    pub locale_code: String,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// --------------------------------------------------------
+// LocalizedTopic: 指定した言語に解決済みのトピック
+// --------------------------------------------------------
+//
+// 💡 name / locale_code が Option の理由:
+// - 要求した言語にもデフォルト言語にも翻訳がない場合は NULL になる
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LocalizedTopic {
+    pub topic_id: i32,
+    pub slug: String,
+    pub is_published: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub name: Option<String>,
+    pub locale_code: Option<String>,
+}
+
+impl LocalizedTopic {
+    /// 要求した言語ではなく、フォールバック先の言語で解決されたか
+    pub fn is_fallback(&self, requested_code: &str) -> bool {
+        self.locale_code.as_deref() != Some(requested_code)
+    }
+}
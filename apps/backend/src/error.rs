@@ -0,0 +1,218 @@
+// ============================================
+// AppError（アプリケーション共通エラー）
+// ============================================
+// すべてのハンドラーが返すエラー型
+//
+// 💡 ポイント:
+// - IntoResponseを実装しているので、ハンドラーから `?` でそのまま返せる
+// - sqlx::Error は From で自動変換され、適切なステータスコードになる
+// - レスポンスは常に同じ形式のJSON（ErrorResponse）
+//
+// 💡 レスポンス例:
+// {
+//   "status": 404,
+//   "code": "not_found",
+//   "message": "Locale 'xx' not found",
+//   "request_id": "6f1c2a4e-..."
+// }
+
+use axum::{
+    http::{header::WWW_AUTHENTICATE, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{error, warn};
+use utoipa::ToSchema;
+
+use crate::middleware::request_id;
+
+/// 共通エラーレスポンス
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ErrorResponse {
+    /// HTTPステータスコード
+    #[schema(example = 404)]
+    pub status: u16,
+    /// 機械処理用のエラーコード（変更されない識別子）
+    #[schema(example = "not_found")]
+    pub code: String,
+    /// 人間向けのエラーメッセージ
+    #[schema(example = "Locale 'xx' not found")]
+    pub message: String,
+    /// リクエストID（X-Request-Idヘッダーと同じ値）
+    #[schema(example = "6f1c2a4e-8f0b-4a4e-9b43-1f2d7c9e0a11")]
+    pub request_id: Option<String>,
+}
+
+/// アプリケーション共通エラー
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    /// 400: 入力が不正
+    #[error("{0}")]
+    BadRequest(String),
+
+    /// 401: 認証されていない（ログインしていない・セッション切れ）
+    #[error("{0}")]
+    Unauthorized(String),
+
+    /// 403: ログインしているが、役割（role）の権限が足りない
+    #[error("{0}")]
+    Forbidden(String),
+
+    /// 404: リソースが見つからない
+    #[error("{0}")]
+    NotFound(String),
+
+    /// 409: 現在の状態と矛盾する操作
+    #[error("{0}")]
+    Conflict(String),
+
+    /// 413: リクエストが大きすぎる（アップロードの上限超え）
+    #[error("{0}")]
+    PayloadTooLarge(String),
+
+    /// 415: 受け付けないファイル形式
+    #[error("{0}")]
+    UnsupportedMediaType(String),
+
+    /// データベースエラー（種類に応じてステータスコードを決める）
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+
+    /// 500: その他の内部エラー
+    #[error(transparent)]
+    Internal(#[from] anyhow::Error),
+}
+
+impl AppError {
+    // --------------------------------------------------------
+    // status_and_code: ステータスコードとエラーコードを決める
+    // --------------------------------------------------------
+    //
+    // 💡 sqlx::Error の対応表:
+    // - RowNotFound              → 404 not_found
+    // - UNIQUE制約違反 (23505)    → 409 unique_violation
+    // - 外部キー制約違反 (23503)  → 409 foreign_key_violation
+    // - CHECK制約違反 (23514)     → 409 check_violation
+    // - プール取得タイムアウト      → 503 database_unavailable
+    // - その他                    → 500 database_error
+    pub fn status_and_code(&self) -> (StatusCode, &'static str) {
+        match self {
+            Self::BadRequest(_) => (StatusCode::BAD_REQUEST, "bad_request"),
+            Self::Unauthorized(_) => (StatusCode::UNAUTHORIZED, "unauthorized"),
+            Self::Forbidden(_) => (StatusCode::FORBIDDEN, "forbidden"),
+            Self::NotFound(_) => (StatusCode::NOT_FOUND, "not_found"),
+            Self::Conflict(_) => (StatusCode::CONFLICT, "conflict"),
+            Self::PayloadTooLarge(_) => (StatusCode::PAYLOAD_TOO_LARGE, "payload_too_large"),
+            Self::UnsupportedMediaType(_) => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, "unsupported_media_type")
+            }
+            Self::Database(e) => match e {
+                sqlx::Error::RowNotFound => (StatusCode::NOT_FOUND, "not_found"),
+                sqlx::Error::Database(db) if db.is_unique_violation() => {
+                    (StatusCode::CONFLICT, "unique_violation")
+                }
+                sqlx::Error::Database(db) if db.is_foreign_key_violation() => {
+                    (StatusCode::CONFLICT, "foreign_key_violation")
+                }
+                sqlx::Error::Database(db) if db.is_check_violation() => {
+                    (StatusCode::CONFLICT, "check_violation")
+                }
+                sqlx::Error::PoolTimedOut | sqlx::Error::PoolClosed => {
+                    (StatusCode::SERVICE_UNAVAILABLE, "database_unavailable")
+                }
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "database_error"),
+            },
+            Self::Internal(_) => (StatusCode::INTERNAL_SERVER_ERROR, "internal_error"),
+        }
+    }
+
+    // --------------------------------------------------------
+    // public_message: クライアントに見せるメッセージ
+    // --------------------------------------------------------
+    //
+    // 💡 なぜ分ける?
+    // - データベースや内部エラーの詳細（SQL文、テーブル名など）は
+    //   攻撃のヒントになるため、ログにだけ出してクライアントには返さない
+    fn public_message(&self) -> String {
+        match self {
+            Self::Database(e) => match e {
+                sqlx::Error::RowNotFound => "Resource not found".to_string(),
+                sqlx::Error::Database(db) if db.is_unique_violation() => {
+                    "Resource already exists".to_string()
+                }
+                sqlx::Error::Database(db) if db.is_foreign_key_violation() => {
+                    "Resource is referenced by or references missing data".to_string()
+                }
+                sqlx::Error::Database(db) if db.is_check_violation() => {
+                    "Request violates a data constraint".to_string()
+                }
+                sqlx::Error::PoolTimedOut | sqlx::Error::PoolClosed => {
+                    "Database is temporarily unavailable".to_string()
+                }
+                _ => "A database error occurred".to_string(),
+            },
+            Self::Internal(_) => "An internal error occurred".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, code) = self.status_and_code();
+
+        // 5xx はサーバー側の問題なので error、4xx は warn でログ出力
+        if status.is_server_error() {
+            error!(code, error = ?self, "❌ Request failed");
+        } else {
+            warn!(code, message = %self, "⚠️ Request rejected");
+        }
+
+        let body = ErrorResponse {
+            status: status.as_u16(),
+            code: code.to_string(),
+            message: self.public_message(),
+            request_id: request_id::current(),
+        };
+
+        // 💡 401 には認証方法を示す WWW-Authenticate を付ける（RFC 9110）
+        if status == StatusCode::UNAUTHORIZED {
+            return (status, [(WWW_AUTHENTICATE, "Bearer")], Json(body)).into_response();
+        }
+
+        (status, Json(body)).into_response()
+    }
+}
+
+/// ハンドラーの戻り値用エイリアス
+pub type AppResult<T> = Result<T, AppError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqlx_error_mapping() {
+        let (status, code) = AppError::from(sqlx::Error::RowNotFound).status_and_code();
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(code, "not_found");
+
+        let (status, code) = AppError::from(sqlx::Error::PoolTimedOut).status_and_code();
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(code, "database_unavailable");
+
+        let (status, code) = AppError::from(sqlx::Error::Protocol("boom".into())).status_and_code();
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(code, "database_error");
+    }
+
+    #[test]
+    fn test_internal_details_are_hidden() {
+        let err = AppError::from(sqlx::Error::Protocol("relation \"secret\" does not exist".into()));
+        assert_eq!(err.public_message(), "A database error occurred");
+
+        let err = AppError::NotFound("Locale 'xx' not found".to_string());
+        assert_eq!(err.public_message(), "Locale 'xx' not found");
+    }
+}
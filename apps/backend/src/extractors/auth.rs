@@ -0,0 +1,409 @@
+// ============================================
+// CurrentAdmin / Actor（ログイン中の管理者・APIキー）
+// ============================================
+// セッショントークン・APIキーを検証して、リクエストの実行者を取り出すエクストラクター
+//
+// 💡 資格情報の送り方:
+// 1. X-API-Key: blog_xxx              （APIキー）
+// 2. Authorization: Bearer <token>    （セッショントークン、または blog_ で始まるAPIキー）
+// 3. Cookie: session=<token>          （ブラウザの管理画面向け）
+//
+// 💡 使用例:
+//   // ログインセッション必須（APIキーでは呼べない）
+//   pub async fn logout(admin: CurrentAdmin, ...) -> ... { ... }
+//
+//   // 管理者でもAPIキーでもよい（未認証なら None）
+//   pub async fn list_posts(actor: Option<Actor>, ...) -> ... { ... }
+//
+// 💡 役割・スコープで制限する場合は Authorized<P> を使う（auth::policy）
+//   pub async fn create_locale(admin: Authorized<AdminOnly>, ...) -> ... { ... }
+//   → 未認証なら 401、役割・スコープが足りなければ 403
+//
+// 💡 セッションが切れる条件:
+// - expires_at を過ぎた（ログインからの絶対期限）
+// - last_activity_at から session_idle_timeout 以上操作がない
+// - 管理者が無効化された（is_active = false）
+//
+// 💡 APIキーが使えなくなる条件:
+// - 無効化された（is_active = false）
+// - expires_at を過ぎた
+
+use std::{marker::PhantomData, ops::Deref};
+
+use axum::{
+    extract::{FromRef, FromRequestParts, OptionalFromRequestParts},
+    http::{
+        header::{AUTHORIZATION, COOKIE},
+        request::Parts,
+        HeaderName,
+    },
+};
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use tracing::info;
+
+use crate::{
+    auth::{
+        policy::{authorize, Policy},
+        token::{hash_token, is_api_key},
+    },
+    config::AuthConfig,
+    entities::{ApiScope, ChangeSource, Role},
+    error::AppError,
+    extractors::{client::ClientInfo, locale::cookie_value},
+    repositories::{AdminRepository, ApiKeyRepository, AuditContext},
+};
+
+/// セッショントークンを入れるCookie名
+pub const SESSION_COOKIE: &str = "session";
+
+/// APIキーを送るヘッダー名
+pub const X_API_KEY: HeaderName = HeaderName::from_static("x-api-key");
+
+/// last_activity_at を更新する間隔（毎リクエストで UPDATE しないため）
+const TOUCH_INTERVAL_SECS: i64 = 60;
+
+/// ログイン中の管理者
+#[derive(Debug, Clone)]
+pub struct CurrentAdmin {
+    pub admin_id: i32,
+    pub username: String,
+    pub role: Role,
+    pub session_id: i32,
+}
+
+/// 検証済みのAPIキー
+#[derive(Debug, Clone)]
+pub struct CurrentApiKey {
+    pub api_key_id: i32,
+    pub key_name: String,
+    pub scopes: Vec<ApiScope>,
+    /// キーを発行した管理者（記事の created_by などに記録する）
+    pub created_by: Option<i32>,
+}
+
+impl CurrentApiKey {
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+// --------------------------------------------------------
+// Actor: リクエストの実行者（管理者 or APIキー）
+// --------------------------------------------------------
+#[derive(Debug, Clone)]
+pub enum Actor {
+    Admin(CurrentAdmin),
+    ApiKey(CurrentApiKey),
+}
+
+impl Actor {
+    /// 作成者・更新者として記録する管理者ID
+    pub fn admin_id(&self) -> Option<i32> {
+        match self {
+            Self::Admin(admin) => Some(admin.admin_id),
+            Self::ApiKey(key) => key.created_by,
+        }
+    }
+
+    /// ログ用の名前（例: kohta / api-key:3:ssg）
+    pub fn name(&self) -> String {
+        match self {
+            Self::Admin(admin) => admin.username.clone(),
+            Self::ApiKey(key) => format!("api-key:{}:{}", key.api_key_id, key.key_name),
+        }
+    }
+
+    /// 監査ログに記録する実行者（ログインセッションは web、APIキーは api）
+    pub fn audit_context(&self, client: &ClientInfo) -> AuditContext {
+        let (api_key_id, changed_via) = match self {
+            Self::Admin(_) => (None, ChangeSource::Web),
+            Self::ApiKey(key) => (Some(key.api_key_id), ChangeSource::Api),
+        };
+
+        AuditContext {
+            changed_by: self.admin_id(),
+            api_key_id,
+            changed_via,
+            ip_address: client.ip_address.clone(),
+        }
+    }
+}
+
+/// リクエストに含まれる資格情報
+enum Credentials {
+    Session(String),
+    ApiKey(String),
+}
+
+impl<S> FromRequestParts<S> for Actor
+where
+    PgPool: FromRef<S>,
+    AuthConfig: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let credentials = credentials(parts)
+            .ok_or_else(|| AppError::Unauthorized("Authentication required".to_string()))?;
+        authenticate(credentials, state).await
+    }
+}
+
+// 💡 Option<Actor>:
+// - 資格情報が送られていなければ None（公開APIとして扱う）
+// - 送られているのに無効なら 401（期限切れに気付けるように）
+impl<S> OptionalFromRequestParts<S> for Actor
+where
+    PgPool: FromRef<S>,
+    AuthConfig: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &S,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        match credentials(parts) {
+            Some(credentials) => authenticate(credentials, state).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+// 💡 CurrentAdmin はログインセッションだけを受け付ける
+// - ログアウト・APIキーの発行など、人が操作すべきAPIで使う
+impl<S> FromRequestParts<S> for CurrentAdmin
+where
+    PgPool: FromRef<S>,
+    AuthConfig: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match <Actor as FromRequestParts<S>>::from_request_parts(parts, state).await? {
+            Actor::Admin(admin) => Ok(admin),
+            Actor::ApiKey(_) => Err(AppError::Forbidden(
+                "API keys cannot be used for this endpoint; log in as an administrator".to_string(),
+            )),
+        }
+    }
+}
+
+// --------------------------------------------------------
+// Authorized<P>: ポリシー P を満たす実行者
+// --------------------------------------------------------
+//
+// 💡 Deref で Actor のメソッドをそのまま使える（actor.admin_id() など）
+pub struct Authorized<P: Policy> {
+    pub actor: Actor,
+    _policy: PhantomData<P>,
+}
+
+impl<P: Policy> Deref for Authorized<P> {
+    type Target = Actor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.actor
+    }
+}
+
+impl<S, P> FromRequestParts<S> for Authorized<P>
+where
+    PgPool: FromRef<S>,
+    AuthConfig: FromRef<S>,
+    S: Send + Sync,
+    P: Policy,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let actor = <Actor as FromRequestParts<S>>::from_request_parts(parts, state).await?;
+        authorize::<P>(&actor)?;
+
+        Ok(Self {
+            actor,
+            _policy: PhantomData,
+        })
+    }
+}
+
+// --------------------------------------------------------
+// require_login_for_unpublished: 非公開データの取得には認証を必須にする
+// --------------------------------------------------------
+//
+// 💡 公開APIの ?include_unpublished=true で下書きが見えてしまわないように
+// - 管理者: どの役割でもよい
+// - APIキー: read スコープが必要
+pub fn require_login_for_unpublished(
+    include_unpublished: bool,
+    actor: Option<&Actor>,
+) -> Result<(), AppError> {
+    if !include_unpublished {
+        return Ok(());
+    }
+
+    match actor {
+        None => Err(AppError::Unauthorized(
+            "Authentication required to include unpublished content".to_string(),
+        )),
+        Some(Actor::ApiKey(key)) if !key.has_scope(ApiScope::Read) => Err(AppError::Forbidden(
+            "API key is missing the 'read' scope required to include unpublished content"
+                .to_string(),
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+// --------------------------------------------------------
+// authenticate: 資格情報 → 実行者
+// --------------------------------------------------------
+async fn authenticate<S>(credentials: Credentials, state: &S) -> Result<Actor, AppError>
+where
+    PgPool: FromRef<S>,
+    AuthConfig: FromRef<S>,
+{
+    let pool = PgPool::from_ref(state);
+    match credentials {
+        Credentials::Session(token) => {
+            authenticate_session(&token, pool, &AuthConfig::from_ref(state))
+                .await
+                .map(Actor::Admin)
+        }
+        Credentials::ApiKey(key) => authenticate_api_key(&key, pool).await.map(Actor::ApiKey),
+    }
+}
+
+async fn authenticate_session(
+    token: &str,
+    pool: PgPool,
+    config: &AuthConfig,
+) -> Result<CurrentAdmin, AppError> {
+    let repo = AdminRepository::new(pool);
+
+    let session = repo
+        .find_session(&hash_token(token))
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("Invalid or expired session".to_string()))?;
+
+    let now = Utc::now();
+    let idle_timeout = Duration::from_std(config.session_idle_timeout)
+        .map_err(|e| anyhow::anyhow!("invalid session idle timeout: {}", e))?;
+    let expired = session.expires_at <= now;
+    let idle = session.last_activity_at + idle_timeout <= now;
+
+    if expired || idle || !session.is_active {
+        info!(
+            "🔒 Session {} of '{}' rejected (expired: {}, idle: {}, active: {})",
+            session.session_id, session.username, expired, idle, session.is_active
+        );
+        repo.delete_session(session.session_id).await?;
+        return Err(AppError::Unauthorized(
+            "Invalid or expired session".to_string(),
+        ));
+    }
+
+    if now - session.last_activity_at >= Duration::seconds(TOUCH_INTERVAL_SECS) {
+        repo.touch_session(session.session_id).await?;
+    }
+
+    Ok(CurrentAdmin {
+        admin_id: session.admin_id,
+        username: session.username,
+        role: session.role,
+        session_id: session.session_id,
+    })
+}
+
+// 💡 無効化・期限切れのキーは行を残したまま 401 にする（セッションと違い削除しない）
+async fn authenticate_api_key(key: &str, pool: PgPool) -> Result<CurrentApiKey, AppError> {
+    let repo = ApiKeyRepository::new(pool);
+
+    let api_key = repo
+        .find_by_hash(&hash_token(key))
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("Invalid API key".to_string()))?;
+
+    let expired = api_key.is_expired(Utc::now());
+    if expired || !api_key.is_active {
+        info!(
+            "🔒 API key {} ({}) rejected (expired: {}, active: {})",
+            api_key.api_key_id, api_key.key_name, expired, api_key.is_active
+        );
+        return Err(AppError::Unauthorized(
+            "API key is revoked or expired".to_string(),
+        ));
+    }
+
+    repo.touch(api_key.api_key_id).await?;
+
+    Ok(CurrentApiKey {
+        api_key_id: api_key.api_key_id,
+        key_name: api_key.key_name,
+        scopes: api_key.permissions,
+        created_by: api_key.created_by,
+    })
+}
+
+// --------------------------------------------------------
+// credentials: リクエストから資格情報を取り出す
+// --------------------------------------------------------
+//
+// 💡 優先順位: X-API-Key → Authorization → Cookie
+// 💡 Authorization: Bearer の値が blog_ で始まればAPIキーとして扱う
+fn credentials(parts: &Parts) -> Option<Credentials> {
+    let api_key = parts
+        .headers
+        .get(&X_API_KEY)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+    if let Some(key) = api_key {
+        return Some(Credentials::ApiKey(key.to_string()));
+    }
+
+    let bearer = parts
+        .headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(bearer_token);
+    if let Some(token) = bearer {
+        return Some(if is_api_key(token) {
+            Credentials::ApiKey(token.to_string())
+        } else {
+            Credentials::Session(token.to_string())
+        });
+    }
+
+    parts
+        .headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|header| cookie_value(header, SESSION_COOKIE))
+        .filter(|token| !token.is_empty())
+        .map(|token| Credentials::Session(token.to_string()))
+}
+
+/// "Bearer <token>" から <token> を取り出す（スキーム名は大文字小文字を区別しない）
+fn bearer_token(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then_some(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_token() {
+        assert_eq!(bearer_token("Bearer abc"), Some("abc"));
+        assert_eq!(bearer_token("bearer  abc "), Some("abc"));
+        assert_eq!(bearer_token("Basic abc"), None);
+        assert_eq!(bearer_token("Bearer"), None);
+    }
+}
@@ -0,0 +1,110 @@
+// ============================================
+// ClientInfo（クライアントの情報）
+// ============================================
+// ログイン時にセッションへ記録する IPアドレス と User-Agent を取り出す
+//
+// 💡 IPアドレスの決め方:
+// 1. X-Forwarded-For の右から auth.trusted_proxy_hops 番目（ALB などのロードバランサー経由の場合）
+// 2. TCP接続の相手（ConnectInfo、main.rs で有効にしている）
+//
+// 💡 X-Forwarded-For の先頭は使わない（例: "1.2.3.4, 203.0.113.7"）
+// | 値                  | 書いたのは                                 |
+// |---------------------|--------------------------------------------|
+// | 1.2.3.4（先頭）     | クライアント（自由に書けるので偽装できる） |
+// | 203.0.113.7（右端） | ALB（実際の接続元を付け足す）              |
+// - 信頼できるプロキシが付け足した値だけを数える（ALB 1段なら一番右）
+// - 値が段数より少ない（プロキシを通っていない）ときは ConnectInfo を使う
+//
+// 💡 取り出せなくてもエラーにはしない（記録用の情報なので None にする）
+// - IPアドレスとして読めない X-Forwarded-For は無視する（sessions / audit_logs の VARCHAR(45) に収めるため）
+
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+};
+
+use axum::{
+    extract::{ConnectInfo, FromRef, FromRequestParts},
+    http::{header::USER_AGENT, request::Parts},
+};
+
+use crate::config::AuthConfig;
+
+/// X-Forwarded-For ヘッダー名
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// User-Agent の最大長（極端に長いヘッダーをそのまま保存しない）
+const MAX_USER_AGENT_LEN: usize = 512;
+
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// X-Forwarded-For から、信頼できるプロキシが記録したクライアントのIPアドレスを取り出す
+///
+/// trusted_hops 段のプロキシがそれぞれ右端に付け足すので、右から trusted_hops 番目を使う
+fn forwarded_client_ip(header: &str, trusted_hops: usize) -> Option<IpAddr> {
+    if trusted_hops == 0 {
+        return None;
+    }
+    header
+        .rsplit(',')
+        .nth(trusted_hops - 1)
+        .and_then(|ip| ip.trim().parse().ok())
+}
+
+impl<S> FromRequestParts<S> for ClientInfo
+where
+    S: Send + Sync,
+    AuthConfig: FromRef<S>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let config = AuthConfig::from_ref(state);
+        let forwarded = parts
+            .headers
+            .get(X_FORWARDED_FOR)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| forwarded_client_ip(value, config.trusted_proxy_hops))
+            .map(|ip| ip.to_string());
+        let ip_address = forwarded.or_else(|| {
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip().to_string())
+        });
+
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|ua| ua.chars().take(MAX_USER_AGENT_LEN).collect());
+
+        Ok(Self {
+            ip_address,
+            user_agent,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forwarded_client_ip() {
+        // 先頭に偽の値を書かれても、ALB が付け足した右端を使う
+        let header = "6.6.6.6, 203.0.113.7";
+        assert_eq!(forwarded_client_ip(header, 1), Some("203.0.113.7".parse().unwrap()));
+        // プロキシが2段なら右から2番目
+        let header = "6.6.6.6, 203.0.113.7, 10.0.0.2";
+        assert_eq!(forwarded_client_ip(header, 2), Some("203.0.113.7".parse().unwrap()));
+        // 段数より値が少ない・0段・IPアドレスでない → None（ConnectInfo を使う）
+        assert_eq!(forwarded_client_ip("203.0.113.7", 2), None);
+        assert_eq!(forwarded_client_ip("203.0.113.7", 0), None);
+        assert_eq!(forwarded_client_ip("6.6.6.6, unknown", 1), None);
+    }
+}
@@ -0,0 +1,129 @@
+// ============================================
+// AppJson / AppPath / AppQuery / AppMultipart
+// ============================================
+// axum::Json / axum::extract::Path / axum::extract::Query / axum::extract::Multipart と
+// 同じ使い方で、失敗時のレスポンスだけ AppError（共通JSON）に差し替える
+//
+// 💡 使用例:
+//   pub async fn create_locale(AppJson(payload): AppJson<CreateLocaleRequest>) -> ...
+
+use axum::{
+    extract::{
+        multipart::{MultipartError, MultipartRejection},
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+        FromRequest, FromRequestParts, Multipart, Request,
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::error::AppError;
+
+/// 失敗時に AppError を返す JSON エクストラクター
+#[derive(Debug, FromRequest)]
+#[from_request(via(axum::Json), rejection(AppError))]
+pub struct AppJson<T>(pub T);
+
+impl<T: Serialize> IntoResponse for AppJson<T> {
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+// --------------------------------------------------------
+// nullable: 部分更新（PATCH）の「省略 / null / 値」を区別する
+// --------------------------------------------------------
+//
+// 💡 #[serde(default, deserialize_with = "nullable")] を付けた Option<Option<T>> で受け取る
+// | JSON         | 値             | 意味                   |
+// |--------------|----------------|------------------------|
+// | 項目なし     | None           | 変更しない             |
+// | "x": null    | Some(None)     | 消す（NULL にする）    |
+// | "x": 値      | Some(Some(値)) | 置き換える             |
+//
+// 💡 serde の標準では null も項目なしも None になり、消すことができない
+pub fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// 失敗時に AppError を返す Path エクストラクター
+#[derive(Debug, FromRequestParts)]
+#[from_request(via(axum::extract::Path), rejection(AppError))]
+pub struct AppPath<T>(pub T);
+
+/// 失敗時に AppError を返す Query エクストラクター
+#[derive(Debug, FromRequestParts)]
+#[from_request(via(axum::extract::Query), rejection(AppError))]
+pub struct AppQuery<T>(pub T);
+
+/// 失敗時に AppError を返す Multipart エクストラクター（ファイルのアップロード）
+///
+/// 💡 Multipart はジェネリックではないため、derive の via ではなく手で実装する
+pub struct AppMultipart(pub Multipart);
+
+impl<S: Send + Sync> FromRequest<S> for AppMultipart {
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self(Multipart::from_request(req, state).await?))
+    }
+}
+
+impl From<JsonRejection> for AppError {
+    fn from(rejection: JsonRejection) -> Self {
+        AppError::BadRequest(rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for AppError {
+    fn from(rejection: PathRejection) -> Self {
+        AppError::BadRequest(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for AppError {
+    fn from(rejection: QueryRejection) -> Self {
+        AppError::BadRequest(rejection.body_text())
+    }
+}
+
+impl From<MultipartRejection> for AppError {
+    fn from(rejection: MultipartRejection) -> Self {
+        AppError::BadRequest(rejection.body_text())
+    }
+}
+
+// 💡 読み込み中のエラー（ボディの上限超えは 413、それ以外は形式の誤りとして 400）
+impl From<MultipartError> for AppError {
+    fn from(error: MultipartError) -> Self {
+        if error.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            AppError::PayloadTooLarge(error.body_text())
+        } else {
+            AppError::BadRequest(error.body_text())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Patch {
+        #[serde(default, deserialize_with = "nullable")]
+        url: Option<Option<String>>,
+    }
+
+    #[test]
+    fn test_nullable() {
+        let parse = |json: &str| serde_json::from_str::<Patch>(json).unwrap().url;
+        assert_eq!(parse("{}"), None);
+        assert_eq!(parse(r#"{"url": null}"#), Some(None));
+        assert_eq!(parse(r#"{"url": "a.png"}"#), Some(Some("a.png".to_string())));
+    }
+}
@@ -0,0 +1,281 @@
+// ============================================
+// ResolvedLocale（言語ネゴシエーション）
+// ============================================
+// リクエストから「どの言語で返すか」を決めるエクストラクター
+//
+// 💡 優先順位:
+// 1. クエリパラメータ  ?lang=en
+// 2. Cookie           lang=en
+// 3. Accept-Language  en-US,en;q=0.9,ja;q=0.8（q値の高い順）
+// 4. デフォルト言語    LocaleRepository::find_default()
+//
+// 💡 候補は LocaleRepository::find_active() の言語だけ
+// - 無効な言語・存在しない言語が指定されても無視して次の候補へ進む
+// - en-US のような地域付きの指定は、完全一致がなければ en に一致させる
+//
+// 💡 使用例:
+//   pub async fn list_topics(locale: ResolvedLocale, ...) -> ... {
+//       repo.find_all_localized(&locale.code, &locale.default_code, ...)
+//   }
+//
+// 💡 決まった言語は Content-Language ヘッダーにも自動で設定される
+//   （middleware/content_language.rs）
+// - 翻訳がなく別の言語で代用したときは、ハンドラーが locale.served(...) で返した言語を記録する
+
+use axum::{
+    extract::{FromRef, FromRequestParts, Query},
+    http::{
+        header::{ACCEPT_LANGUAGE, COOKIE},
+        request::Parts,
+    },
+};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::IntoParams;
+
+use crate::{
+    error::AppError, middleware::content_language::NegotiatedLocale,
+    repositories::LocaleRepository,
+};
+
+/// 言語指定に使うCookie名
+pub const LANG_COOKIE: &str = "lang";
+
+/// 言語指定のクエリパラメータ（OpenAPIのドキュメント用にも使う）
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct LangQuery {
+    /// 言語コード（省略時は Cookie → Accept-Language → デフォルト言語の順で決まる）
+    #[param(example = "en")]
+    pub lang: Option<String>,
+}
+
+/// 言語がどこから決まったか
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocaleSource {
+    Query,
+    Cookie,
+    AcceptLanguage,
+    Default,
+}
+
+/// 解決済みの言語
+#[derive(Debug, Clone)]
+pub struct ResolvedLocale {
+    /// 使う言語コード（有効な言語のどれか）
+    pub code: String,
+    /// サイトのデフォルト言語コード（翻訳がない場合のフォールバック先）
+    pub default_code: String,
+    /// 言語を決めた情報源
+    pub source: LocaleSource,
+    /// Content-Language の書き込み先（middleware/content_language.rs）
+    negotiated: NegotiatedLocale,
+}
+
+impl ResolvedLocale {
+    /// 実際に返した翻訳の言語を Content-Language に記録する
+    ///
+    /// 💡 None（どの言語の翻訳もない）は記録しない
+    pub fn served(&self, code: Option<&str>) {
+        if let Some(code) = code {
+            self.negotiated.served(code);
+        }
+    }
+}
+
+impl<S> FromRequestParts<S> for ResolvedLocale
+where
+    PgPool: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let repo = LocaleRepository::new(PgPool::from_ref(state));
+
+        let default_code = repo
+            .find_default()
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("no default locale is configured"))?
+            .code;
+        let active: Vec<String> = repo
+            .find_active()
+            .await?
+            .into_iter()
+            .map(|locale| locale.code)
+            .collect();
+
+        let query = Query::<LangQuery>::try_from_uri(&parts.uri)
+            .ok()
+            .and_then(|Query(q)| q.lang);
+        let cookie = parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .find_map(|header| cookie_value(header, LANG_COOKIE));
+        let accept_language = parts
+            .headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok());
+
+        let (code, source) = negotiate(query.as_deref(), cookie, accept_language, &active)
+            .map(|(code, source)| (code.to_string(), source))
+            .unwrap_or_else(|| (default_code.clone(), LocaleSource::Default));
+
+        // 💡 ミドルウェアを通らない場合（テストなど）は、どこにも付かない書き込み先を使う
+        let negotiated = parts
+            .extensions
+            .get::<NegotiatedLocale>()
+            .cloned()
+            .unwrap_or_default();
+        negotiated.set(&code);
+
+        Ok(Self {
+            code,
+            default_code,
+            source,
+            negotiated,
+        })
+    }
+}
+
+// --------------------------------------------------------
+// negotiate: 優先順位に従って有効な言語を1つ選ぶ
+// --------------------------------------------------------
+//
+// 💡 どれにも一致しなければ None（呼び出し側でデフォルト言語を使う）
+fn negotiate<'a>(
+    query: Option<&str>,
+    cookie: Option<&str>,
+    accept_language: Option<&str>,
+    active: &'a [String],
+) -> Option<(&'a str, LocaleSource)> {
+    if let Some(code) = query.and_then(|tag| match_locale(tag, active)) {
+        return Some((code, LocaleSource::Query));
+    }
+    if let Some(code) = cookie.and_then(|tag| match_locale(tag, active)) {
+        return Some((code, LocaleSource::Cookie));
+    }
+
+    parse_accept_language(accept_language?)
+        .into_iter()
+        .find_map(|tag| match_locale(tag, active))
+        .map(|code| (code, LocaleSource::AcceptLanguage))
+}
+
+// --------------------------------------------------------
+// match_locale: 言語タグ → 有効な言語コード
+// --------------------------------------------------------
+//
+// 💡 一致のルール（大文字小文字は区別しない）:
+// 1. 完全一致        en-US → en-US
+// 2. 主言語だけで一致 en-US → en
+fn match_locale<'a>(tag: &str, active: &'a [String]) -> Option<&'a str> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return None;
+    }
+
+    if let Some(code) = active.iter().find(|code| code.eq_ignore_ascii_case(tag)) {
+        return Some(code);
+    }
+
+    let primary = tag.split(['-', '_']).next()?;
+    active
+        .iter()
+        .find(|code| code.eq_ignore_ascii_case(primary))
+        .map(String::as_str)
+}
+
+// --------------------------------------------------------
+// parse_accept_language: Accept-Language をq値の高い順に並べる
+// --------------------------------------------------------
+//
+// 💡 例: "ja;q=0.5, en-US, en;q=0.8, *;q=0.1"
+//   → ["en-US", "en", "ja"]
+// - q値を省略した場合は 1.0
+// - q=0 は「この言語は不要」なので除外
+// - * はデフォルト言語に任せるので除外
+// - 同じq値なら書かれた順を保つ（安定ソート）
+fn parse_accept_language(header: &str) -> Vec<&str> {
+    let mut tags: Vec<(&str, f32)> = header
+        .split(',')
+        .filter_map(|item| {
+            let mut pieces = item.split(';');
+            let tag = pieces.next()?.trim();
+            let quality = pieces
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+
+            (!tag.is_empty() && tag != "*" && quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+
+    tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+    tags.into_iter().map(|(tag, _)| tag).collect()
+}
+
+// --------------------------------------------------------
+// cookie_value: Cookieヘッダーから指定した名前の値を取り出す
+// --------------------------------------------------------
+pub(crate) fn cookie_value<'a>(header: &'a str, name: &str) -> Option<&'a str> {
+    header.split(';').find_map(|pair| {
+        let (key, value) = pair.trim().split_once('=')?;
+        (key == name).then_some(value.trim_matches('"'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn active() -> Vec<String> {
+        vec!["ja".to_string(), "en".to_string(), "zh-TW".to_string()]
+    }
+
+    #[test]
+    fn test_parse_accept_language() {
+        assert_eq!(
+            parse_accept_language("ja;q=0.5, en-US, en;q=0.8, *;q=0.1"),
+            vec!["en-US", "en", "ja"]
+        );
+        assert_eq!(parse_accept_language("fr;q=0, en"), vec!["en"]);
+        assert_eq!(parse_accept_language("en;q=abc, ja"), vec!["ja"]);
+        assert!(parse_accept_language("").is_empty());
+    }
+
+    #[test]
+    fn test_match_locale() {
+        let active = active();
+        assert_eq!(match_locale("EN", &active), Some("en"));
+        assert_eq!(match_locale("en-GB", &active), Some("en"));
+        assert_eq!(match_locale("zh-tw", &active), Some("zh-TW"));
+        assert_eq!(match_locale("fr", &active), None);
+        assert_eq!(match_locale("", &active), None);
+    }
+
+    #[test]
+    fn test_negotiate_priority() {
+        let active = active();
+        assert_eq!(
+            negotiate(Some("en"), Some("ja"), Some("ja"), &active),
+            Some(("en", LocaleSource::Query))
+        );
+        // 無効なクエリは無視して Cookie へ
+        assert_eq!(
+            negotiate(Some("xx"), Some("ja"), Some("en"), &active),
+            Some(("ja", LocaleSource::Cookie))
+        );
+        assert_eq!(
+            negotiate(None, None, Some("fr, en-US;q=0.9"), &active),
+            Some(("en", LocaleSource::AcceptLanguage))
+        );
+        assert_eq!(negotiate(None, None, Some("fr"), &active), None);
+    }
+
+    #[test]
+    fn test_cookie_value() {
+        assert_eq!(cookie_value("theme=dark; lang=en", "lang"), Some("en"));
+        assert_eq!(cookie_value("language=ja", "lang"), None);
+    }
+}
@@ -0,0 +1,31 @@
+// ============================================
+// Extractors Module（独自エクストラクター）
+// ============================================
+//
+// 💡 エクストラクターとは?
+// - ハンドラーの引数でリクエストから値を取り出す仕組み
+// - 例: State<PgPool>, Path<String>, Json<T>
+//
+// 💡 独自に定義する理由:
+// - axum標準のJson/Path/Queryは失敗時にプレーンテキストのエラーを返す
+// - ここで包んで、失敗時も AppError の共通JSONを返すようにする
+//
+// 💡 ResolvedLocale:
+// - ?lang= / Cookie / Accept-Language からレスポンスの言語を決める
+//
+// 💡 CurrentAdmin / Actor:
+// - CurrentAdmin: セッショントークンを検証してログイン中の管理者を取り出す
+// - Actor: 管理者またはAPIキー（X-API-Key / Authorization: Bearer blog_...）
+// - Authorized<P> は役割・スコープも確認する（auth::policy）
+
+pub mod auth;
+pub mod client;
+pub mod json;
+pub mod locale;
+
+pub use auth::{
+    require_login_for_unpublished, Actor, Authorized, CurrentAdmin, SESSION_COOKIE, X_API_KEY,
+};
+pub use client::ClientInfo;
+pub use json::{nullable, AppJson, AppMultipart, AppPath, AppQuery};
+pub use locale::{LangQuery, ResolvedLocale};
@@ -0,0 +1,235 @@
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+// ============================================
+// RSS 2.0 / Atom フィードの組み立て
+// ============================================
+//
+// 💡 XML は文字列で組み立てる（値はすべて escape() を通す）
+//
+// 💡 2つの形式の違い（このブログで使う部分）:
+// | 項目           | RSS 2.0                      | Atom (RFC 4287)             |
+// |----------------|------------------------------|-----------------------------|
+// | 言語           | <language>ja</language>      | <feed xml:lang="ja">        |
+// | フィードの更新 | <lastBuildDate>（RFC 2822）  | <updated>（RFC 3339）       |
+// | 記事の公開日時 | <pubDate>                    | <published>                 |
+// | 記事の更新日時 | なし                         | <updated>                   |
+// | 記事のID       | <guid>                       | <id>                        |
+
+/// フィード全体
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    /// 言語コード（locales.code）
+    pub language: String,
+    /// 公開サイトのページ（フィードの内容と同じ一覧）
+    pub site_url: String,
+    /// このフィード自身のURL
+    pub self_url: String,
+    /// いちばん新しい記事の更新日時
+    pub updated: DateTime<Utc>,
+    pub entries: Vec<FeedEntry>,
+}
+
+/// フィードの1記事
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    pub title: String,
+    /// 記事のURL（ID にも使う）
+    pub url: String,
+    pub summary: Option<String>,
+    pub published: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    /// トピック名
+    pub categories: Vec<String>,
+}
+
+/// フィードの形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    /// Content-Type ヘッダーの値
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Rss => "application/rss+xml; charset=utf-8",
+            Self::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+
+    /// URLの最後の部分（/feeds/ja/rss.xml の rss.xml）
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::Rss => "rss.xml",
+            Self::Atom => "atom.xml",
+        }
+    }
+
+    pub fn render(self, feed: &Feed) -> String {
+        match self {
+            Self::Rss => rss(feed),
+            Self::Atom => atom(feed),
+        }
+    }
+}
+
+// --------------------------------------------------------
+// rss: RSS 2.0
+// --------------------------------------------------------
+//
+// 💡 <atom:link rel="self"> は RSS の仕様外だが、フィードの検証ツールが推奨している
+fn rss(feed: &Feed) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\">\n<channel>\n");
+    let _ = writeln!(xml, "<title>{}</title>", escape(&feed.title));
+    let _ = writeln!(xml, "<link>{}</link>", escape(&feed.site_url));
+    let _ = writeln!(xml, "<description>{}</description>", escape(&feed.description));
+    let _ = writeln!(xml, "<language>{}</language>", escape(&feed.language));
+    let _ = writeln!(xml, "<lastBuildDate>{}</lastBuildDate>", feed.updated.to_rfc2822());
+    let _ = writeln!(
+        xml,
+        "<atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>",
+        escape(&feed.self_url)
+    );
+
+    for entry in &feed.entries {
+        xml.push_str("<item>\n");
+        let _ = writeln!(xml, "<title>{}</title>", escape(&entry.title));
+        let _ = writeln!(xml, "<link>{}</link>", escape(&entry.url));
+        let _ = writeln!(xml, "<guid isPermaLink=\"true\">{}</guid>", escape(&entry.url));
+        let _ = writeln!(xml, "<pubDate>{}</pubDate>", entry.published.to_rfc2822());
+        if let Some(summary) = &entry.summary {
+            let _ = writeln!(xml, "<description>{}</description>", escape(summary));
+        }
+        for category in &entry.categories {
+            let _ = writeln!(xml, "<category>{}</category>", escape(category));
+        }
+        xml.push_str("</item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+// --------------------------------------------------------
+// atom: Atom (RFC 4287)
+// --------------------------------------------------------
+//
+// 💡 <id> はフィード・記事を永続的に識別する値（ここではURLを使う）
+fn atom(feed: &Feed) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"{}\">",
+        escape(&feed.language)
+    );
+    let _ = writeln!(xml, "<title>{}</title>", escape(&feed.title));
+    let _ = writeln!(xml, "<subtitle>{}</subtitle>", escape(&feed.description));
+    let _ = writeln!(xml, "<id>{}</id>", escape(&feed.self_url));
+    let _ = writeln!(xml, "<link rel=\"self\" type=\"application/atom+xml\" href=\"{}\"/>", escape(&feed.self_url));
+    let _ = writeln!(xml, "<link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>", escape(&feed.site_url));
+    let _ = writeln!(xml, "<updated>{}</updated>", rfc3339(feed.updated));
+    let _ = writeln!(xml, "<author><name>{}</name></author>", escape(&feed.title));
+
+    for entry in &feed.entries {
+        xml.push_str("<entry>\n");
+        let _ = writeln!(xml, "<title>{}</title>", escape(&entry.title));
+        let _ = writeln!(xml, "<id>{}</id>", escape(&entry.url));
+        let _ = writeln!(xml, "<link rel=\"alternate\" type=\"text/html\" href=\"{}\"/>", escape(&entry.url));
+        let _ = writeln!(xml, "<published>{}</published>", rfc3339(entry.published));
+        let _ = writeln!(xml, "<updated>{}</updated>", rfc3339(entry.updated));
+        if let Some(summary) = &entry.summary {
+            let _ = writeln!(xml, "<summary>{}</summary>", escape(summary));
+        }
+        for category in &entry.categories {
+            let _ = writeln!(xml, "<category term=\"{}\"/>", escape(category));
+        }
+        xml.push_str("</entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn rfc3339(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+// --------------------------------------------------------
+// escape: XMLの特殊文字を置き換える（要素の中身・属性の値の両方に使える）
+// --------------------------------------------------------
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // 💡 XML 1.0 で使えない制御文字は取り除く（タブ・改行は使える）
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample_feed() -> Feed {
+        let published = Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap();
+        let updated = Utc.with_ymd_and_hms(2026, 10, 2, 12, 30, 0).unwrap();
+        Feed {
+            title: "Kohta Tech Blog".to_string(),
+            description: "Rust & Web".to_string(),
+            language: "ja".to_string(),
+            site_url: "https://blog.example.com/ja/blog".to_string(),
+            self_url: "https://api.blog.example.com/feeds/ja/atom.xml".to_string(),
+            updated,
+            entries: vec![FeedEntry {
+                title: "Rustの<所有権>".to_string(),
+                url: "https://blog.example.com/ja/blog/rust-ownership".to_string(),
+                summary: Some("借用 & ライフタイム".to_string()),
+                published,
+                updated,
+                categories: vec!["Rust".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(r#"a & <b> "c" 'd'"#), "a &amp; &lt;b&gt; &quot;c&quot; &apos;d&apos;");
+        assert_eq!(escape("tab\there\u{0}"), "tab\there");
+    }
+
+    #[test]
+    fn test_rss() {
+        let xml = FeedFormat::Rss.render(&sample_feed());
+        assert!(xml.contains("<language>ja</language>"));
+        assert!(xml.contains("<lastBuildDate>Fri, 2 Oct 2026 12:30:00 +0000</lastBuildDate>"));
+        assert!(xml.contains("<title>Rustの&lt;所有権&gt;</title>"));
+        assert!(xml.contains("<pubDate>Thu, 1 Oct 2026 09:00:00 +0000</pubDate>"));
+        assert!(xml.contains("<description>借用 &amp; ライフタイム</description>"));
+    }
+
+    #[test]
+    fn test_atom() {
+        let xml = FeedFormat::Atom.render(&sample_feed());
+        assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"ja\">"));
+        assert!(xml.contains("<updated>2026-10-02T12:30:00Z</updated>"));
+        assert!(xml.contains("<published>2026-10-01T09:00:00Z</published>"));
+        assert!(xml.contains("<category term=\"Rust\"/>"));
+    }
+}
@@ -0,0 +1,107 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use serde::Deserialize;
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::{
+        accounts::{create_admin as create_admin_account, AdminAccountInput},
+        policy::AdminOnly,
+    },
+    entities::Role,
+    error::{AppResult, ErrorResponse},
+    extractors::{AppJson, Authorized, ClientInfo},
+    handlers::auth::AdminResponse,
+};
+
+// ============================================
+// リクエスト用の構造体
+// ============================================
+
+/// 管理者作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateAdminRequest {
+    /// ユーザー名（英数字・ハイフン・アンダースコア・ドット、3〜100文字）
+    #[schema(example = "hanako")]
+    pub username: String,
+    /// メールアドレス
+    #[schema(example = "hanako@example.com")]
+    pub email: String,
+    /// パスワード（12〜128文字）
+    #[schema(example = "correct horse battery staple")]
+    pub password: String,
+    /// 表示名
+    #[schema(example = "Hanako")]
+    pub full_name: Option<String>,
+    /// 役割（省略時は author）
+    #[serde(default = "default_role")]
+    pub role: Role,
+}
+
+fn default_role() -> Role {
+    Role::Author
+}
+
+// ============================================
+// ハンドラー関数
+// ============================================
+
+// --------------------------------------------------------
+// create_admin: 管理者アカウントの作成
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/admins
+//
+// 💡 admin のみ実行できる
+// 💡 最初の管理者はAPIでは作れない（ログインが必要なため）
+//    → CLI の `backend admin create` で作成する
+//
+// 💡 使用例:
+//   curl -X POST http://localhost:8000/api/v1/admins \
+//     -H 'Authorization: Bearer <token>' \
+//     -H 'Content-Type: application/json' \
+//     -d '{"username": "hanako", "email": "hanako@example.com", "password": "correct horse battery staple", "role": "author"}'
+#[utoipa::path(
+    post,
+    path = "/api/v1/admins",
+    tag = "admins",
+    summary = "管理者作成",
+    description = "管理者アカウントを作成します。パスワードは Argon2id でハッシュ化して保存されます",
+    request_body = CreateAdminRequest,
+    responses(
+        (status = 201, description = "作成された管理者", body = AdminResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 409, description = "ユーザー名またはメールアドレスが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn create_admin(
+    State(pool): State<PgPool>,
+    current: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppJson(payload): AppJson<CreateAdminRequest>,
+) -> AppResult<(StatusCode, Json<AdminResponse>)> {
+    let admin = create_admin_account(
+        &pool,
+        AdminAccountInput {
+            username: payload.username,
+            email: payload.email,
+            password: payload.password,
+            full_name: payload.full_name,
+            role: payload.role,
+        },
+        &current.audit_context(&client),
+    )
+    .await?;
+
+    info!(
+        "👤 '{}' created administrator '{}' ({})",
+        current.name(), admin.username, admin.role
+    );
+
+    Ok((StatusCode::CREATED, Json(admin.into())))
+}
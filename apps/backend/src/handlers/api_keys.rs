@@ -0,0 +1,258 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::ToSchema;
+
+use crate::{
+    auth::{
+        policy::AdminOnly,
+        token::{api_key_display_prefix, generate_api_key, hash_token},
+    },
+    entities::{ApiKey, ApiScope},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{AppJson, AppPath, Authorized, ClientInfo},
+    repositories::{ApiKeyRepository, NewApiKey},
+};
+
+/// キー名の最大長（api_keys.key_name は VARCHAR(100)）
+const MAX_KEY_NAME_LEN: usize = 100;
+
+// ============================================
+// リクエスト・レスポンス用の構造体
+// ============================================
+
+/// APIキー発行リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// 用途がわかる名前
+    #[schema(example = "static-site-generator")]
+    pub name: String,
+    /// 許可するスコープ（1つ以上）
+    #[schema(example = json!(["read"]))]
+    pub scopes: Vec<ApiScope>,
+    /// 有効期限（省略時は無期限）
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// APIキーのレスポンス（キーそのもの・ハッシュは含めない）
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiKeyResponse {
+    #[schema(example = 1)]
+    pub api_key_id: i32,
+    #[schema(example = "static-site-generator")]
+    pub name: String,
+    /// キーの先頭部分（どのキーか見分けるため）
+    #[schema(example = "blog_q1Xo3c9")]
+    pub key_prefix: String,
+    pub scopes: Vec<ApiScope>,
+    /// 使えるかどうか（無効化・期限切れなら false）
+    #[schema(example = true)]
+    pub is_usable: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// 発行した管理者のID
+    #[schema(example = 1)]
+    pub created_by: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            is_usable: key.is_active && !key.is_expired(Utc::now()),
+            api_key_id: key.api_key_id,
+            name: key.key_name,
+            key_prefix: key.key_prefix,
+            scopes: key.permissions,
+            expires_at: key.expires_at,
+            created_by: key.created_by,
+            created_at: key.created_at,
+            last_used_at: key.last_used_at,
+            revoked_at: key.revoked_at,
+        }
+    }
+}
+
+/// APIキー発行レスポンス
+///
+/// 💡 api_key（平文）はこのレスポンスでしか返さない
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreatedApiKeyResponse {
+    /// APIキー（X-API-Key または Authorization: Bearer で送る。再表示はできません）
+    #[schema(example = "blog_q1Xo3c9mO3Vd0vC3f7uV6b5m2Jd8zT4eYk1sR0aWnHg")]
+    pub api_key: String,
+    #[serde(flatten)]
+    pub key: ApiKeyResponse,
+}
+
+/// APIキー一覧のレスポンス
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ApiKeysListResponse {
+    pub api_keys: Vec<ApiKeyResponse>,
+}
+
+// ============================================
+// ハンドラー関数
+// ============================================
+//
+// 💡 APIキーの管理は admin がログインして行う（APIキー自身では呼べない）
+
+// --------------------------------------------------------
+// list_api_keys: APIキー一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/api-keys
+#[utoipa::path(
+    get,
+    path = "/api/v1/api-keys",
+    tag = "api-keys",
+    summary = "APIキー一覧",
+    description = "発行済みのAPIキーを新しい順に返します（無効化したものも含む）。キーそのものは返しません",
+    responses(
+        (status = 200, description = "APIキー一覧", body = ApiKeysListResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn list_api_keys(
+    State(pool): State<PgPool>,
+    _admin: Authorized<AdminOnly>,
+) -> AppResult<Json<ApiKeysListResponse>> {
+    let keys = ApiKeyRepository::new(pool).find_all().await?;
+
+    Ok(Json(ApiKeysListResponse {
+        api_keys: keys.into_iter().map(Into::into).collect(),
+    }))
+}
+
+// --------------------------------------------------------
+// create_api_key: APIキーの発行
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/api-keys
+//
+// 💡 使用例:
+//   curl -X POST http://localhost:8000/api/v1/api-keys \
+//     -H 'Authorization: Bearer <session token>' \
+//     -H 'Content-Type: application/json' \
+//     -d '{"name": "static-site-generator", "scopes": ["read"]}'
+#[utoipa::path(
+    post,
+    path = "/api/v1/api-keys",
+    tag = "api-keys",
+    summary = "APIキー発行",
+    description = "APIキーを発行します。キーはこのレスポンスでのみ返され、サーバーにはハッシュだけが保存されます",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "発行したAPIキー", body = CreatedApiKeyResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn create_api_key(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppJson(payload): AppJson<CreateApiKeyRequest>,
+) -> AppResult<(StatusCode, Json<CreatedApiKeyResponse>)> {
+    let name = payload.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_KEY_NAME_LEN {
+        return Err(AppError::BadRequest(format!(
+            "API key name must be 1-{} characters",
+            MAX_KEY_NAME_LEN
+        )));
+    }
+
+    let mut scopes = payload.scopes;
+    scopes.sort_by_key(|scope| scope.as_str());
+    scopes.dedup();
+    if scopes.is_empty() {
+        return Err(AppError::BadRequest(
+            "Specify at least one scope (read, write, delete)".to_string(),
+        ));
+    }
+    if payload.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return Err(AppError::BadRequest(
+            "expires_at must be in the future".to_string(),
+        ));
+    }
+
+    let api_key = generate_api_key();
+    let created = ApiKeyRepository::new(pool)
+        .create(
+            &NewApiKey {
+                key_name: name.to_string(),
+                key_prefix: api_key_display_prefix(&api_key),
+                api_key_hash: hash_token(&api_key),
+                permissions: scopes,
+                expires_at: payload.expires_at,
+                created_by: admin.admin_id(),
+            },
+            &admin.audit_context(&client),
+        )
+        .await?;
+
+    info!(
+        "🔑 '{}' issued API key {} ({})",
+        admin.name(),
+        created.api_key_id,
+        created.key_name
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedApiKeyResponse {
+            api_key,
+            key: created.into(),
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// revoke_api_key: APIキーの無効化
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: DELETE /api/v1/api-keys/{id}
+//
+// 💡 行は削除せず is_active = false にする（最終使用日時などを後から確認できるように）
+#[utoipa::path(
+    delete,
+    path = "/api/v1/api-keys/{id}",
+    tag = "api-keys",
+    summary = "APIキー無効化",
+    description = "APIキーを無効化します。無効化したキーは以降のリクエストで 401 になります",
+    params(
+        ("id" = i32, Path, description = "APIキーのID")
+    ),
+    responses(
+        (status = 200, description = "無効化したAPIキー", body = ApiKeyResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "APIキーが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn revoke_api_key(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppPath(id): AppPath<i32>,
+) -> AppResult<Json<ApiKeyResponse>> {
+    let key = ApiKeyRepository::new(pool)
+        .revoke(id, &admin.audit_context(&client))
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("API key {} not found", id)))?;
+
+    info!("🔑 '{}' revoked API key {} ({})", admin.name(), key.api_key_id, key.key_name);
+
+    Ok(Json(key.into()))
+}
@@ -0,0 +1,176 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, Uri},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::PgPool;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::AdminOnly,
+    entities::{AuditAction, AuditLog, AuditTable, ChangeSource},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{AppQuery, Authorized},
+    pagination::{ListQuery, PageMeta},
+    repositories::{AuditLogFilter, AuditLogRepository, AuditLogSort},
+};
+
+// ============================================
+// リクエスト・レスポンス用の構造体
+// ============================================
+
+/// 監査ログ一覧の絞り込み条件
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditLogQuery {
+    /// テーブル名（locales / topics / blog_posts / administrators / api_keys / books / book_chapters / media）
+    pub table: Option<AuditTable>,
+    /// レコードのID（table と一緒に指定）
+    #[param(example = 1)]
+    pub record_id: Option<i32>,
+    /// 変更した管理者のID（その管理者が発行したAPIキーでの変更も含む）
+    #[param(example = 1)]
+    pub changed_by: Option<i32>,
+    /// 変更に使ったAPIキーのID
+    pub api_key_id: Option<i32>,
+    /// この日時以降の変更（RFC 3339）
+    pub from: Option<DateTime<Utc>>,
+    /// この日時より前の変更（RFC 3339）
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// 監査ログのレスポンス
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    #[schema(example = 42)]
+    pub log_id: i32,
+    pub table_name: AuditTable,
+    /// 変更されたレコードのID
+    #[schema(example = 2)]
+    pub record_id: i32,
+    pub action: AuditAction,
+    /// 変更前のスナップショット（INSERTではnull）
+    #[schema(value_type = Option<Object>, example = json!({"code": "ja", "is_default": true}))]
+    pub old_values: Option<Value>,
+    /// 変更後のスナップショット（DELETEではnull）
+    #[schema(value_type = Option<Object>, example = json!({"code": "ja", "is_default": false}))]
+    pub new_values: Option<Value>,
+    /// 変更した管理者のID（APIキーの場合は発行した管理者、CLIではnull）
+    #[schema(example = 1)]
+    pub changed_by: Option<i32>,
+    /// 変更した管理者のユーザー名
+    #[schema(example = "kohta")]
+    pub changed_by_username: Option<String>,
+    /// 変更に使ったAPIキーのID
+    pub api_key_id: Option<i32>,
+    pub changed_via: ChangeSource,
+    #[schema(example = "203.0.113.10")]
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditLog> for AuditLogResponse {
+    fn from(log: AuditLog) -> Self {
+        Self {
+            log_id: log.log_id,
+            table_name: log.table_name,
+            record_id: log.record_id,
+            action: log.action,
+            old_values: log.old_values,
+            new_values: log.new_values,
+            changed_by: log.changed_by,
+            changed_by_username: log.changed_by_username,
+            api_key_id: log.api_key_id,
+            changed_via: log.changed_via,
+            ip_address: log.ip_address,
+            created_at: log.created_at,
+        }
+    }
+}
+
+/// 監査ログ一覧のレスポンス
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogsListResponse {
+    /// 監査ログ（新しい順）
+    pub audit_logs: Vec<AuditLogResponse>,
+    /// ページ情報（total, page, per_page, total_pages, next_cursor）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+}
+
+// ============================================
+// ハンドラー関数
+// ============================================
+
+// --------------------------------------------------------
+// list_audit_logs: 監査ログ一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/audit-logs
+//
+// 💡 使用例（デフォルト言語を誰がいつ変えたか）:
+//   curl 'http://localhost:8000/api/v1/audit-logs?table=locales' \
+//     -H 'Authorization: Bearer <session token>'
+//
+// 💡 デフォルトは新しい順のキーセット方式（next_cursor で次のページ）
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit-logs",
+    tag = "audit-logs",
+    summary = "監査ログ一覧",
+    description = "言語・トピック・記事・本・チャプター・管理者・APIキーの変更履歴を新しい順に返します。\
+                   テーブル・レコード・実行者・期間で絞り込めます",
+    params(AuditLogQuery, ListQuery),
+    responses(
+        (status = 200, description = "監査ログ一覧（Link ヘッダーに前後のページのURL）", body = AuditLogsListResponse),
+        (status = 400, description = "絞り込み・ページの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn list_audit_logs(
+    State(pool): State<PgPool>,
+    _admin: Authorized<AdminOnly>,
+    AppQuery(query): AppQuery<AuditLogQuery>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<AuditLogsListResponse>)> {
+    // record_id だけではどのテーブルのIDか分からない
+    if query.record_id.is_some() && query.table.is_none() {
+        return Err(AppError::BadRequest(
+            "record_id requires table".to_string(),
+        ));
+    }
+    if query.from.zip(query.to).is_some_and(|(from, to)| from >= to) {
+        return Err(AppError::BadRequest("from must be earlier than to".to_string()));
+    }
+
+    let params = list.resolve::<AuditLogSort>()?;
+    let filter = AuditLogFilter {
+        table: query.table,
+        record_id: query.record_id,
+        changed_by: query.changed_by,
+        api_key_id: query.api_key_id,
+        from: query.from,
+        to: query.to,
+    };
+
+    let page = AuditLogRepository::new(pool)
+        .find_page(&filter, &params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (audit_logs, pagination) = page.into_parts(Into::into);
+
+    Ok((
+        headers,
+        Json(AuditLogsListResponse {
+            audit_logs,
+            pagination,
+        }),
+    ))
+}
@@ -0,0 +1,303 @@
+use axum::{
+    extract::State,
+    http::{header::SET_COOKIE, HeaderMap, HeaderValue, StatusCode},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::{
+        password::{verify_password, MAX_PASSWORD_LEN},
+        token::{generate_token, hash_token},
+    },
+    config::AuthConfig,
+    entities::{Administrator, Role},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{AppJson, ClientInfo, CurrentAdmin, SESSION_COOKIE},
+    repositories::{AdminRepository, NewSession},
+};
+
+// ============================================
+// リクエスト・レスポンス用の構造体
+// ============================================
+
+/// ログインリクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct LoginRequest {
+    /// ユーザー名またはメールアドレス
+    #[schema(example = "kohta")]
+    pub username: String,
+    /// パスワード
+    #[schema(example = "correct horse battery staple")]
+    pub password: String,
+}
+
+/// 管理者のレスポンス（password_hash は含めない）
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AdminResponse {
+    #[schema(example = 1)]
+    pub admin_id: i32,
+    #[schema(example = "kohta")]
+    pub username: String,
+    #[schema(example = "kohta@example.com")]
+    pub email: String,
+    #[schema(example = "Kohta")]
+    pub full_name: Option<String>,
+    pub role: Role,
+    #[schema(example = true)]
+    pub is_active: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Administrator> for AdminResponse {
+    fn from(admin: Administrator) -> Self {
+        Self {
+            admin_id: admin.admin_id,
+            username: admin.username,
+            email: admin.email,
+            full_name: admin.full_name,
+            role: admin.role,
+            is_active: admin.is_active,
+            last_login_at: admin.last_login_at,
+            created_at: admin.created_at,
+            updated_at: admin.updated_at,
+        }
+    }
+}
+
+/// ログインレスポンス
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LoginResponse {
+    /// セッショントークン（Authorization: Bearer で送る。Cookieにも設定される）
+    #[schema(example = "q1Xo3c9mO3Vd0vC3f7uV6b5m2Jd8zT4eYk1sR0aWnHg")]
+    pub token: String,
+    /// セッションの有効期限
+    pub expires_at: DateTime<Utc>,
+    /// ログインした管理者
+    pub admin: AdminResponse,
+}
+
+// ============================================
+// ハンドラー関数
+// ============================================
+
+// --------------------------------------------------------
+// login: ログイン（セッションを発行）
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/auth/login
+//
+// 💡 ユーザーが存在しない・パスワードが違う・無効化されている場合は
+//    すべて同じ 401 を返す（どれが原因かを攻撃者に教えない）
+//
+// 💡 使用例:
+//   curl -X POST http://localhost:8000/api/v1/auth/login \
+//     -H 'Content-Type: application/json' \
+//     -d '{"username": "kohta", "password": "correct horse battery staple"}'
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/login",
+    tag = "auth",
+    summary = "ログイン",
+    description = "ユーザー名（またはメールアドレス）とパスワードでログインし、セッショントークンを発行します。トークンはレスポンスと HttpOnly Cookie の両方で返されます",
+    request_body = LoginRequest,
+    responses(
+        (status = 200, description = "ログイン成功", body = LoginResponse,
+            headers(("Set-Cookie" = String, description = "session=<token>; HttpOnly; SameSite=Lax"))),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ユーザー名またはパスワードが違います", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn login(
+    State(pool): State<PgPool>,
+    State(config): State<AuthConfig>,
+    client: ClientInfo,
+    AppJson(payload): AppJson<LoginRequest>,
+) -> AppResult<(HeaderMap, Json<LoginResponse>)> {
+    let login = payload.username.trim();
+    if login.is_empty() || payload.password.is_empty() {
+        return Err(AppError::BadRequest(
+            "username and password are required".to_string(),
+        ));
+    }
+    if payload.password.chars().count() > MAX_PASSWORD_LEN {
+        return Err(invalid_credentials());
+    }
+
+    let repo = AdminRepository::new(pool);
+    let admin = repo.find_by_login(login).await?;
+
+    let verified = verify_password(
+        payload.password,
+        admin.as_ref().map(|admin| admin.password_hash.clone()),
+    )
+    .await?;
+    let admin = match admin {
+        Some(admin) if verified && admin.is_active => admin,
+        _ => {
+            warn!("🔒 Failed login attempt for '{}' from {:?}", login, client.ip_address);
+            return Err(invalid_credentials());
+        }
+    };
+
+    let idle_secs = config.session_idle_timeout.as_secs() as i64;
+    let removed = repo.delete_expired_sessions(idle_secs).await?;
+    if removed > 0 {
+        info!("🧹 Removed {} expired sessions", removed);
+    }
+
+    let token = generate_token();
+    let ttl = chrono::Duration::from_std(config.session_ttl)
+        .map_err(|e| anyhow::anyhow!("invalid session ttl: {}", e))?;
+    let session = repo
+        .create_session(&NewSession {
+            admin_id: admin.admin_id,
+            token_hash: hash_token(&token),
+            ip_address: client.ip_address,
+            user_agent: client.user_agent,
+            expires_at: Utc::now() + ttl,
+        })
+        .await?;
+    repo.record_login(admin.admin_id).await?;
+
+    info!("🔓 '{}' logged in (session {})", admin.username, session.session_id);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        SET_COOKIE,
+        session_cookie(&token, config.session_ttl.as_secs(), config.cookie_secure),
+    );
+
+    Ok((
+        headers,
+        Json(LoginResponse {
+            token,
+            expires_at: session.expires_at,
+            admin: admin.into(),
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// logout: ログアウト（セッションを削除）
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/auth/logout
+//
+// 💡 使用例:
+//   curl -X POST http://localhost:8000/api/v1/auth/logout \
+//     -H 'Authorization: Bearer <token>'
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/logout",
+    tag = "auth",
+    summary = "ログアウト",
+    description = "現在のセッションを削除し、セッションCookieを消去します",
+    responses(
+        (status = 204, description = "ログアウトしました"),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = []))
+)]
+pub async fn logout(
+    State(pool): State<PgPool>,
+    State(config): State<AuthConfig>,
+    admin: CurrentAdmin,
+) -> AppResult<(StatusCode, HeaderMap)> {
+    AdminRepository::new(pool)
+        .delete_session(admin.session_id)
+        .await?;
+
+    info!(
+        "🔒 '{}' ({}) logged out (session {})",
+        admin.username, admin.role, admin.session_id
+    );
+
+    let mut headers = HeaderMap::new();
+    headers.insert(SET_COOKIE, session_cookie("", 0, config.cookie_secure));
+
+    Ok((StatusCode::NO_CONTENT, headers))
+}
+
+// --------------------------------------------------------
+// me: ログイン中の管理者
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/auth/me
+#[utoipa::path(
+    get,
+    path = "/api/v1/auth/me",
+    tag = "auth",
+    summary = "ログイン中の管理者",
+    description = "現在のセッションの管理者情報を返します",
+    responses(
+        (status = 200, description = "ログイン中の管理者", body = AdminResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = []))
+)]
+pub async fn me(
+    State(pool): State<PgPool>,
+    admin: CurrentAdmin,
+) -> AppResult<Json<AdminResponse>> {
+    let admin = AdminRepository::new(pool)
+        .find_by_id(admin.admin_id)
+        .await?
+        .ok_or_else(|| AppError::Unauthorized("Invalid or expired session".to_string()))?;
+
+    Ok(Json(admin.into()))
+}
+
+// ============================================
+// ヘルパー関数
+// ============================================
+
+fn invalid_credentials() -> AppError {
+    AppError::Unauthorized("Invalid username or password".to_string())
+}
+
+// --------------------------------------------------------
+// session_cookie: Set-Cookie ヘッダーの値を作る
+// --------------------------------------------------------
+//
+// 💡 属性:
+// - HttpOnly : JavaScript から読めない（XSSでトークンを盗まれない）
+// - SameSite=Lax : 他サイトからの POST には送られない（CSRF対策）
+// - Secure   : HTTPSのときだけ送る（本番では必須、config で切り替え）
+// - Max-Age=0 で送るとブラウザがCookieを削除する（ログアウト）
+fn session_cookie(token: &str, max_age_secs: u64, secure: bool) -> HeaderValue {
+    let mut cookie = format!(
+        "{}={}; HttpOnly; SameSite=Lax; Path=/; Max-Age={}",
+        SESSION_COOKIE, token, max_age_secs
+    );
+    if secure {
+        cookie.push_str("; Secure");
+    }
+    HeaderValue::from_str(&cookie).expect("session cookie is a valid header value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_cookie() {
+        assert_eq!(
+            session_cookie("abc", 3600, false),
+            "session=abc; HttpOnly; SameSite=Lax; Path=/; Max-Age=3600"
+        );
+        assert_eq!(
+            session_cookie("", 0, true),
+            "session=; HttpOnly; SameSite=Lax; Path=/; Max-Age=0; Secure"
+        );
+    }
+}
@@ -0,0 +1,1322 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode, Uri},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::EditorOrAdmin,
+    entities::{Book, BookChapter, BookChapterTranslation, BookTranslation, LocalizedBook, LocalizedChapter},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{
+        require_login_for_unpublished, Actor, AppJson, AppPath, AppQuery, Authorized, ClientInfo,
+        LangQuery, ResolvedLocale,
+    },
+    handlers::posts::{PostListItem, PostsListResponse},
+    pagination::{ListQuery, PageMeta},
+    repositories::{
+        BookChanges, BookRepository, BookSort, BookTranslationInput, ChapterMove,
+        ChapterTranslationInput, LocaleRepository, NewBook, NewChapter, PostFilter,
+        PostRepository, PostSort,
+    },
+    validation::{is_valid_slug, MAX_BOOK_SLUG_LEN},
+};
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+// --------------------------------------------------------
+// BookResponse: 言語解決済みの本
+// --------------------------------------------------------
+//
+// 💡 locale / is_fallback は記事・トピックと同じ
+// - locale: 実際に使われた翻訳の言語コード
+// - is_fallback: 要求した言語の翻訳がなく、原文言語やデフォルト言語で代用した場合 true
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookResponse {
+    /// 本のID
+    #[schema(example = 1)]
+    pub book_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "rust-tutorial")]
+    pub slug: String,
+    /// タイトル（翻訳がない場合はnull）
+    #[schema(example = "Rustチュートリアル")]
+    pub title: Option<String>,
+    /// はじめに
+    pub introduction: Option<String>,
+    /// タイトル・はじめにの言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// 要求した言語以外で代用したか
+    #[schema(example = false)]
+    pub is_fallback: bool,
+    /// 絵文字アイコン
+    #[schema(example = "🦀")]
+    pub emoji: Option<String>,
+    /// トップ画像のURL
+    pub top_image_url: Option<String>,
+    /// 表示順（小さいほど先）
+    #[schema(example = 1)]
+    pub display_order: Option<i32>,
+    /// 公開フラグ
+    #[schema(example = true)]
+    pub is_published: bool,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 公開日時
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl BookResponse {
+    fn from_localized(book: LocalizedBook, requested: &str) -> Self {
+        Self {
+            is_fallback: book.is_fallback(requested),
+            book_id: book.book_id,
+            slug: book.slug,
+            title: book.title,
+            introduction: book.introduction,
+            locale: book.locale_code,
+            emoji: book.emoji,
+            top_image_url: book.top_image_url,
+            display_order: book.display_order,
+            is_published: book.is_published,
+            created_at: book.created_at,
+            updated_at: book.updated_at,
+            published_at: book.published_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// BookTranslationResponse: 本の翻訳1件
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookTranslationResponse {
+    /// 言語コード
+    #[schema(example = "en")]
+    pub locale: String,
+    /// タイトル
+    #[schema(example = "Rust Tutorial")]
+    pub title: String,
+    /// はじめに
+    pub introduction: Option<String>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BookTranslation> for BookTranslationResponse {
+    fn from(translation: BookTranslation) -> Self {
+        Self {
+            locale: translation.locale_code,
+            title: translation.title,
+            introduction: translation.introduction,
+            updated_at: translation.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// ChapterNodeResponse: 目次の1項目（子チャプター付き）
+// --------------------------------------------------------
+//
+// 💡 number: ルートからの番号をドットでつないだもの（例: 2章の1節 → "2.1"）
+//
+// 💡 #[schema(no_recursion)]:
+// - children が自分自身の型なので、OpenAPI のスキーマ生成が無限に展開しないようにする
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ChapterNodeResponse {
+    /// チャプターID
+    #[schema(example = 3)]
+    pub chapter_id: i32,
+    /// URL用スラッグ（本の中で一意）
+    #[schema(example = "installation")]
+    pub slug: String,
+    /// 同じ親の中での順番（1始まり）
+    #[schema(example = 1)]
+    pub chapter_number: i32,
+    /// 目次の番号
+    #[schema(example = "1.1")]
+    pub number: String,
+    /// タイトル（翻訳がない場合はnull）
+    #[schema(example = "インストール")]
+    pub title: Option<String>,
+    /// タイトルの言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// 要求した言語以外で代用したか
+    #[schema(example = false)]
+    pub is_fallback: bool,
+    /// 公開フラグ
+    #[schema(example = true)]
+    pub is_published: bool,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 子チャプター（順番どおり）
+    #[schema(no_recursion)]
+    pub children: Vec<ChapterNodeResponse>,
+}
+
+// --------------------------------------------------------
+// BookDetailResponse: 本の詳細（目次と全翻訳付き）
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BookDetailResponse {
+    /// 言語解決済みの本
+    pub book: BookResponse,
+    /// チャプターのツリー（目次）
+    pub chapters: Vec<ChapterNodeResponse>,
+    /// 登録されているすべての翻訳
+    pub translations: Vec<BookTranslationResponse>,
+}
+
+// --------------------------------------------------------
+// BooksListResponse: 本の一覧
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BooksListResponse {
+    /// 本の一覧
+    pub books: Vec<BookResponse>,
+    /// ページ情報（total, page, per_page, total_pages, next_cursor）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// 解決に使った言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+// --------------------------------------------------------
+// ChapterTranslationResponse: チャプターの翻訳1件
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ChapterTranslationResponse {
+    /// 言語コード
+    #[schema(example = "en")]
+    pub locale: String,
+    /// タイトル
+    #[schema(example = "Installation")]
+    pub title: String,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BookChapterTranslation> for ChapterTranslationResponse {
+    fn from(translation: BookChapterTranslation) -> Self {
+        Self {
+            locale: translation.locale_code,
+            title: translation.title,
+            updated_at: translation.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// ChapterDetailResponse: チャプター1件（全翻訳付き、作成・更新の結果）
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ChapterDetailResponse {
+    /// チャプターID
+    #[schema(example = 3)]
+    pub chapter_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "installation")]
+    pub slug: String,
+    /// 親チャプターのID（トップレベルはnull）
+    #[schema(example = 1)]
+    pub parent_chapter_id: Option<i32>,
+    /// 同じ親の中での順番（1始まり）
+    #[schema(example = 1)]
+    pub chapter_number: i32,
+    /// 公開フラグ
+    #[schema(example = false)]
+    pub is_published: bool,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 登録されているすべての翻訳
+    pub translations: Vec<ChapterTranslationResponse>,
+}
+
+// ============================================
+// リクエスト用の構造体
+// ============================================
+
+/// 本の取得のクエリパラメータ（言語は ResolvedLocale が ?lang= から読む）
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct BookQuery {
+    /// 非公開の本・チャプター・記事も含める（ログイン、または read スコープのAPIキーが必要）
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// 本の翻訳の入力
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BookTranslationRequest {
+    /// 言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// タイトル
+    #[schema(example = "Rustチュートリアル")]
+    pub title: String,
+    /// はじめに
+    pub introduction: Option<String>,
+}
+
+/// 本の作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateBookRequest {
+    /// URL用スラッグ
+    #[schema(example = "rust-tutorial")]
+    pub slug: String,
+    /// 原文の言語コード（省略時: サイトのデフォルト言語）
+    #[schema(example = "ja")]
+    pub default_locale: Option<String>,
+    /// 絵文字アイコン
+    #[schema(example = "🦀")]
+    pub emoji: Option<String>,
+    /// トップ画像のURL
+    pub top_image_url: Option<String>,
+    /// 表示順（小さいほど先）
+    pub display_order: Option<i32>,
+    /// 公開フラグ（省略時: false）
+    #[serde(default)]
+    pub is_published: bool,
+    /// 翻訳（原文の言語の翻訳は必須）
+    pub translations: Vec<BookTranslationRequest>,
+}
+
+/// 本の更新リクエスト（部分更新）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateBookRequest {
+    /// URL用スラッグ
+    pub slug: Option<String>,
+    /// 原文の言語コード
+    pub default_locale: Option<String>,
+    /// 絵文字アイコン
+    pub emoji: Option<String>,
+    /// トップ画像のURL
+    pub top_image_url: Option<String>,
+    /// 表示順
+    pub display_order: Option<i32>,
+    /// 公開フラグ
+    pub is_published: Option<bool>,
+    /// 追加・更新する翻訳（含まれない言語はそのまま）
+    #[serde(default)]
+    pub translations: Vec<BookTranslationRequest>,
+}
+
+/// チャプターの翻訳の入力
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ChapterTranslationRequest {
+    /// 言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// タイトル
+    #[schema(example = "インストール")]
+    pub title: String,
+}
+
+/// チャプターの作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateChapterRequest {
+    /// URL用スラッグ（本の中で一意）
+    #[schema(example = "installation")]
+    pub slug: String,
+    /// 親チャプターのslug（省略時: トップレベル）
+    #[schema(example = "getting-started")]
+    pub parent: Option<String>,
+    /// 兄弟の中での位置（1始まり、省略時: 末尾）
+    #[schema(example = 1, minimum = 1)]
+    pub position: Option<u32>,
+    /// 公開フラグ（省略時: false）
+    #[serde(default)]
+    pub is_published: bool,
+    /// 翻訳（本の原文の言語の翻訳は必須）
+    pub translations: Vec<ChapterTranslationRequest>,
+}
+
+/// チャプターの更新リクエスト（部分更新）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateChapterRequest {
+    /// URL用スラッグ
+    pub slug: Option<String>,
+    /// 公開フラグ（チャプターごとに公開・非公開を切り替える）
+    pub is_published: Option<bool>,
+    /// 追加・更新する翻訳（含まれない言語はそのまま）
+    #[serde(default)]
+    pub translations: Vec<ChapterTranslationRequest>,
+}
+
+/// チャプターの移動リクエスト（並び替え・入れ子の変更）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveChapterRequest {
+    /// 移動先の親チャプターのslug（null または省略: トップレベル）
+    #[schema(example = "getting-started")]
+    pub parent: Option<String>,
+    /// 移動先の兄弟の中での位置（1始まり、省略時: 末尾）
+    #[schema(example = 2, minimum = 1)]
+    pub position: Option<u32>,
+}
+
+// ============================================
+// 共通処理
+// ============================================
+
+fn validate_slug(slug: &str) -> AppResult<()> {
+    if is_valid_slug(slug, MAX_BOOK_SLUG_LEN) {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!(
+            "Invalid slug '{}' (lowercase letters, digits and single hyphens only)",
+            slug
+        )))
+    }
+}
+
+fn validate_position(position: Option<u32>) -> AppResult<()> {
+    match position {
+        Some(0) => Err(AppError::BadRequest("position must be 1 or greater".to_string())),
+        _ => Ok(()),
+    }
+}
+
+// 💡 books.emoji は VARCHAR(10)、top_image_url は VARCHAR(500)
+fn validate_book_fields(emoji: Option<&str>, top_image_url: Option<&str>) -> AppResult<()> {
+    if emoji.is_some_and(|emoji| emoji.is_empty() || emoji.chars().count() > 10) {
+        return Err(AppError::BadRequest("emoji must be 1-10 characters".to_string()));
+    }
+    if top_image_url.is_some_and(|url| url.is_empty() || url.len() > 500) {
+        return Err(AppError::BadRequest(
+            "top_image_url must be 1-500 characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn book_not_found(slug: &str) -> AppError {
+    AppError::NotFound(format!("Book '{}' not found", slug))
+}
+
+fn chapter_not_found(book: &str, chapter: &str) -> AppError {
+    AppError::NotFound(format!("Chapter '{}' not found in book '{}'", chapter, book))
+}
+
+// --------------------------------------------------------
+// resolve_active_locale_id: 原文の言語コード → locale_id
+// --------------------------------------------------------
+async fn resolve_active_locale_id(pool: &PgPool, code: &str) -> AppResult<i32> {
+    match LocaleRepository::new(pool.clone()).find_by_code(code).await? {
+        Some(locale) if locale.is_active => Ok(locale.locale_id),
+        Some(_) => Err(AppError::BadRequest(format!("Locale '{}' is not active", code))),
+        None => Err(AppError::BadRequest(format!("Unknown locale '{}'", code))),
+    }
+}
+
+// --------------------------------------------------------
+// resolve_locale_ids: 翻訳の言語コード → locale_id（重複チェック付き）
+// --------------------------------------------------------
+//
+// 💡 本とチャプターの翻訳で共通（タイトルの検証は呼び出し側）
+async fn resolve_locale_ids<'a>(
+    pool: &PgPool,
+    codes: impl Iterator<Item = &'a str>,
+) -> AppResult<Vec<i32>> {
+    let repo = LocaleRepository::new(pool.clone());
+    let mut seen = HashSet::new();
+    let mut locale_ids = Vec::new();
+
+    for code in codes {
+        if !seen.insert(code) {
+            return Err(AppError::BadRequest(format!(
+                "Duplicate translation for locale '{}'",
+                code
+            )));
+        }
+
+        let locale = repo
+            .find_by_code(code)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Unknown locale '{}'", code)))?;
+        locale_ids.push(locale.locale_id);
+    }
+
+    Ok(locale_ids)
+}
+
+fn validate_title(title: &str, locale: &str) -> AppResult<String> {
+    let title = title.trim();
+    if title.is_empty() || title.chars().count() > 255 {
+        return Err(AppError::BadRequest(format!(
+            "Title for locale '{}' must be 1-255 characters",
+            locale
+        )));
+    }
+    Ok(title.to_string())
+}
+
+async fn resolve_book_translations(
+    pool: &PgPool,
+    translations: &[BookTranslationRequest],
+) -> AppResult<Vec<BookTranslationInput>> {
+    let locale_ids = resolve_locale_ids(pool, translations.iter().map(|t| t.locale.as_str())).await?;
+
+    translations
+        .iter()
+        .zip(locale_ids)
+        .map(|(translation, locale_id)| {
+            Ok(BookTranslationInput {
+                locale_id,
+                title: validate_title(&translation.title, &translation.locale)?,
+                introduction: translation.introduction.clone(),
+            })
+        })
+        .collect()
+}
+
+async fn resolve_chapter_translations(
+    pool: &PgPool,
+    translations: &[ChapterTranslationRequest],
+) -> AppResult<Vec<ChapterTranslationInput>> {
+    let locale_ids = resolve_locale_ids(pool, translations.iter().map(|t| t.locale.as_str())).await?;
+
+    translations
+        .iter()
+        .zip(locale_ids)
+        .map(|(translation, locale_id)| {
+            Ok(ChapterTranslationInput {
+                locale_id,
+                title: validate_title(&translation.title, &translation.locale)?,
+            })
+        })
+        .collect()
+}
+
+// --------------------------------------------------------
+// find_book / find_chapter: slug → 行（なければ 404）
+// --------------------------------------------------------
+async fn find_book(repo: &BookRepository, slug: &str) -> AppResult<Book> {
+    repo.find_by_slug(slug)
+        .await?
+        .ok_or_else(|| book_not_found(slug))
+}
+
+async fn find_chapter(repo: &BookRepository, book: &Book, slug: &str) -> AppResult<BookChapter> {
+    repo.find_chapter_by_slug(book.book_id, slug)
+        .await?
+        .ok_or_else(|| chapter_not_found(&book.slug, slug))
+}
+
+// --------------------------------------------------------
+// build_chapter_tree: 目次の順に並んだ行 → 入れ子のツリー
+// --------------------------------------------------------
+//
+// 💡 行は BookRepository::find_chapter_tree の順（深さ優先、兄弟は chapter_number 順）
+// - 親ごとに子の行をまとめてから、トップレベル（親 = None）から順に組み立てる
+// - 親が結果にない行（非公開の親の子孫）は、そもそもクエリの結果に含まれない
+fn build_chapter_tree(rows: Vec<LocalizedChapter>, requested: &str) -> Vec<ChapterNodeResponse> {
+    let mut children: HashMap<Option<i32>, Vec<LocalizedChapter>> = HashMap::new();
+    for row in rows {
+        children.entry(row.parent_chapter_id).or_default().push(row);
+    }
+
+    attach_children(&mut children, None, "", requested)
+}
+
+fn attach_children(
+    children: &mut HashMap<Option<i32>, Vec<LocalizedChapter>>,
+    parent: Option<i32>,
+    prefix: &str,
+    requested: &str,
+) -> Vec<ChapterNodeResponse> {
+    children
+        .remove(&parent)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|row| {
+            let number = if prefix.is_empty() {
+                row.chapter_number.to_string()
+            } else {
+                format!("{}.{}", prefix, row.chapter_number)
+            };
+            let nested = attach_children(children, Some(row.chapter_id), &number, requested);
+
+            ChapterNodeResponse {
+                is_fallback: row.is_fallback(requested),
+                chapter_id: row.chapter_id,
+                slug: row.slug,
+                chapter_number: row.chapter_number,
+                number,
+                title: row.title,
+                locale: row.locale_code,
+                is_published: row.is_published,
+                updated_at: row.updated_at,
+                children: nested,
+            }
+        })
+        .collect()
+}
+
+// --------------------------------------------------------
+// load_detail: 言語解決済みの本 + 目次 + 全翻訳
+// --------------------------------------------------------
+async fn load_detail(
+    repo: &BookRepository,
+    slug: &str,
+    requested: &str,
+    fallback: &str,
+    include_unpublished: bool,
+) -> AppResult<BookDetailResponse> {
+    let book = repo
+        .find_by_slug_localized(slug, requested, fallback)
+        .await?
+        .ok_or_else(|| book_not_found(slug))?;
+
+    let chapters = repo
+        .find_chapter_tree(book.book_id, requested, fallback, include_unpublished)
+        .await?;
+    let translations = repo
+        .find_translations(book.book_id)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(BookDetailResponse {
+        chapters: build_chapter_tree(chapters, requested),
+        book: BookResponse::from_localized(book, requested),
+        translations,
+    })
+}
+
+// --------------------------------------------------------
+// load_chapter_detail: チャプター + 全翻訳
+// --------------------------------------------------------
+async fn load_chapter_detail(
+    repo: &BookRepository,
+    chapter: BookChapter,
+) -> AppResult<ChapterDetailResponse> {
+    let translations = repo
+        .find_chapter_translations(chapter.chapter_id)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(ChapterDetailResponse {
+        chapter_id: chapter.chapter_id,
+        slug: chapter.slug,
+        parent_chapter_id: chapter.parent_chapter_id,
+        chapter_number: chapter.chapter_number,
+        is_published: chapter.is_published,
+        created_at: chapter.created_at,
+        updated_at: chapter.updated_at,
+        translations,
+    })
+}
+
+// ============================================
+// Handler関数
+// ============================================
+
+// --------------------------------------------------------
+// list_books: 本の一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/books?lang=en
+#[utoipa::path(
+    get,
+    path = "/api/v1/books",
+    tag = "books",
+    summary = "本の一覧取得",
+    description = "本の一覧を指定した言語で取得します。翻訳がない場合は原文の言語、次にデフォルト言語で返します。\
+                   sort には display_order（デフォルト）/ slug / created_at / updated_at を指定できます",
+    params(LangQuery, BookQuery, ListQuery),
+    responses(
+        (status = 200, description = "本の一覧（Link ヘッダーに前後のページのURL）", body = BooksListResponse),
+        (status = 400, description = "ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn list_books(
+    State(pool): State<PgPool>,
+    actor: Option<Actor>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<BookQuery>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<BooksListResponse>)> {
+    info!("📚 Fetching books (locale: {}, from {:?})...", locale.code, locale.source);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let params = list.resolve::<BookSort>()?;
+
+    let repo = BookRepository::new(pool);
+    let page = repo
+        .find_page_localized(
+            &locale.code,
+            &locale.default_code,
+            query.include_unpublished,
+            &params,
+        )
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (books, pagination) =
+        page.into_parts(|book| BookResponse::from_localized(book, &locale.code));
+    for item in &books {
+        locale.served(item.locale.as_deref());
+    }
+
+    info!("✅ Successfully fetched {} of {} books", books.len(), pagination.total);
+
+    Ok((
+        headers,
+        Json(BooksListResponse {
+            books,
+            pagination,
+            locale: locale.code,
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// get_book: 本の詳細（目次付き）
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/books/{slug}?lang=en
+//
+// 💡 目次（chapters）はチャプターのツリーを1回の再帰クエリで取得して組み立てる
+#[utoipa::path(
+    get,
+    path = "/api/v1/books/{slug}",
+    tag = "books",
+    summary = "本の詳細取得",
+    description = "slugを指定して本・目次（入れ子のチャプター）・全翻訳を取得します。\
+                   非公開のチャプターは include_unpublished を指定しない限り子孫ごと含まれません",
+    params(
+        ("slug" = String, Path, description = "本のスラッグ（例: rust-tutorial）"),
+        LangQuery,
+        BookQuery
+    ),
+    responses(
+        (status = 200, description = "本の詳細", body = BookDetailResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 404, description = "本が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn get_book(
+    State(pool): State<PgPool>,
+    actor: Option<Actor>,
+    AppPath(slug): AppPath<String>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<BookQuery>,
+) -> AppResult<Json<BookDetailResponse>> {
+    info!("📚 Fetching book: {} (locale: {})", slug, locale.code);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let repo = BookRepository::new(pool);
+    let detail = load_detail(
+        &repo,
+        &slug,
+        &locale.code,
+        &locale.default_code,
+        query.include_unpublished,
+    )
+    .await?;
+    locale.served(detail.book.locale.as_deref());
+
+    if !detail.book.is_published && !query.include_unpublished {
+        return Err(book_not_found(&slug));
+    }
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// create_book: 本の作成
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/books
+#[utoipa::path(
+    post,
+    path = "/api/v1/books",
+    tag = "books",
+    summary = "本の作成",
+    description = "本と翻訳を作成します。原文の言語の翻訳は必須です",
+    request_body = CreateBookRequest,
+    responses(
+        (status = 201, description = "作成された本", body = BookDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 409, description = "slugが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn create_book(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<CreateBookRequest>,
+) -> AppResult<(StatusCode, Json<BookDetailResponse>)> {
+    info!("📚 Creating book: {}", payload.slug);
+
+    validate_slug(&payload.slug)?;
+    validate_book_fields(payload.emoji.as_deref(), payload.top_image_url.as_deref())?;
+
+    let default_code = payload
+        .default_locale
+        .clone()
+        .unwrap_or_else(|| locale.default_code.clone());
+    let default_locale_id = resolve_active_locale_id(&pool, &default_code).await?;
+
+    if !payload.translations.iter().any(|t| t.locale == default_code) {
+        return Err(AppError::BadRequest(format!(
+            "A translation for the book's default locale '{}' is required",
+            default_code
+        )));
+    }
+
+    let translations = resolve_book_translations(&pool, &payload.translations).await?;
+
+    let repo = BookRepository::new(pool);
+    let book = repo
+        .create(
+            &NewBook {
+                slug: payload.slug,
+                emoji: payload.emoji,
+                top_image_url: payload.top_image_url,
+                display_order: payload.display_order,
+                is_published: payload.is_published,
+                default_locale_id,
+                created_by: actor.admin_id(),
+            },
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await?;
+
+    info!("✅ Created book: {} (id: {})", book.slug, book.book_id);
+
+    let detail = load_detail(&repo, &book.slug, &locale.code, &locale.default_code, true).await?;
+    locale.served(detail.book.locale.as_deref());
+
+    Ok((StatusCode::CREATED, Json(detail)))
+}
+
+// --------------------------------------------------------
+// update_book: 本の更新
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: PATCH /api/v1/books/{slug}
+#[utoipa::path(
+    patch,
+    path = "/api/v1/books/{slug}",
+    tag = "books",
+    summary = "本の更新",
+    description = "本の属性を更新し、指定した言語の翻訳を追加・更新します",
+    params(
+        ("slug" = String, Path, description = "本のスラッグ（例: rust-tutorial）")
+    ),
+    request_body = UpdateBookRequest,
+    responses(
+        (status = 200, description = "更新後の本", body = BookDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "本が見つかりません", body = ErrorResponse),
+        (status = 409, description = "slugが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn update_book(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    AppPath(slug): AppPath<String>,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<UpdateBookRequest>,
+) -> AppResult<Json<BookDetailResponse>> {
+    info!("📚 Updating book: {}", slug);
+
+    if let Some(new_slug) = &payload.slug {
+        validate_slug(new_slug)?;
+    }
+    validate_book_fields(payload.emoji.as_deref(), payload.top_image_url.as_deref())?;
+
+    let default_locale_id = match &payload.default_locale {
+        Some(code) => Some(resolve_active_locale_id(&pool, code).await?),
+        None => None,
+    };
+    let translations = resolve_book_translations(&pool, &payload.translations).await?;
+
+    let repo = BookRepository::new(pool);
+    let current = find_book(&repo, &slug).await?;
+
+    let book = repo
+        .update(
+            current.book_id,
+            &BookChanges {
+                slug: payload.slug,
+                emoji: payload.emoji,
+                top_image_url: payload.top_image_url,
+                display_order: payload.display_order,
+                is_published: payload.is_published,
+                default_locale_id,
+                last_updated_by: actor.admin_id(),
+            },
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await?
+        .ok_or_else(|| book_not_found(&slug))?;
+
+    info!("✅ Updated book: {} (id: {})", book.slug, book.book_id);
+
+    let detail = load_detail(&repo, &book.slug, &locale.code, &locale.default_code, true).await?;
+    locale.served(detail.book.locale.as_deref());
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// create_chapter: チャプターの追加
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/books/{slug}/chapters
+//
+// 💡 使用例（1章の2番目に節を追加）:
+//   curl -X POST http://localhost:8000/api/v1/books/rust-tutorial/chapters \
+//     -H 'Authorization: Bearer <session token>' -H 'Content-Type: application/json' \
+//     -d '{"slug": "hello-world", "parent": "getting-started", "position": 2,
+//          "translations": [{"locale": "ja", "title": "Hello, world!"}]}'
+#[utoipa::path(
+    post,
+    path = "/api/v1/books/{slug}/chapters",
+    tag = "books",
+    summary = "チャプターの追加",
+    description = "本にチャプターを追加します。parent を指定すると子チャプター、position を指定すると\
+                   その位置に差し込みます（以降の兄弟は1つ後ろにずれます）。本の原文の言語の翻訳は必須です",
+    params(
+        ("slug" = String, Path, description = "本のスラッグ（例: rust-tutorial）")
+    ),
+    request_body = CreateChapterRequest,
+    responses(
+        (status = 201, description = "作成されたチャプター", body = ChapterDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "本が見つかりません", body = ErrorResponse),
+        (status = 409, description = "slugが本の中で既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn create_chapter(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    AppPath(slug): AppPath<String>,
+    AppJson(payload): AppJson<CreateChapterRequest>,
+) -> AppResult<(StatusCode, Json<ChapterDetailResponse>)> {
+    info!("📚 Creating chapter: {} in book {}", payload.slug, slug);
+
+    validate_slug(&payload.slug)?;
+    validate_position(payload.position)?;
+    let translations = resolve_chapter_translations(&pool, &payload.translations).await?;
+
+    let repo = BookRepository::new(pool);
+    let book = find_book(&repo, &slug).await?;
+
+    if !translations
+        .iter()
+        .any(|t| Some(t.locale_id) == book.default_locale_id)
+    {
+        return Err(AppError::BadRequest(
+            "A translation for the book's default locale is required".to_string(),
+        ));
+    }
+
+    let parent_chapter_id = match &payload.parent {
+        Some(parent) => Some(
+            repo.find_chapter_by_slug(book.book_id, parent)
+                .await?
+                .ok_or_else(|| {
+                    AppError::BadRequest(format!("Unknown parent chapter '{}'", parent))
+                })?
+                .chapter_id,
+        ),
+        None => None,
+    };
+
+    let chapter = repo
+        .create_chapter(
+            book.book_id,
+            &NewChapter {
+                slug: payload.slug,
+                parent_chapter_id,
+                position: payload.position,
+                is_published: payload.is_published,
+            },
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await?;
+
+    info!("✅ Created chapter: {} (id: {})", chapter.slug, chapter.chapter_id);
+
+    let detail = load_chapter_detail(&repo, chapter).await?;
+
+    Ok((StatusCode::CREATED, Json(detail)))
+}
+
+// --------------------------------------------------------
+// update_chapter: チャプターの更新（公開・非公開の切り替えなど）
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: PATCH /api/v1/books/{slug}/chapters/{chapter}
+//
+// 💡 並び替え・入れ子の変更は move_chapter で行う
+#[utoipa::path(
+    patch,
+    path = "/api/v1/books/{slug}/chapters/{chapter}",
+    tag = "books",
+    summary = "チャプターの更新",
+    description = "チャプターの slug・公開フラグを更新し、指定した言語の翻訳を追加・更新します。\
+                   非公開にしたチャプターは子孫ごと読者向けの目次から外れます",
+    params(
+        ("slug" = String, Path, description = "本のスラッグ（例: rust-tutorial）"),
+        ("chapter" = String, Path, description = "チャプターのスラッグ（例: installation）")
+    ),
+    request_body = UpdateChapterRequest,
+    responses(
+        (status = 200, description = "更新後のチャプター", body = ChapterDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "本またはチャプターが見つかりません", body = ErrorResponse),
+        (status = 409, description = "slugが本の中で既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn update_chapter(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    AppPath((slug, chapter_slug)): AppPath<(String, String)>,
+    AppJson(payload): AppJson<UpdateChapterRequest>,
+) -> AppResult<Json<ChapterDetailResponse>> {
+    info!("📚 Updating chapter: {} in book {}", chapter_slug, slug);
+
+    if let Some(new_slug) = &payload.slug {
+        validate_slug(new_slug)?;
+    }
+    let translations = resolve_chapter_translations(&pool, &payload.translations).await?;
+
+    let repo = BookRepository::new(pool);
+    let book = find_book(&repo, &slug).await?;
+    let current = find_chapter(&repo, &book, &chapter_slug).await?;
+
+    let chapter = repo
+        .update_chapter(
+            current.chapter_id,
+            payload.slug.as_deref(),
+            payload.is_published,
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await?
+        .ok_or_else(|| chapter_not_found(&slug, &chapter_slug))?;
+
+    info!("✅ Updated chapter: {} (id: {})", chapter.slug, chapter.chapter_id);
+
+    let detail = load_chapter_detail(&repo, chapter).await?;
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// move_chapter: チャプターの並び替え・入れ子の変更
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/books/{slug}/chapters/{chapter}/move
+//
+// 💡 使用例:
+// - 同じ親の中で先頭へ: {"parent": "getting-started", "position": 1}
+// - トップレベルの末尾へ: {}
+//
+// 💡 子孫は一緒に移動する。自分自身や自分の子孫の下には移動できない（400）
+#[utoipa::path(
+    post,
+    path = "/api/v1/books/{slug}/chapters/{chapter}/move",
+    tag = "books",
+    summary = "チャプターの移動",
+    description = "チャプターを parent の子の position 番目に移動します（parent 省略時はトップレベル、\
+                   position 省略時は末尾）。移動元・移動先の兄弟の番号は振り直され、子孫も一緒に移動します",
+    params(
+        ("slug" = String, Path, description = "本のスラッグ（例: rust-tutorial）"),
+        ("chapter" = String, Path, description = "チャプターのスラッグ（例: installation）"),
+        LangQuery
+    ),
+    request_body = MoveChapterRequest,
+    responses(
+        (status = 200, description = "移動後の本（非公開を含む目次）", body = BookDetailResponse),
+        (status = 400, description = "移動先が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "本またはチャプターが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn move_chapter(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    AppPath((slug, chapter_slug)): AppPath<(String, String)>,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<MoveChapterRequest>,
+) -> AppResult<Json<BookDetailResponse>> {
+    info!("📚 Moving chapter: {} in book {}", chapter_slug, slug);
+
+    validate_position(payload.position)?;
+
+    let repo = BookRepository::new(pool);
+    let book = find_book(&repo, &slug).await?;
+    let chapter = find_chapter(&repo, &book, &chapter_slug).await?;
+
+    let parent_id = match &payload.parent {
+        Some(parent) => Some(
+            repo.find_chapter_by_slug(book.book_id, parent)
+                .await?
+                .ok_or_else(|| {
+                    AppError::BadRequest(format!("Unknown parent chapter '{}'", parent))
+                })?
+                .chapter_id,
+        ),
+        None => None,
+    };
+
+    let outcome = repo
+        .move_chapter(
+            book.book_id,
+            chapter.chapter_id,
+            parent_id,
+            payload.position,
+            &actor.audit_context(&client),
+        )
+        .await?;
+
+    match outcome {
+        ChapterMove::Moved => {}
+        ChapterMove::NotFound => return Err(chapter_not_found(&slug, &chapter_slug)),
+        ChapterMove::IntoOwnSubtree => {
+            return Err(AppError::BadRequest(format!(
+                "Chapter '{}' cannot be moved under itself or its descendants",
+                chapter_slug
+            )))
+        }
+    }
+
+    info!("✅ Moved chapter: {} (id: {})", chapter.slug, chapter.chapter_id);
+
+    let detail = load_detail(&repo, &book.slug, &locale.code, &locale.default_code, true).await?;
+    locale.served(detail.book.locale.as_deref());
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// list_chapter_posts: チャプターに紐づく記事の一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/books/{slug}/chapters/{chapter}/posts?lang=en
+//
+// 💡 blog_posts.chapter_id で紐づく記事を、記事一覧と同じ形式・並び順で返す
+// - 本・チャプター（とその祖先）が非公開なら、include_unpublished なしでは 404
+#[utoipa::path(
+    get,
+    path = "/api/v1/books/{slug}/chapters/{chapter}/posts",
+    tag = "books",
+    summary = "チャプターの記事一覧取得",
+    description = "チャプターに紐づく記事を取得します。並び替え・ページ送りは記事一覧と同じです",
+    params(
+        ("slug" = String, Path, description = "本のスラッグ（例: rust-tutorial）"),
+        ("chapter" = String, Path, description = "チャプターのスラッグ（例: installation）"),
+        LangQuery,
+        BookQuery,
+        ListQuery
+    ),
+    responses(
+        (status = 200, description = "記事一覧（Link ヘッダーに次のページのURL）", body = PostsListResponse),
+        (status = 400, description = "ページ・並び替え・カーソルの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 404, description = "本またはチャプターが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn list_chapter_posts(
+    State(pool): State<PgPool>,
+    actor: Option<Actor>,
+    AppPath((slug, chapter_slug)): AppPath<(String, String)>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<BookQuery>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<PostsListResponse>)> {
+    info!("📚 Fetching posts of chapter: {} in book {}", chapter_slug, slug);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let params = list.resolve::<PostSort>()?;
+
+    let books = BookRepository::new(pool.clone());
+    let book = find_book(&books, &slug).await?;
+    let chapter = find_chapter(&books, &book, &chapter_slug).await?;
+
+    if !query.include_unpublished {
+        // 💡 公開中の目次に含まれるか（非公開の祖先がいれば含まれない）
+        let visible = book.is_published
+            && books
+                .find_chapter_tree(book.book_id, &locale.code, &locale.default_code, false)
+                .await?
+                .iter()
+                .any(|row| row.chapter_id == chapter.chapter_id);
+        if !visible {
+            return Err(chapter_not_found(&slug, &chapter_slug));
+        }
+    }
+
+    let filter = PostFilter {
+        include_unpublished: query.include_unpublished,
+        topic: None,
+        chapter_id: Some(chapter.chapter_id),
+    };
+
+    let page = PostRepository::new(pool)
+        .find_page_localized(&locale.code, &locale.default_code, &filter, &params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (posts, pagination) =
+        page.into_parts(|post| PostListItem::from_summary(post, &locale.code));
+    for item in &posts {
+        locale.served(item.locale.as_deref());
+    }
+
+    info!("✅ Successfully fetched {} of {} posts", posts.len(), pagination.total);
+
+    Ok((
+        headers,
+        Json(PostsListResponse {
+            posts,
+            pagination,
+            locale: locale.code,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(chapter_id: i32, parent: Option<i32>, chapter_number: i32, depth: i32) -> LocalizedChapter {
+        LocalizedChapter {
+            chapter_id,
+            parent_chapter_id: parent,
+            chapter_number,
+            depth,
+            slug: format!("chapter-{}", chapter_id),
+            is_published: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            title: Some(format!("Chapter {}", chapter_id)),
+            locale_code: Some("ja".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_build_chapter_tree() {
+        // 1 ─┬─ 3
+        //    └─ 4 ── 5
+        // 2
+        let rows = vec![
+            row(1, None, 1, 0),
+            row(3, Some(1), 1, 1),
+            row(4, Some(1), 2, 1),
+            row(5, Some(4), 1, 2),
+            row(2, None, 2, 0),
+        ];
+
+        let tree = build_chapter_tree(rows, "en");
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree[0].chapter_id, 1);
+        assert_eq!(tree[1].chapter_id, 2);
+        assert!(tree[1].children.is_empty());
+
+        let children: Vec<_> = tree[0].children.iter().map(|c| c.chapter_id).collect();
+        assert_eq!(children, vec![3, 4]);
+        assert_eq!(tree[0].children[1].children[0].chapter_id, 5);
+        assert_eq!(tree[0].children[1].children[0].number, "1.2.1");
+        assert!(tree[0].is_fallback);
+    }
+}
@@ -0,0 +1,8 @@
+use axum::http::Uri;
+
+use crate::error::AppError;
+
+/// 存在しないパスへのリクエスト - 404を共通エラー形式で返す
+pub async fn not_found(uri: Uri) -> AppError {
+    AppError::NotFound(format!("No route for {}", uri.path()))
+}
@@ -0,0 +1,226 @@
+use axum::{
+    extract::State,
+    http::header::{CACHE_CONTROL, CONTENT_TYPE, LAST_MODIFIED},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use sqlx::PgPool;
+use tracing::info;
+
+use crate::{
+    config::SiteConfig,
+    entities::{Locale, PostFeedItem},
+    error::{AppResult, ErrorResponse},
+    extractors::AppPath,
+    feed::{Feed, FeedEntry, FeedFormat},
+    handlers::{locales::locale_not_found, topics::topic_not_found},
+    repositories::{LocaleRepository, PostRepository, TopicRepository},
+};
+
+// ============================================
+// RSS / Atom フィード
+// ============================================
+//
+// 💡 エンドポイント:
+// - GET /feeds/{locale}/rss.xml, /feeds/{locale}/atom.xml                 → その言語の新着記事
+// - GET /feeds/{locale}/topics/{topic}/rss.xml, .../topics/{topic}/atom.xml → トピックごと
+//
+// 💡 言語のルール:
+// - 有効な言語（locales.is_active = TRUE）だけ。それ以外は 404
+// - その言語の翻訳がある記事だけを載せる（他の言語にフォールバックしない）
+//   → フィードの xml:lang / <language> と記事の言語が必ず一致する
+//
+// 💡 記事のリンクは公開サイト（site.public_url）、フィード自身のURLは API（site.api_url）
+
+/// 1つのフィードに載せる記事数
+const FEED_ITEM_LIMIT: i64 = 20;
+
+/// フィードのキャッシュ時間（秒）
+const FEED_MAX_AGE_SECS: u32 = 900;
+
+// ============================================
+// Handler関数
+// ============================================
+
+#[utoipa::path(
+    get,
+    path = "/feeds/{locale}/rss.xml",
+    tag = "feeds",
+    summary = "新着記事の RSS 2.0 フィード",
+    description = "指定した言語の翻訳がある公開済み記事を、公開日時の新しい順に最大20件返します",
+    params(("locale" = String, Path, description = "有効な言語コード（例: ja, en）")),
+    responses(
+        (status = 200, description = "RSS 2.0", content_type = "application/rss+xml"),
+        (status = 404, description = "言語が見つからないか無効です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn locale_rss(
+    State(pool): State<PgPool>,
+    State(site): State<SiteConfig>,
+    AppPath(locale): AppPath<String>,
+) -> AppResult<Response> {
+    render(pool, &site, FeedFormat::Rss, &locale, None).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/feeds/{locale}/atom.xml",
+    tag = "feeds",
+    summary = "新着記事の Atom フィード",
+    description = "指定した言語の翻訳がある公開済み記事を、公開日時の新しい順に最大20件返します",
+    params(("locale" = String, Path, description = "有効な言語コード（例: ja, en）")),
+    responses(
+        (status = 200, description = "Atom", content_type = "application/atom+xml"),
+        (status = 404, description = "言語が見つからないか無効です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn locale_atom(
+    State(pool): State<PgPool>,
+    State(site): State<SiteConfig>,
+    AppPath(locale): AppPath<String>,
+) -> AppResult<Response> {
+    render(pool, &site, FeedFormat::Atom, &locale, None).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/feeds/{locale}/topics/{topic}/rss.xml",
+    tag = "feeds",
+    summary = "トピックの RSS 2.0 フィード",
+    description = "指定したトピックの記事だけを載せた RSS 2.0 フィードです（公開中のトピックのみ）",
+    params(
+        ("locale" = String, Path, description = "有効な言語コード（例: ja, en）"),
+        ("topic" = String, Path, description = "トピックのslug（例: rust）")
+    ),
+    responses(
+        (status = 200, description = "RSS 2.0", content_type = "application/rss+xml"),
+        (status = 404, description = "言語・トピックが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn topic_rss(
+    State(pool): State<PgPool>,
+    State(site): State<SiteConfig>,
+    AppPath((locale, topic)): AppPath<(String, String)>,
+) -> AppResult<Response> {
+    render(pool, &site, FeedFormat::Rss, &locale, Some(&topic)).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/feeds/{locale}/topics/{topic}/atom.xml",
+    tag = "feeds",
+    summary = "トピックの Atom フィード",
+    description = "指定したトピックの記事だけを載せた Atom フィードです（公開中のトピックのみ）",
+    params(
+        ("locale" = String, Path, description = "有効な言語コード（例: ja, en）"),
+        ("topic" = String, Path, description = "トピックのslug（例: rust）")
+    ),
+    responses(
+        (status = 200, description = "Atom", content_type = "application/atom+xml"),
+        (status = 404, description = "言語・トピックが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn topic_atom(
+    State(pool): State<PgPool>,
+    State(site): State<SiteConfig>,
+    AppPath((locale, topic)): AppPath<(String, String)>,
+) -> AppResult<Response> {
+    render(pool, &site, FeedFormat::Atom, &locale, Some(&topic)).await
+}
+
+// --------------------------------------------------------
+// render: 4つのエンドポイントの共通処理
+// --------------------------------------------------------
+//
+// 💡 フィードの更新日時 = 載せた記事の更新日時のうち最新
+// - 記事がない場合は現在時刻（更新日時のないフィードは Atom では不正）
+// - Last-Modified ヘッダーにも同じ値を入れる
+async fn render(
+    pool: PgPool,
+    site: &SiteConfig,
+    format: FeedFormat,
+    code: &str,
+    topic_slug: Option<&str>,
+) -> AppResult<Response> {
+    info!("📰 Building {:?} feed (locale: {}, topic: {:?})", format, code, topic_slug);
+
+    let locale = find_active_locale(&pool, code).await?;
+
+    // トピックのフィードはタイトルにトピック名（その言語の名前、なければslug）を入れる
+    let (title, path) = match topic_slug {
+        None => (site.title.clone(), format!("/feeds/{}", locale.code)),
+        Some(slug) => {
+            let topic = TopicRepository::new(pool.clone())
+                .find_by_slug_localized(slug, &locale.code, &locale.code)
+                .await?
+                .filter(|topic| topic.is_published)
+                .ok_or_else(|| topic_not_found(slug))?;
+            (
+                format!("{} - {}", site.title, topic.name.as_deref().unwrap_or(&topic.slug)),
+                format!("/feeds/{}/topics/{}", locale.code, topic.slug),
+            )
+        }
+    };
+
+    let items = PostRepository::new(pool)
+        .find_feed_items(&locale.code, topic_slug, FEED_ITEM_LIMIT)
+        .await?;
+
+    let feed = Feed {
+        description: format!("{} ({})", title, locale.name),
+        title,
+        language: locale.code.clone(),
+        // 💡 公開サイトにトピック別のページはないため、トピックのフィードも記事一覧にリンクする
+        site_url: format!("{}/{}/blog", site.public_url, locale.code),
+        self_url: format!("{}{}/{}", site.api_url, path, format.file_name()),
+        updated: items
+            .iter()
+            .map(|item| item.updated_at)
+            .max()
+            .unwrap_or_else(Utc::now),
+        entries: items
+            .into_iter()
+            .map(|item| entry(site, &locale, item))
+            .collect(),
+    };
+
+    info!("✅ Built feed with {} entries", feed.entries.len());
+
+    Ok((
+        [
+            (CONTENT_TYPE, format.content_type().to_string()),
+            (
+                LAST_MODIFIED,
+                feed.updated.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+            ),
+            (CACHE_CONTROL, format!("public, max-age={}", FEED_MAX_AGE_SECS)),
+        ],
+        format.render(&feed),
+    )
+        .into_response())
+}
+
+/// 有効な言語だけを受け付ける（無効化した言語・存在しない言語は 404）
+async fn find_active_locale(pool: &PgPool, code: &str) -> AppResult<Locale> {
+    LocaleRepository::new(pool.clone())
+        .find_active()
+        .await?
+        .into_iter()
+        .find(|locale| locale.code == code)
+        .ok_or_else(|| locale_not_found(code))
+}
+
+fn entry(site: &SiteConfig, locale: &Locale, item: PostFeedItem) -> FeedEntry {
+    FeedEntry {
+        url: format!("{}/{}/blog/{}", site.public_url, locale.code, item.slug),
+        title: item.title,
+        summary: item.summary,
+        published: item.published_at,
+        updated: item.updated_at,
+        categories: item.topics,
+    }
+}
@@ -1,21 +1,277 @@
-use axum::response::Json;
-use tracing::info;
+// ============================================
+// Health Handlers（ヘルスチェック）
+// ============================================
+//
+// 💡 2種類のチェック:
+// - /health/live  → プロセスが動いているか（依存先は見ない）
+//   落ちていたらコンテナを再起動する、という判断に使う
+// - /health/ready → リクエストを処理できるか（DB・接続プール・マイグレーション）
+//   落ちていたらロードバランサーから外す、という判断に使う
+//
+// 💡 なぜ分ける?
+// - DBが一時的に落ちただけでアプリを再起動しても意味がない
+// - live に依存先のチェックを入れると、DB障害で全コンテナが再起動を繰り返してしまう
 
-use crate::models::HealthResponse;
+use std::time::Duration;
 
-/// GET /health - ヘルスチェック
+use axum::{extract::State, http::StatusCode, response::Json};
+use sqlx::PgPool;
+use tracing::{info, warn};
+
+use crate::{
+    database,
+    migrations::SchemaStatus,
+    models::{
+        ComponentStatus, DatabaseHealth, HealthResponse, MigrationHealth, PoolHealth,
+        ReadinessComponents, ReadinessResponse,
+    },
+};
+
+/// DBチェックの待ち時間の上限（これを超えたら down）
+const DATABASE_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// これより遅い応答は degraded
+const SLOW_DATABASE_LATENCY: Duration = Duration::from_millis(500);
+
+/// 接続プールの使用率がこれ以上なら degraded
+const POOL_SATURATION_WARNING: f64 = 0.9;
+
+/// GET /health - ヘルスチェック（/health/live と同じ）
 #[utoipa::path(
     get,
     path = "/health",
     tag = "health",
     summary = "ヘルスチェック",
-    description = "サービスの稼働状態を確認します",
+    description = "プロセスの稼働状態を確認します（依存先は確認しません）。/health/live と同じです",
     responses(
-        (status = 200, description = "サービス正常"),
-        (status = 500, description = "サービス異常")
+        (status = 200, description = "サービス稼働中", body = HealthResponse)
     )
 )]
 pub async fn health_check() -> Json<HealthResponse> {
     info!("Health check endpoint called");
     Json(HealthResponse::healthy())
 }
+
+/// GET /health/live - ライブネスチェック
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    summary = "ライブネスチェック",
+    description = "プロセスが応答できるかを確認します。DBなどの依存先は確認しません",
+    responses(
+        (status = 200, description = "プロセス稼働中", body = HealthResponse)
+    )
+)]
+pub async fn liveness() -> Json<HealthResponse> {
+    Json(HealthResponse::healthy())
+}
+
+/// GET /health/ready - レディネスチェック
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    summary = "レディネスチェック",
+    description = "DB接続（応答時間）・接続プールの使用状況・マイグレーションの適用状況を確認します。\
+                   いずれかが down なら 503 を返します（degraded は 200）",
+    responses(
+        (status = 200, description = "リクエストを処理できる（up / degraded）", body = ReadinessResponse),
+        (status = 503, description = "リクエストを処理できない（down）", body = ReadinessResponse)
+    )
+)]
+pub async fn readiness(State(pool): State<PgPool>) -> (StatusCode, Json<ReadinessResponse>) {
+    // 💡 プールの状態は ping が接続を借りる前に読む
+    let pool_health = check_pool(&pool);
+    let (database, migrations) = tokio::join!(check_database(&pool), check_migrations(&pool));
+
+    let components = ReadinessComponents {
+        database,
+        pool: pool_health,
+        migrations,
+    };
+    let status = [
+        components.database.status,
+        components.pool.status,
+        components.migrations.status,
+    ]
+    .into_iter()
+    .max()
+    .unwrap_or(ComponentStatus::Up);
+
+    if status != ComponentStatus::Up {
+        warn!("⚠️ Readiness check: {:?}", status);
+    }
+
+    let code = if status == ComponentStatus::Down {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    (
+        code,
+        Json(ReadinessResponse {
+            status,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            components,
+        }),
+    )
+}
+
+// --------------------------------------------------------
+// check_database: SELECT 1 の往復時間を測る
+// --------------------------------------------------------
+//
+// 💡 エラーの詳細はログにだけ出す（接続先などをレスポンスに含めない）
+async fn check_database(pool: &PgPool) -> DatabaseHealth {
+    match tokio::time::timeout(DATABASE_CHECK_TIMEOUT, database::ping(pool)).await {
+        Ok(Ok(latency)) => {
+            let slow = latency > SLOW_DATABASE_LATENCY;
+            DatabaseHealth {
+                status: if slow { ComponentStatus::Degraded } else { ComponentStatus::Up },
+                latency_ms: Some(latency.as_secs_f64() * 1000.0),
+                message: slow.then(|| {
+                    format!("slower than {}ms", SLOW_DATABASE_LATENCY.as_millis())
+                }),
+            }
+        }
+        Ok(Err(e)) => {
+            warn!("⚠️ Readiness: database ping failed: {}", e);
+            DatabaseHealth {
+                status: ComponentStatus::Down,
+                latency_ms: None,
+                message: Some("database is unreachable".to_string()),
+            }
+        }
+        Err(_) => {
+            warn!("⚠️ Readiness: database ping timed out");
+            DatabaseHealth {
+                status: ComponentStatus::Down,
+                latency_ms: None,
+                message: Some(format!(
+                    "no response within {}s",
+                    DATABASE_CHECK_TIMEOUT.as_secs()
+                )),
+            }
+        }
+    }
+}
+
+// --------------------------------------------------------
+// check_pool: 接続プールの使用率
+// --------------------------------------------------------
+//
+// 💡 使い切っていても新しいリクエストは空きを待つだけなので down ではなく degraded
+fn check_pool(pool: &PgPool) -> PoolHealth {
+    let size = pool.size();
+    let idle = u32::try_from(pool.num_idle()).unwrap_or(u32::MAX).min(size);
+    let in_use = size - idle;
+    let max_connections = pool.options().get_max_connections();
+    let saturation = pool_saturation(in_use, max_connections);
+
+    let (status, message) = if pool.is_closed() {
+        (ComponentStatus::Down, Some("pool is closed".to_string()))
+    } else if saturation >= POOL_SATURATION_WARNING {
+        (
+            ComponentStatus::Degraded,
+            Some(format!("{} of {} connections in use", in_use, max_connections)),
+        )
+    } else {
+        (ComponentStatus::Up, None)
+    };
+
+    PoolHealth {
+        status,
+        size,
+        idle,
+        in_use,
+        max_connections,
+        saturation,
+        message,
+    }
+}
+
+fn pool_saturation(in_use: u32, max_connections: u32) -> f64 {
+    if max_connections == 0 {
+        return 1.0;
+    }
+    f64::from(in_use) / f64::from(max_connections)
+}
+
+// --------------------------------------------------------
+// check_migrations: スキーマがこのバイナリと一致しているか
+// --------------------------------------------------------
+//
+// 💡 判定:
+// - 未適用・失敗がある               → down（起動時チェックと同じ基準）
+// - 書き換え・未知のマイグレーションがある → degraded（ローリングデプロイ中など）
+async fn check_migrations(pool: &PgPool) -> MigrationHealth {
+    let expected_version = SchemaStatus::expected_version();
+
+    let status = match tokio::time::timeout(DATABASE_CHECK_TIMEOUT, SchemaStatus::load(pool)).await
+    {
+        Ok(Ok(status)) => status,
+        failed => {
+            match failed {
+                Ok(Err(e)) => warn!("⚠️ Readiness: failed to load migration status: {}", e),
+                _ => warn!("⚠️ Readiness: loading migration status timed out"),
+            }
+            return MigrationHealth {
+                status: ComponentStatus::Down,
+                current_version: None,
+                expected_version,
+                pending: 0,
+                message: Some("could not read migration history".to_string()),
+            };
+        }
+    };
+
+    let pending = status.pending().len();
+    let (component_status, message) = if status.is_behind() {
+        (
+            ComponentStatus::Down,
+            Some("database schema is behind this binary".to_string()),
+        )
+    } else if !status.unknown().is_empty() {
+        (
+            ComponentStatus::Degraded,
+            Some("database has migrations newer than this binary".to_string()),
+        )
+    } else if !status.checksum_mismatches().is_empty() {
+        (
+            ComponentStatus::Degraded,
+            Some("applied migrations were modified".to_string()),
+        )
+    } else {
+        (ComponentStatus::Up, None)
+    };
+
+    MigrationHealth {
+        status: component_status,
+        current_version: status.current_version(),
+        expected_version,
+        pending,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_saturation() {
+        assert_eq!(pool_saturation(0, 10), 0.0);
+        assert_eq!(pool_saturation(9, 10), 0.9);
+        assert_eq!(pool_saturation(0, 0), 1.0);
+    }
+
+    #[test]
+    fn test_component_status_order() {
+        // 全体の状態は一番悪いコンポーネントで決まる
+        assert!(ComponentStatus::Up < ComponentStatus::Degraded);
+        assert!(ComponentStatus::Degraded < ComponentStatus::Down);
+    }
+}
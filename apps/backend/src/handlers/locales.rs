@@ -1,17 +1,21 @@
 
 use axum::{
-    extract::{Path, State},  // URLパラメータと状態を取得
-    http::StatusCode,        // HTTPステータスコード（200, 404, 500など）
-    response::{IntoResponse, Json},  // JSONレスポンス
+    extract::State,    // 状態を取得
+    http::{HeaderMap, StatusCode, Uri},  // HTTPステータスコード（200, 404, 500など）
+    response::Json,    // JSONレスポンス
 };
 use serde::{Deserialize, Serialize};  // JSON変換
 use sqlx::PgPool;  // PostgreSQL接続プール
-use tracing::{error, info};  // ログ出力
-use utoipa::ToSchema;  // OpenAPIスキーマ生成
+use tracing::info;  // ログ出力
+use utoipa::{IntoParams, ToSchema};  // OpenAPIスキーマ生成
 
 use crate::{
-    entities::Locale,  // Localeエンティティ
-    repositories::LocaleRepository,  // LocaleRepository
+    auth::policy::AdminOnly,  // 管理者のみ
+    entities::{Locale, LocaleReference, LocaleReferenceKind, TranslatableEntity},  // Localeエンティティ
+    error::{AppError, AppResult, ErrorResponse},  // 共通エラー
+    extractors::{AppJson, AppPath, AppQuery, Authorized, ClientInfo},  // 失敗時も共通エラーを返すエクストラクター
+    pagination::{ListQuery, PageMeta},  // ページネーション
+    repositories::{LocaleRepository, LocaleSort},  // LocaleRepository
 };
 
 // ============================================
@@ -71,20 +75,30 @@ impl From<Locale> for LocaleResponse {
 // --------------------------------------------------------
 //
 // 💡 なぜ配列をラップする?
-// - 将来的にメタ情報を追加しやすい
-// - 例: total（総数）、page（ページ番号）など
+// - メタ情報を一緒に返せる
+// - total（総数）、page（ページ番号）などは PageMeta をそのまま展開する
+//
+// 💡 #[serde(flatten)]:
+// - PageMeta のフィールドを locales と同じ階層に並べる
+//   { "locales": [...], "total": 2, "page": 1, "per_page": 20, ... }
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct LocalesListResponse {
     /// 言語一覧
     pub locales: Vec<LocaleResponse>,
-    /// 総数
-    #[schema(example = 2)]
-    pub total: usize,
+    /// ページ情報
+    #[serde(flatten)]
+    pub pagination: PageMeta,
 }
 
 // ============================================
 // Handler関数
 // ============================================
+//
+// 💡 エラーハンドリング:
+// - 戻り値は AppResult<T>（= Result<T, AppError>）
+// - repo.find_all().await? のように ? を付けるだけで、
+//   sqlx::Error が AppError に変換され、共通JSONのエラーレスポンスになる
+// - 「見つからない」などの業務エラーは AppError::NotFound(...) などを直接返す
 
 // --------------------------------------------------------
 // list_locales: 全言語取得
@@ -98,124 +112,51 @@ pub struct LocalesListResponse {
 //   - main.rsで.with_state(pool)で渡したPgPoolを取得
 //   - 自動的にCloneされる
 //
-// 💡 戻り値:
-//   Result<Json<LocalesListResponse>, impl IntoResponse>
-//   - 成功時: Json(レスポンス) → 200 OK
-//   - 失敗時: (StatusCode, Json(エラー)) → 500 Error
-//
 // 💡 使用例:
 //   curl http://localhost:8000/api/v1/locales
+//   curl "http://localhost:8000/api/v1/locales?sort=code&order=desc&per_page=10"
 #[utoipa::path(
     get,
     path = "/api/v1/locales",
     tag = "locales",
     summary = "全言語取得",
-    description = "登録されているすべての言語情報を取得します",
+    description = "登録されているすべての言語情報を取得します。\
+                   sort には locale_id（デフォルト）/ code / name を指定できます",
+    params(ListQuery),
     responses(
-        (status = 200, description = "言語一覧", body = LocalesListResponse),
-        (status = 500, description = "サーバーエラー")
+        (status = 200, description = "言語一覧（Link ヘッダーに前後のページのURL）", body = LocalesListResponse),
+        (status = 400, description = "ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
     )
 )]
 pub async fn list_locales(
     State(pool): State<PgPool>,
-) -> Result<Json<LocalesListResponse>, impl IntoResponse> {
-    // ------------------------------------------------
-    // 1. ログ出力（デバッグ用）
-    // ------------------------------------------------
-    //
-    // 💡 info!マクロ:
-    // - tracingクレートのログマクロ
-    // - コンソールに出力される
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<LocalesListResponse>)> {
+    // 💡 info!マクロ: tracingクレートのログマクロ
     // - レベル: error! > warn! > info! > debug! > trace!
     info!("🌐 Fetching all locales...");
-    
-    // ------------------------------------------------
-    // 2. Repositoryを作成
-    // ------------------------------------------------
-    //
-    // 💡 LocaleRepository::new(pool):
-    // - poolを渡して新しいRepositoryインスタンスを作成
+
+    // 💡 ?演算子:
+    // - ページ・並び替えの指定が不正なら 400 を即 return
+    let params = list.resolve::<LocaleSort>()?;
+
     let repo = LocaleRepository::new(pool);
-    
-    // ------------------------------------------------
-    // 3. データベースから言語を取得
-    // ------------------------------------------------
-    //
-    // 💡 matchによるエラーハンドリング:
-    // - Result<T, E>をパターンマッチング
-    // - Ok(value) → 成功、valueを使用
-    // - Err(e) → エラー、エラーレスポンスを返す
-    let locales = match repo.find_all().await {
-        Ok(locales) => locales,  // 成功 → localesを取り出す
-        Err(e) => {
-            // ------------------------------------------------
-            // エラー処理
-            // ------------------------------------------------
-            //
-            // 💡 error!マクロ:
-            // - エラーレベルのログ出力
-            // - {:?}でDebug形式で出力
-            error!("❌ Failed to fetch locales: {:?}", e);
-            
-            // ------------------------------------------------
-            // エラーレスポンスを返す
-            // ------------------------------------------------
-            //
-            // 💡 return Err(...):
-            // - 早期リターン（関数を即座に終了）
-            // - タプル (StatusCode, Json) を返す
-            //
-            // 💡 serde_json::json!マクロ:
-            // - JSONを簡単に作成できる
-            // - 例: json!({"key": "value"})
-            return Err((
-                StatusCode::INTERNAL_SERVER_ERROR,  // 500エラー
-                Json(serde_json::json!({
-                    "error": "Failed to fetch locales",
-                    "message": "データベースエラーが発生しました"
-                })),
-            ));
-        }
-    };
-    
-    // ------------------------------------------------
-    // 4. EntityをResponseに変換
-    // ------------------------------------------------
-    //
-    // 💡 イテレータチェーン:
-    // locales (Vec<Locale>)
-    //   ↓ .into_iter() : 所有権を移動するイテレータ
-    //   ↓ .map(|l| l.into()) : 各LocaleをLocaleResponseに変換
-    //   ↓ .collect() : Vec<LocaleResponse>に集める
-    let locale_responses: Vec<LocaleResponse> = locales
-        .into_iter()  // イテレータに変換
-        .map(|locale| locale.into())  // LocaleをLocaleResponseに変換
-        .collect();  // Vec<LocaleResponse>に集める
-    
-    // ------------------------------------------------
-    // 5. レスポンスを作成
-    // ------------------------------------------------
-    let total = locale_responses.len();  // 総数
-    
-    let response = LocalesListResponse {
-        locales: locale_responses,
-        total,
-    };
-    
-    // ------------------------------------------------
-    // 6. 成功ログ出力
-    // ------------------------------------------------
-    info!("✅ Successfully fetched {} locales", total);
-    
-    // ------------------------------------------------
-    // 7. JSONレスポンスを返す
-    // ------------------------------------------------
-    //
-    // 💡 Ok(Json(response)):
-    // - Result型の成功値
-    // - Json()でJSONレスポンスに変換
-    // - Content-Type: application/jsonが自動設定される
-    Ok(Json(response))
+
+    // 💡 ?演算子:
+    // - Err(sqlx::Error) の場合、AppError に変換して即 return
+    let page = repo.find_page(false, &params).await?;
+    let headers = page.link_headers(&uri);
+
+    // 💡 into_parts:
+    // - Page<Locale> を「変換した配列」と「ページ情報」に分ける
+    // - 各LocaleはFrom実装でLocaleResponseに変換
+    let (locales, pagination) = page.into_parts(LocaleResponse::from);
+
+    info!("✅ Successfully fetched {} of {} locales", locales.len(), pagination.total);
+
+    Ok((headers, Json(LocalesListResponse { locales, pagination })))
 }
 
 // --------------------------------------------------------
@@ -233,60 +174,43 @@ pub async fn list_locales(
     tag = "locales",
     summary = "有効な言語のみ取得",
     description = "有効化されている言語のみを取得します",
+    params(ListQuery),
     responses(
-        (status = 200, description = "有効な言語一覧", body = LocalesListResponse),
-        (status = 500, description = "サーバーエラー")
+        (status = 200, description = "有効な言語一覧（Link ヘッダーに前後のページのURL）", body = LocalesListResponse),
+        (status = 400, description = "ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
     )
 )]
 pub async fn list_active_locales(
     State(pool): State<PgPool>,
-) -> Result<Json<LocalesListResponse>, impl IntoResponse> {
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<LocalesListResponse>)> {
     info!("🌐 Fetching active locales...");
-    
+
+    let params = list.resolve::<LocaleSort>()?;
     let repo = LocaleRepository::new(pool);
-    
-    // find_active()で有効な言語のみ取得
-    let locales = match repo.find_active().await {
-        Ok(locales) => locales,
-        Err(e) => {
-            error!("❌ Failed to fetch active locales: {:?}", e);
-            return Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({
-                    "error": "Failed to fetch active locales",
-                    "message": "データベースエラーが発生しました"
-                })),
-            ));
-        }
-    };
-    
-    let locale_responses: Vec<LocaleResponse> = locales
-        .into_iter()
-        .map(|locale| locale.into())
-        .collect();
-    
-    let total = locale_responses.len();
-    
-    let response = LocalesListResponse {
-        locales: locale_responses,
-        total,
-    };
-    
-    info!("✅ Successfully fetched {} active locales", total);
-    
-    Ok(Json(response))
+
+    // active_only = true で有効な言語のみ取得
+    let page = repo.find_page(true, &params).await?;
+    let headers = page.link_headers(&uri);
+    let (locales, pagination) = page.into_parts(LocaleResponse::from);
+
+    info!("✅ Successfully fetched {} active locales", locales.len());
+
+    Ok((headers, Json(LocalesListResponse { locales, pagination })))
 }
 
 // --------------------------------------------------------
 // get_locale_by_code: 特定言語取得
 // --------------------------------------------------------
 //
-// 💡 エンドポイント: GET /api/v1/locales/:code
+// 💡 エンドポイント: GET /api/v1/locales/{code}
 //
 // 💡 引数:
-//   Path(code): Path<String>
-//   - Axumのパス抽出（Path Extractor）
-//   - URLの:codeの部分を取得
+//   AppPath(code): AppPath<String>
+//   - Axumのパス抽出（Path Extractor）を包んだもの
+//   - URLの{code}の部分を取得
 //   - 例: /api/v1/locales/ja → code = "ja"
 //
 // 💡 使用例:
@@ -302,71 +226,812 @@ pub async fn list_active_locales(
     ),
     responses(
         (status = 200, description = "言語情報", body = LocaleResponse),
-        (status = 404, description = "言語が見つかりません"),
-        (status = 500, description = "サーバーエラー")
+        (status = 404, description = "言語が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
     )
 )]
 pub async fn get_locale_by_code(
     State(pool): State<PgPool>,
-    Path(code): Path<String>,  // URLパラメータを取得
-) -> Result<Json<LocaleResponse>, impl IntoResponse> {
+    AppPath(code): AppPath<String>,  // URLパラメータを取得
+) -> AppResult<Json<LocaleResponse>> {
     info!("🌐 Fetching locale: {}", code);
-    
+
     let repo = LocaleRepository::new(pool);
-    
+
     // ------------------------------------------------
-    // find_by_code()で特定言語を取得
+    // Option → Result への変換
     // ------------------------------------------------
     //
-    // 💡 戻り値: Result<Option<Locale>, sqlx::Error>
-    // - 見つかった: Ok(Some(locale))
-    // - 見つからない: Ok(None)
-    // - エラー: Err(e)
-    let locale_opt = match repo.find_by_code(&code).await {
-        Ok(locale_opt) => locale_opt,
-        Err(e) => {
-            error!("❌ Failed to fetch locale {}: {:?}", code, e);
-            return Err((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({
-                    "error": "Failed to fetch locale",
-                    "message": "データベースエラーが発生しました"
-                })),
-            ));
+    // 💡 find_by_code() の戻り値: Result<Option<Locale>, sqlx::Error>
+    // - ? で sqlx::Error を処理
+    // - .ok_or_else() で None を 404 エラーに変換
+    let locale = repo
+        .find_by_code(&code)
+        .await?
+        .ok_or_else(|| locale_not_found(&code))?;
+
+    info!("✅ Found locale: {}", locale.name);
+
+    Ok(Json(locale.into()))
+}
+
+// ============================================
+// 管理用リクエスト構造体
+// ============================================
+
+// --------------------------------------------------------
+// CreateLocaleRequest: 言語作成リクエスト
+// --------------------------------------------------------
+//
+// 💡 #[serde(default)]:
+// - JSONに項目がない場合、型のデフォルト値を使う
+// - is_default を省略すると false になる
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLocaleRequest {
+    /// 言語コード（ISO 639-1、地域付きも可: zh-TW）
+    #[schema(example = "zh")]
+    pub code: String,
+    /// 言語名
+    #[schema(example = "Chinese")]
+    pub name: String,
+    /// デフォルト言語にするか（trueの場合、既存のデフォルトは解除される）
+    #[serde(default)]
+    #[schema(example = false)]
+    pub is_default: bool,
+    /// 有効化フラグ（省略時: true）
+    #[serde(default = "default_is_active")]
+    #[schema(example = true)]
+    pub is_active: bool,
+}
+
+fn default_is_active() -> bool {
+    true
+}
+
+// --------------------------------------------------------
+// UpdateLocaleRequest: 言語更新リクエスト（部分更新）
+// --------------------------------------------------------
+//
+// 💡 Option<T>:
+// - 指定された項目だけを更新する
+// - is_active: true で有効化（無効化は deactivate_locale）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateLocaleRequest {
+    /// 言語名
+    #[schema(example = "中文")]
+    pub name: Option<String>,
+    /// 有効化フラグ（true のみ。無効化は POST /api/v1/locales/{code}/deactivate）
+    #[schema(example = true)]
+    pub is_active: Option<bool>,
+}
+
+// --------------------------------------------------------
+// 入力チェック
+// --------------------------------------------------------
+//
+// 💡 許可する形式:
+// - "ja", "en", "zh" のような小文字2〜3文字
+// - "zh-TW", "pt-BR" のような地域付きコード
+// - localesテーブルの VARCHAR(10) に収まる長さ
+pub fn is_valid_locale_code(code: &str) -> bool {
+    if code.len() > 10 {
+        return false;
+    }
+
+    let mut parts = code.splitn(2, '-');
+    let language = parts.next().unwrap_or_default();
+    let language_ok = (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase());
+
+    let region_ok = match parts.next() {
+        Some(region) => {
+            (2..=8).contains(&region.len()) && region.chars().all(|c| c.is_ascii_alphanumeric())
         }
+        None => true,
+    };
+
+    language_ok && region_ok
+}
+
+fn validate_locale_name(name: &str) -> AppResult<()> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(AppError::BadRequest("Locale name must not be empty".to_string()));
+    }
+    if trimmed.chars().count() > 100 {
+        return Err(AppError::BadRequest(
+            "Locale name must be at most 100 characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 言語が見つからない場合の 404 エラー
+pub fn locale_not_found(code: &str) -> AppError {
+    AppError::NotFound(format!("Locale '{}' not found", code))
+}
+
+// --------------------------------------------------------
+// create_locale: 言語を追加
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/locales
+//
+// 💡 コードの重複:
+// - UNIQUE制約違反は AppError が自動で 409 unique_violation に変換する
+//
+// 💡 使用例:
+//   curl -X POST http://localhost:8000/api/v1/locales \
+//     -H 'Content-Type: application/json' \
+//     -d '{"code": "zh", "name": "Chinese"}'
+#[utoipa::path(
+    post,
+    path = "/api/v1/locales",
+    tag = "locales",
+    summary = "言語追加",
+    description = "新しい言語を登録します。is_default = true の場合、既存のデフォルト言語は同じトランザクション内で解除されます",
+    request_body = CreateLocaleRequest,
+    responses(
+        (status = 201, description = "作成された言語", body = LocaleResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 409, description = "言語コードが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn create_locale(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppJson(payload): AppJson<CreateLocaleRequest>,
+) -> AppResult<(StatusCode, Json<LocaleResponse>)> {
+    info!("🌐 Creating locale: {}", payload.code);
+
+    if !is_valid_locale_code(&payload.code) {
+        return Err(AppError::BadRequest(format!(
+            "Invalid locale code '{}' (expected e.g. ja, en, zh-TW)",
+            payload.code
+        )));
+    }
+    validate_locale_name(&payload.name)?;
+    if payload.is_default && !payload.is_active {
+        return Err(AppError::BadRequest(
+            "The default locale must be active".to_string(),
+        ));
+    }
+
+    let repo = LocaleRepository::new(pool);
+
+    let locale = repo
+        .create(
+            &payload.code,
+            payload.name.trim(),
+            payload.is_default,
+            payload.is_active,
+            &admin.audit_context(&client),
+        )
+        .await?;
+
+    info!("✅ Created locale: {}", locale.display_info());
+
+    Ok((StatusCode::CREATED, Json(locale.into())))
+}
+
+// --------------------------------------------------------
+// update_locale: 言語名の変更・有効化
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: PATCH /api/v1/locales/{code}
+//
+// 💡 使用例:
+//   # 有効化
+//   curl -X PATCH http://localhost:8000/api/v1/locales/zh \
+//     -H 'Content-Type: application/json' -d '{"is_active": true}'
+//
+// 💡 is_active: false は 400（無効化は deactivate_locale だけで行う）
+// - 影響の確認・参照の付け替え・監査ログを1つの経路にまとめるため
+#[utoipa::path(
+    patch,
+    path = "/api/v1/locales/{code}",
+    tag = "locales",
+    summary = "言語更新",
+    description = "言語名の変更、有効化を行います。無効化は POST /api/v1/locales/{code}/deactivate で行います",
+    params(
+        ("code" = String, Path, description = "言語コード（例: ja, en）")
+    ),
+    request_body = UpdateLocaleRequest,
+    responses(
+        (status = 200, description = "更新後の言語情報", body = LocaleResponse),
+        (status = 400, description = "入力が不正です / is_active: false は指定できません", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "言語が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn update_locale(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppPath(code): AppPath<String>,
+    AppJson(payload): AppJson<UpdateLocaleRequest>,
+) -> AppResult<Json<LocaleResponse>> {
+    info!("🌐 Updating locale: {}", code);
+
+    if payload.name.is_none() && payload.is_active.is_none() {
+        return Err(AppError::BadRequest(
+            "Specify at least one of name or is_active".to_string(),
+        ));
+    }
+    if payload.is_active == Some(false) {
+        return Err(AppError::BadRequest(format!(
+            "Use POST /api/v1/locales/{}/deactivate to deactivate a locale",
+            code
+        )));
+    }
+    if let Some(name) = &payload.name {
+        validate_locale_name(name)?;
+    }
+
+    let repo = LocaleRepository::new(pool);
+
+    let locale = repo
+        .update(
+            &code,
+            payload.name.as_deref().map(str::trim),
+            payload.is_active,
+            &admin.audit_context(&client),
+        )
+        .await?
+        .ok_or_else(|| locale_not_found(&code))?;
+
+    info!("✅ Updated locale: {}", locale.display_info());
+
+    Ok(Json(locale.into()))
+}
+
+// --------------------------------------------------------
+// set_default_locale: デフォルト言語の切り替え
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/locales/{code}/default
+//
+// 💡 トランザクションで「旧デフォルトの解除」と「新デフォルトの設定」を
+//    まとめて行うため、is_default = TRUE の行は常に1つだけになる
+#[utoipa::path(
+    post,
+    path = "/api/v1/locales/{code}/default",
+    tag = "locales",
+    summary = "デフォルト言語設定",
+    description = "指定した言語をデフォルト言語にします。既存のデフォルト言語は同じトランザクション内で解除されます",
+    params(
+        ("code" = String, Path, description = "言語コード（例: ja, en）")
+    ),
+    responses(
+        (status = 200, description = "新しいデフォルト言語", body = LocaleResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "言語が見つかりません", body = ErrorResponse),
+        (status = 409, description = "無効化された言語はデフォルトにできません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn set_default_locale(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppPath(code): AppPath<String>,
+) -> AppResult<Json<LocaleResponse>> {
+    info!("🌐 Setting default locale: {}", code);
+
+    let repo = LocaleRepository::new(pool);
+
+    let previous = repo.find_default().await?;
+
+    let Some(locale) = repo.set_default(&code, &admin.audit_context(&client)).await? else {
+        // 存在しないのか、無効なのかを区別して返す
+        return Err(match repo.find_by_code(&code).await? {
+            Some(_) => AppError::Conflict(format!(
+                "Locale '{}' is inactive and cannot be the default locale",
+                code
+            )),
+            None => locale_not_found(&code),
+        });
     };
-    
+
+    info!(
+        "✅ Default locale changed: {} → {}",
+        previous.map(|l| l.code).unwrap_or_else(|| "(none)".to_string()),
+        locale.display_info()
+    );
+
+    Ok(Json(locale.into()))
+}
+
+// ============================================
+// 言語の無効化・削除の影響
+// ============================================
+//
+// 💡 言語を無効化すると、その言語の翻訳は公開APIから見えなくなる（行は残る）
+// 💡 言語を削除するには、参照している行（翻訳・版・原文の言語）が1つもないことが必要
+//    → 翻訳テーブルの locale_id は ON DELETE RESTRICT（migration 004 / 005 / 010 / 011 / 015）
+//
+// 💡 流れ:
+//   1. GET  /api/v1/locales/{code}/impact?migrate_to=en  → 参照している行と、付け替えられない行（conflict）
+//   2. POST /api/v1/locales/{code}/deactivate {"migrate_to": "en"} → 付け替え + 無効化（1トランザクション）
+//   3. DELETE /api/v1/locales/{code}                     → 参照がなくなった言語を削除
+//
+// 💡 デフォルト言語は無効化・削除できない（先に別の言語をデフォルトにする）
+
+/// 影響の確認のクエリパラメータ
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LocaleImpactQuery {
+    /// 付け替え先の言語コード（指定すると、付け替えられない行を conflict として返す）
+    pub migrate_to: Option<String>,
+}
+
+/// 言語を参照している1行（版は記事ごとにまとめる）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LocaleReferenceResponse {
+    /// 参照しているテーブル
+    #[schema(example = "blog_post_translations")]
+    pub table_name: String,
+    pub kind: LocaleReferenceKind,
+    pub entity_type: TranslatableEntity,
+    #[schema(example = 1)]
+    pub entity_id: i32,
+    /// slug（チャプターは 本のslug/チャプターのslug、メディアは元のファイル名）
+    #[schema(example = "rust-ownership")]
+    pub label: String,
+    /// 行数（版は記事ごとの版の数）
+    #[schema(example = 1)]
+    pub row_count: i64,
+    /// 付け替え先の言語に同じデータの行がある（先にどちらかを削除する必要がある）
+    #[schema(example = false)]
+    pub conflict: bool,
+}
+
+impl From<LocaleReference> for LocaleReferenceResponse {
+    fn from(reference: LocaleReference) -> Self {
+        Self {
+            table_name: reference.table_name,
+            kind: reference.kind,
+            entity_type: reference.entity_type,
+            entity_id: reference.entity_id,
+            label: reference.label,
+            row_count: reference.row_count,
+            conflict: reference.conflict,
+        }
+    }
+}
+
+/// 言語の無効化・削除の影響
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LocaleImpactResponse {
+    pub locale: LocaleResponse,
+    /// 付け替え先の言語コード（クエリで指定した場合）
+    #[schema(example = "en")]
+    pub migrate_to: Option<String>,
+    /// 参照している行の合計
+    #[schema(example = 12)]
+    pub total_rows: i64,
+    /// 付け替えられない行の合計
+    #[schema(example = 0)]
+    pub conflicts: i64,
+    /// 無効化できるか（migrate_to を指定した場合は、付け替えも含めて）
+    pub can_deactivate: bool,
+    /// 今すぐ削除できるか（参照している行がない、デフォルト言語でない）
+    pub can_delete: bool,
+    pub references: Vec<LocaleReferenceResponse>,
+}
+
+/// 無効化リクエスト
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct DeactivateLocaleRequest {
+    /// 参照を付け替える言語コード（省略時は付け替えずに無効化するだけ）
+    #[schema(example = "en")]
+    pub migrate_to: Option<String>,
+}
+
+/// 無効化の結果
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeactivateLocaleResponse {
+    pub locale: LocaleResponse,
+    #[schema(example = "en")]
+    pub migrated_to: Option<String>,
+    /// 付け替えた行数
+    #[schema(example = 12)]
+    pub migrated_rows: u64,
+}
+
+// --------------------------------------------------------
+// get_locale_impact: 無効化・削除の影響を確認
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/locales/{code}/impact
+//
+// 💡 使用例:
+//   curl 'http://localhost:8000/api/v1/locales/zh/impact?migrate_to=en'
+#[utoipa::path(
+    get,
+    path = "/api/v1/locales/{code}/impact",
+    tag = "locales",
+    summary = "言語の無効化・削除の影響",
+    description = "言語を参照している翻訳・版・原文の言語の行を一覧にします。\
+                   migrate_to を指定すると、その言語に付け替えられない行（同じデータの翻訳がすでにある）を conflict として返します",
+    params(
+        ("code" = String, Path, description = "言語コード（例: ja, en）"),
+        LocaleImpactQuery
+    ),
+    responses(
+        (status = 200, description = "参照している行と、無効化・削除できるか", body = LocaleImpactResponse),
+        (status = 400, description = "付け替え先の言語が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "言語が見つかりません", body = ErrorResponse),
+        (status = 409, description = "付け替え先の言語が無効です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn get_locale_impact(
+    State(pool): State<PgPool>,
+    _admin: Authorized<AdminOnly>,
+    AppPath(code): AppPath<String>,
+    AppQuery(query): AppQuery<LocaleImpactQuery>,
+) -> AppResult<Json<LocaleImpactResponse>> {
+    info!("🌐 Checking impact of deactivating locale: {}", code);
+
+    let repo = LocaleRepository::new(pool);
+
+    let locale = repo
+        .find_by_code(&code)
+        .await?
+        .ok_or_else(|| locale_not_found(&code))?;
+    let target = resolve_migration_target(&repo, &locale, query.migrate_to.as_deref()).await?;
+
+    let references = repo
+        .find_references(locale.locale_id, target.as_ref().map(|t| t.locale_id))
+        .await?;
+    let impact = assess_impact(locale.is_default, &references);
+
+    info!(
+        "✅ Locale {} is referenced by {} rows ({} conflicts)",
+        code, impact.total_rows, impact.conflicts
+    );
+
+    Ok(Json(LocaleImpactResponse {
+        locale: locale.into(),
+        migrate_to: target.map(|t| t.code),
+        total_rows: impact.total_rows,
+        conflicts: impact.conflicts,
+        can_deactivate: impact.can_deactivate,
+        can_delete: impact.can_delete,
+        references: references.into_iter().map(LocaleReferenceResponse::from).collect(),
+    }))
+}
+
+// --------------------------------------------------------
+// deactivate_locale: 参照を付け替えてから無効化
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/locales/{code}/deactivate
+//
+// 💡 migrate_to を省略すると、翻訳は残したまま無効化する（言語を無効化する唯一の経路）
+//
+// 💡 使用例:
+//   curl -X POST http://localhost:8000/api/v1/locales/zh/deactivate \
+//     -H 'Content-Type: application/json' -d '{"migrate_to": "en"}'
+#[utoipa::path(
+    post,
+    path = "/api/v1/locales/{code}/deactivate",
+    tag = "locales",
+    summary = "言語の無効化（参照の付け替え）",
+    description = "migrate_to を指定すると、翻訳・版・記事と本の原文の言語を指定した言語に付け替えてから、\
+                   同じトランザクションで言語を無効化します。付け替えられない行（conflict）がある場合は何も変更しません。\
+                   デフォルト言語は無効化できません",
+    params(
+        ("code" = String, Path, description = "言語コード（例: ja, en）")
+    ),
+    request_body = DeactivateLocaleRequest,
+    responses(
+        (status = 200, description = "無効化した言語と付け替えた行数", body = DeactivateLocaleResponse),
+        (status = 400, description = "付け替え先の言語が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "言語が見つかりません", body = ErrorResponse),
+        (status = 409, description = "デフォルト言語、付け替え先が無効、または付け替えられない行があります", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn deactivate_locale(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppPath(code): AppPath<String>,
+    AppJson(payload): AppJson<DeactivateLocaleRequest>,
+) -> AppResult<Json<DeactivateLocaleResponse>> {
+    info!("🌐 Deactivating locale: {} (migrate_to: {:?})", code, payload.migrate_to);
+
+    let repo = LocaleRepository::new(pool);
+
+    let locale = repo
+        .find_by_code(&code)
+        .await?
+        .ok_or_else(|| locale_not_found(&code))?;
+    if locale.is_default {
+        return Err(default_locale_conflict(&code, "deactivated"));
+    }
+    let target = resolve_migration_target(&repo, &locale, payload.migrate_to.as_deref()).await?;
+
+    // 付け替えられない行があれば、何も変更せずに返す
+    if let Some(target) = &target {
+        let references = repo.find_references(locale.locale_id, Some(target.locale_id)).await?;
+        let conflicts: Vec<_> = references.iter().filter(|r| r.conflict).collect();
+        if !conflicts.is_empty() {
+            return Err(AppError::Conflict(format!(
+                "Cannot migrate locale '{}' to '{}': '{}' already has {}; delete one of the translations first",
+                code,
+                target.code,
+                target.code,
+                conflicts
+                    .iter()
+                    .map(|r| format!("{} '{}'", r.table_name, r.label))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )));
+        }
+    }
+
+    let result = repo
+        .deactivate(
+            locale.locale_id,
+            target.as_ref().map(|t| t.locale_id),
+            &admin.audit_context(&client),
+        )
+        .await?;
+
     // ------------------------------------------------
-    // Optionのハンドリング
+    // None = 事前チェックの後に、別のリクエストでデフォルト・有効フラグが変わった
     // ------------------------------------------------
-    //
-    // 💡 matchでOption<Locale>を分岐:
-    // - Some(locale) → 見つかった
-    // - None → 見つからない（404エラー）
-    match locale_opt {
-        Some(locale) => {
-            info!("✅ Found locale: {}", locale.name);
-            
-            // LocaleをLocaleResponseに変換
-            let response: LocaleResponse = locale.into();
-            
-            Ok(Json(response))
+    let Some((locale, migrated_rows)) = result else {
+        return Err(AppError::Conflict(format!(
+            "Locale '{}' or its migration target changed during deactivation; check the locales and retry",
+            code
+        )));
+    };
+
+    info!(
+        "✅ Deactivated locale: {} (migrated {} rows)",
+        locale.display_info(),
+        migrated_rows
+    );
+
+    Ok(Json(DeactivateLocaleResponse {
+        locale: locale.into(),
+        migrated_to: target.map(|t| t.code),
+        migrated_rows,
+    }))
+}
+
+// --------------------------------------------------------
+// delete_locale: 言語を削除
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: DELETE /api/v1/locales/{code}
+//
+// 💡 参照している行があれば 409（どのテーブルに何行あるかをメッセージに含める）
+//    → 先に POST /api/v1/locales/{code}/deactivate で付け替える
+#[utoipa::path(
+    delete,
+    path = "/api/v1/locales/{code}",
+    tag = "locales",
+    summary = "言語削除",
+    description = "どこからも参照されていない言語を削除します。デフォルト言語と、翻訳・版・原文の言語として参照されている言語は削除できません",
+    params(
+        ("code" = String, Path, description = "言語コード（例: ja, en）")
+    ),
+    responses(
+        (status = 204, description = "削除しました"),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "管理者のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "言語が見つかりません", body = ErrorResponse),
+        (status = 409, description = "デフォルト言語、または参照されています", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = ["admin"]), ("session_cookie" = ["admin"]))
+)]
+pub async fn delete_locale(
+    State(pool): State<PgPool>,
+    admin: Authorized<AdminOnly>,
+    client: ClientInfo,
+    AppPath(code): AppPath<String>,
+) -> AppResult<StatusCode> {
+    info!("🌐 Deleting locale: {}", code);
+
+    let repo = LocaleRepository::new(pool);
+
+    let locale = repo
+        .find_by_code(&code)
+        .await?
+        .ok_or_else(|| locale_not_found(&code))?;
+    if locale.is_default {
+        return Err(default_locale_conflict(&code, "deleted"));
+    }
+
+    let references = repo.find_references(locale.locale_id, None).await?;
+    if !references.is_empty() {
+        return Err(AppError::Conflict(format!(
+            "Locale '{}' is still referenced ({}); migrate the references with POST /api/v1/locales/{}/deactivate first",
+            code,
+            describe_table_counts(&references),
+            code
+        )));
+    }
+
+    // 💡 false = 事前チェックの後にデフォルト言語になった
+    if !repo.delete(locale.locale_id, &admin.audit_context(&client)).await? {
+        return Err(default_locale_conflict(&code, "deleted"));
+    }
+
+    info!("✅ Deleted locale: {}", code);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// --------------------------------------------------------
+// resolve_migration_target: 付け替え先の言語
+// --------------------------------------------------------
+//
+// 💡 存在しない・自分自身 → 400、無効な言語 → 409（無効な言語の翻訳は公開されないため）
+async fn resolve_migration_target(
+    repo: &LocaleRepository,
+    locale: &Locale,
+    migrate_to: Option<&str>,
+) -> AppResult<Option<Locale>> {
+    let Some(target_code) = migrate_to else {
+        return Ok(None);
+    };
+    if target_code == locale.code {
+        return Err(AppError::BadRequest(format!(
+            "migrate_to must be a different locale than '{}'",
+            locale.code
+        )));
+    }
+
+    let target = repo
+        .find_by_code(target_code)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("Unknown locale '{}'", target_code)))?;
+    if !target.is_active {
+        return Err(AppError::Conflict(format!(
+            "Locale '{}' is inactive and cannot receive migrated references",
+            target_code
+        )));
+    }
+
+    Ok(Some(target))
+}
+
+fn default_locale_conflict(code: &str, action: &str) -> AppError {
+    AppError::Conflict(format!(
+        "Locale '{}' is the default locale and cannot be {}; set another default first",
+        code, action
+    ))
+}
+
+/// 影響の集計
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Impact {
+    total_rows: i64,
+    conflicts: i64,
+    can_deactivate: bool,
+    can_delete: bool,
+}
+
+// --------------------------------------------------------
+// assess_impact: 参照している行 → 無効化・削除できるか
+// --------------------------------------------------------
+//
+// 💡 無効化: デフォルト言語でなく、付け替えられない行がない（付け替えない場合は常に0）
+// 💡 削除: デフォルト言語でなく、参照している行が1つもない
+fn assess_impact(is_default: bool, references: &[LocaleReference]) -> Impact {
+    let total_rows = references.iter().map(|r| r.row_count).sum();
+    let conflicts = references.iter().filter(|r| r.conflict).map(|r| r.row_count).sum();
+
+    Impact {
+        total_rows,
+        conflicts,
+        can_deactivate: !is_default && conflicts == 0,
+        can_delete: !is_default && total_rows == 0,
+    }
+}
+
+/// 409 のメッセージ用（例: blog_post_translations: 3, books: 1）
+fn describe_table_counts(references: &[LocaleReference]) -> String {
+    let mut counts: Vec<(&str, i64)> = Vec::new();
+    for reference in references {
+        match counts.iter_mut().find(|(table, _)| *table == reference.table_name) {
+            Some((_, count)) => *count += reference.row_count,
+            None => counts.push((&reference.table_name, reference.row_count)),
         }
-        None => {
-            // ------------------------------------------------
-            // 404 Not Found
-            // ------------------------------------------------
-            info!("⚠️ Locale not found: {}", code);
-            
-            Err((
-                StatusCode::NOT_FOUND,  // 404エラー
-                Json(serde_json::json!({
-                    "error": "Locale not found",
-                    "message": format!("言語コード '{}' が見つかりません", code)
-                })),
-            ))
+    }
+
+    counts
+        .iter()
+        .map(|(table, count)| format!("{}: {}", table, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_locale_code() {
+        assert!(is_valid_locale_code("ja"));
+        assert!(is_valid_locale_code("fil"));
+        assert!(is_valid_locale_code("zh-TW"));
+        assert!(is_valid_locale_code("es-419"));
+
+        assert!(!is_valid_locale_code(""));
+        assert!(!is_valid_locale_code("JA"));
+        assert!(!is_valid_locale_code("j"));
+        assert!(!is_valid_locale_code("zh-"));
+        assert!(!is_valid_locale_code("zh_TW"));
+        assert!(!is_valid_locale_code("en-verylongtag"));
+    }
+
+    fn reference(table_name: &str, row_count: i64, conflict: bool) -> LocaleReference {
+        LocaleReference {
+            table_name: table_name.to_string(),
+            kind: LocaleReferenceKind::Translation,
+            entity_type: TranslatableEntity::Post,
+            entity_id: 1,
+            label: "rust-ownership".to_string(),
+            row_count,
+            conflict,
         }
     }
+
+    #[test]
+    fn test_assess_impact() {
+        let unused = assess_impact(false, &[]);
+        assert!(unused.can_deactivate && unused.can_delete);
+
+        let references = vec![
+            reference("blog_post_translations", 1, false),
+            reference("blog_post_translation_revisions", 3, true),
+        ];
+        let impact = assess_impact(false, &references);
+        assert_eq!((impact.total_rows, impact.conflicts), (4, 3));
+        assert!(!impact.can_deactivate);
+        assert!(!impact.can_delete);
+
+        // デフォルト言語は参照がなくても無効化・削除できない
+        let default = assess_impact(true, &[]);
+        assert!(!default.can_deactivate && !default.can_delete);
+    }
+
+    #[test]
+    fn test_describe_table_counts() {
+        let references = vec![
+            reference("blog_post_translations", 1, false),
+            reference("topic_translations", 1, false),
+            reference("blog_post_translations", 1, false),
+        ];
+        assert_eq!(
+            describe_table_counts(&references),
+            "blog_post_translations: 2, topic_translations: 1"
+        );
+    }
 }
 
 // ============================================
@@ -380,31 +1045,28 @@ pub async fn get_locale_by_code(
 //   → Axumの状態抽出
 //   → main.rsで.with_state()で渡した値を取得
 //
-// Path<T>
-//   → Axumのパス抽出
-//   → URLの:paramの部分を取得
+// AppPath<T> / AppJson<T>
+//   → Axumの Path / Json を包んだエクストラクター（extractors/json.rs）
+//   → 失敗時も AppError の共通JSONを返す
 //
 // Json<T>
 //   → JSONレスポンス
 //   → Serializeトレイトを実装した型をJSONに変換
 //
-// IntoResponse
-//   → HTTPレスポンスに変換できる型
-//   → Json, StatusCode, (StatusCode, Json)などが実装
-//
-// Result<T, E>
-//   → 成功（Ok）またはエラー（Err）
+// AppResult<T>
+//   → Result<T, AppError> の別名（error.rs）
+//   → AppError は IntoResponse を実装しているので、そのままレスポンスになる
 //
 // Option<T>
 //   → 値がある（Some）またはない（None）
 //
-// match
-//   → パターンマッチング
-//   → 値を分岐して処理
+// .ok_or_else(...)
+//   → Option を Result に変換
+//   → None のときだけクロージャを実行してエラーを作る
 //
-// return Err(...)
-//   → 早期リターン
-//   → 関数を即座に終了してエラーを返す
+// let Some(x) = ... else { ... };
+//   → let-else構文
+//   → パターンに一致しない場合は else 側で return する
 //
 // .into()
 //   → Fromトレイトを使った型変換
@@ -416,15 +1078,20 @@ pub async fn get_locale_by_code(
 // 200 OK
 //   → 成功
 //
+// 201 Created
+//   → 作成成功
+//
+// 400 Bad Request
+//   → 入力が不正
+//
 // 404 Not Found
 //   → リソースが見つからない
 //
+// 409 Conflict
+//   → 現在の状態と矛盾する操作（重複、デフォルト言語の無効化など）
+//
 // 500 Internal Server Error
 //   → サーバー内部エラー
 //
-// ============================================
-// 💡 次のステップ
-// ============================================
-// 1. routes/mod.rsでエンドポイントを登録
-// 2. main.rsでモジュールをインポート
-// 3. サーバーを起動して動作確認
+// 503 Service Unavailable
+//   → データベースに接続できない（プールのタイムアウトなど）
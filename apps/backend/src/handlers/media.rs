@@ -0,0 +1,922 @@
+use std::collections::HashSet;
+
+use axum::{
+    extract::{multipart::Field, State},
+    http::{
+        header::{CACHE_CONTROL, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS},
+        HeaderMap, StatusCode, Uri,
+    },
+    response::{IntoResponse, Json, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::{ensure_can_edit_media, AnyRole, EditorOrAdminDelete},
+    config::MediaConfig,
+    entities::{LocalizedMedia, MediaReference, MediaTranslation, MediaUsage},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{
+        AppJson, AppMultipart, AppPath, AppQuery, Authorized, ClientInfo, LangQuery,
+        ResolvedLocale,
+    },
+    pagination::{ListQuery, PageMeta},
+    repositories::{
+        LocaleRepository, MediaDelete, MediaFilter, MediaRepository, MediaSort,
+        MediaTranslationInput, NewMedia,
+    },
+    storage::{self, SharedStorage},
+};
+
+// ============================================
+// メディアライブラリ
+// ============================================
+//
+// 💡 アップロードの流れ（POST /api/v1/media、multipart/form-data）:
+// 1. file フィールドを少しずつ読み、media.max_upload_bytes を超えたら 413
+// 2. 先頭のバイト列でファイル形式を判定（infer）。許可していない形式は 415
+//    → Content-Type やファイル名の拡張子は信用しない（偽装できるため）
+// 3. 画像なら幅・高さを読む（imagesize、画像全体はデコードしない）
+// 4. ストレージに保存 → media に記録（記録に失敗したらファイルを消す）
+//
+// 💡 削除（DELETE /api/v1/media/{id}）:
+// - 記事・本から使われていれば 409（どこで使われているかをメッセージに含める）
+// - 行の削除をコミットしてからファイルを消す（ファイルだけ先に消えて画像が壊れないように）
+
+/// アップロードできるファイル形式（判定したMIMEタイプ → 保存するときの拡張子）
+///
+/// 💡 SVG は許可しない（スクリプトを埋め込めるため）
+const ALLOWED_TYPES: &[(&str, &str)] = &[
+    ("image/png", "png"),
+    ("image/jpeg", "jpg"),
+    ("image/gif", "gif"),
+    ("image/webp", "webp"),
+    ("application/pdf", "pdf"),
+];
+
+/// translations フィールド（JSON）の最大サイズ
+const MAX_TRANSLATIONS_BYTES: u64 = 64 * 1024;
+
+/// 代替テキストの最大文字数（media_translations.alt_text）
+const MAX_ALT_TEXT_LEN: usize = 255;
+
+/// ファイル名の最大文字数（media.original_filename）
+const MAX_FILENAME_LEN: usize = 255;
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+// --------------------------------------------------------
+// MediaResponse: 言語解決済みのメディア
+// --------------------------------------------------------
+//
+// 💡 url はストレージから組み立てる（保存先・CDN を変えても DB は変わらない）
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediaResponse {
+    /// メディアID
+    #[schema(example = 1)]
+    pub media_id: i32,
+    /// 公開URL
+    #[schema(example = "/media/2026/10/3f2a9c1e-7b7d-4c1a-9f0e-2b6f8d1c5a3e.png")]
+    pub url: String,
+    /// 保存したファイル名
+    #[schema(example = "3f2a9c1e-7b7d-4c1a-9f0e-2b6f8d1c5a3e.png")]
+    pub filename: String,
+    /// アップロードされたときのファイル名
+    #[schema(example = "screenshot.png")]
+    pub original_filename: String,
+    /// MIMEタイプ（ファイルの中身から判定）
+    #[schema(example = "image/png")]
+    pub file_type: String,
+    /// ファイルサイズ（バイト）
+    #[schema(example = 48213)]
+    pub file_size: i64,
+    /// 幅（画像のみ）
+    #[schema(example = 1280)]
+    pub width: Option<i32>,
+    /// 高さ（画像のみ）
+    #[schema(example = 720)]
+    pub height: Option<i32>,
+    /// 代替テキスト（翻訳がない場合はnull）
+    #[schema(example = "Rustのロゴ")]
+    pub alt_text: Option<String>,
+    /// キャプション
+    pub caption: Option<String>,
+    /// 代替テキスト・キャプションの言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// デフォルト言語で代用したか
+    #[schema(example = false)]
+    pub is_fallback: bool,
+    /// アップロードした管理者のID
+    pub uploaded_by: Option<i32>,
+    /// アップロード日時
+    pub created_at: DateTime<Utc>,
+}
+
+impl MediaResponse {
+    fn from_localized(media: LocalizedMedia, requested: &str, storage: &SharedStorage) -> Self {
+        Self {
+            is_fallback: media.is_fallback(requested),
+            url: storage.public_url(&media.file_path),
+            media_id: media.media_id,
+            filename: media.filename,
+            original_filename: media.original_filename,
+            file_type: media.file_type,
+            file_size: media.file_size,
+            width: media.width,
+            height: media.height,
+            alt_text: media.alt_text,
+            caption: media.caption,
+            locale: media.locale_code,
+            uploaded_by: media.uploaded_by,
+            created_at: media.created_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// MediaTranslationResponse: 翻訳1件
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediaTranslationResponse {
+    /// 言語コード
+    #[schema(example = "en")]
+    pub locale: String,
+    /// 代替テキスト
+    #[schema(example = "The Rust logo")]
+    pub alt_text: Option<String>,
+    /// キャプション
+    pub caption: Option<String>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<MediaTranslation> for MediaTranslationResponse {
+    fn from(translation: MediaTranslation) -> Self {
+        Self {
+            locale: translation.locale_code,
+            alt_text: translation.alt_text,
+            caption: translation.caption,
+            updated_at: translation.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// MediaReferenceResponse: メディアを使っている場所
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediaReferenceResponse {
+    /// 使われ方
+    pub usage: MediaUsage,
+    /// 使っている記事・本のslug
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+}
+
+impl From<MediaReference> for MediaReferenceResponse {
+    fn from(reference: MediaReference) -> Self {
+        Self {
+            usage: reference.usage,
+            slug: reference.slug,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// MediaDetailResponse: メディア詳細（全翻訳・使われている場所付き）
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediaDetailResponse {
+    /// 言語解決済みのメディア
+    pub media: MediaResponse,
+    /// 登録されているすべての翻訳
+    pub translations: Vec<MediaTranslationResponse>,
+    /// このメディアを使っている記事・本（空でなければ削除できない）
+    pub references: Vec<MediaReferenceResponse>,
+}
+
+// --------------------------------------------------------
+// MediaListResponse: メディア一覧
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MediaListResponse {
+    /// メディア一覧
+    pub media: Vec<MediaResponse>,
+    /// ページ情報（total, page, per_page, total_pages, next_cursor）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// 解決に使った言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+// ============================================
+// リクエスト用の構造体
+// ============================================
+
+/// メディア一覧の絞り込み条件
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct MediaFilterQuery {
+    /// MIMEタイプの前方一致（例: image/ → 画像のみ、application/pdf）
+    #[serde(rename = "type")]
+    #[param(rename = "type", example = "image/")]
+    pub file_type: Option<String>,
+}
+
+/// 翻訳の入力
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MediaTranslationRequest {
+    /// 言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// 代替テキスト（255文字以内）
+    #[schema(example = "Rustのロゴ")]
+    pub alt_text: Option<String>,
+    /// キャプション
+    pub caption: Option<String>,
+}
+
+/// アップロードのフォーム（multipart/form-data）
+#[derive(Debug, ToSchema)]
+#[allow(dead_code)]
+pub struct UploadMediaForm {
+    /// ファイル本体（PNG / JPEG / GIF / WebP / PDF）
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+    /// 翻訳（MediaTranslationRequest の配列をJSON文字列で）
+    #[schema(example = r#"[{"locale":"ja","alt_text":"Rustのロゴ"}]"#)]
+    pub translations: Option<String>,
+}
+
+/// メディア更新リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMediaRequest {
+    /// 追加・更新する翻訳（含まれない言語はそのまま）
+    pub translations: Vec<MediaTranslationRequest>,
+}
+
+// ============================================
+// 共通処理
+// ============================================
+
+/// ファイルの中身から判定した情報
+#[derive(Debug, PartialEq, Eq)]
+struct FileInfo {
+    mime_type: &'static str,
+    extension: &'static str,
+    dimensions: Option<(i32, i32)>,
+}
+
+// --------------------------------------------------------
+// inspect_file: ファイル形式の判定と、画像の幅・高さの取得
+// --------------------------------------------------------
+//
+// 💡 infer: 先頭のマジックナンバー（PNGなら \x89PNG）で形式を判定する
+// 💡 imagesize: ヘッダーだけ読んで幅・高さを取り出す（壊れた画像は 400）
+fn inspect_file(bytes: &[u8]) -> AppResult<FileInfo> {
+    let detected = infer::get(bytes).map(|kind| kind.mime_type());
+    let Some(&(mime_type, extension)) = ALLOWED_TYPES
+        .iter()
+        .find(|(allowed, _)| Some(*allowed) == detected)
+    else {
+        let allowed: Vec<&str> = ALLOWED_TYPES.iter().map(|(mime, _)| *mime).collect();
+        return Err(AppError::UnsupportedMediaType(format!(
+            "Unsupported file type '{}' (allowed: {})",
+            detected.unwrap_or("unknown"),
+            allowed.join(", ")
+        )));
+    };
+
+    let dimensions = if mime_type.starts_with("image/") {
+        let size = imagesize::blob_size(bytes)
+            .map_err(|_| AppError::BadRequest("Image data is corrupted".to_string()))?;
+        let width = i32::try_from(size.width).ok().filter(|w| *w > 0);
+        let height = i32::try_from(size.height).ok().filter(|h| *h > 0);
+        match (width, height) {
+            (Some(width), Some(height)) => Some((width, height)),
+            _ => return Err(AppError::BadRequest("Image dimensions are invalid".to_string())),
+        }
+    } else {
+        None
+    };
+
+    Ok(FileInfo {
+        mime_type,
+        extension,
+        dimensions,
+    })
+}
+
+// --------------------------------------------------------
+// clean_filename: 元のファイル名を記録用に整える
+// --------------------------------------------------------
+//
+// 💡 ブラウザによってはフルパス（C:\Users\...\a.png）が送られるため、最後の部分だけ使う
+// 💡 保存するファイル名には使わない（ストレージのキーは UUID）
+fn clean_filename(name: Option<&str>, fallback: &str) -> String {
+    let name = name
+        .and_then(|name| name.rsplit(['/', '\\']).next())
+        .map(|name| name.chars().filter(|c| !c.is_control()).collect::<String>())
+        .map(|name| name.trim().to_string())
+        .unwrap_or_default();
+
+    if name.is_empty() {
+        fallback.to_string()
+    } else {
+        name.chars().take(MAX_FILENAME_LEN).collect()
+    }
+}
+
+// --------------------------------------------------------
+// read_field: フィールドを上限つきで読む
+// --------------------------------------------------------
+//
+// 💡 一度に全部読まず、チャンクごとに合計サイズを確認する
+// - 上限を超えた時点で読むのをやめる（巨大なファイルをメモリに載せない）
+async fn read_field(field: &mut Field<'_>, limit: u64) -> AppResult<Vec<u8>> {
+    let name = field.name().unwrap_or("field").to_string();
+    let mut bytes = Vec::new();
+
+    while let Some(chunk) = field.chunk().await? {
+        if (bytes.len() + chunk.len()) as u64 > limit {
+            return Err(AppError::PayloadTooLarge(format!(
+                "'{}' must be at most {} bytes",
+                name, limit
+            )));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Ok(bytes)
+}
+
+// --------------------------------------------------------
+// resolve_translations: 言語コード → locale_id に変換して検証
+// --------------------------------------------------------
+//
+// 💡 空文字は NULL として保存する（翻訳の行は残る）
+async fn resolve_translations(
+    pool: &PgPool,
+    translations: &[MediaTranslationRequest],
+) -> AppResult<Vec<MediaTranslationInput>> {
+    let repo = LocaleRepository::new(pool.clone());
+    let mut seen = HashSet::new();
+    let mut inputs = Vec::with_capacity(translations.len());
+
+    for translation in translations {
+        if !seen.insert(translation.locale.as_str()) {
+            return Err(AppError::BadRequest(format!(
+                "Duplicate translation for locale '{}'",
+                translation.locale
+            )));
+        }
+
+        let alt_text = non_empty(translation.alt_text.as_deref());
+        if alt_text.as_ref().is_some_and(|alt| alt.chars().count() > MAX_ALT_TEXT_LEN) {
+            return Err(AppError::BadRequest(format!(
+                "alt_text for locale '{}' must be at most {} characters",
+                translation.locale, MAX_ALT_TEXT_LEN
+            )));
+        }
+
+        let locale = repo.find_by_code(&translation.locale).await?.ok_or_else(|| {
+            AppError::BadRequest(format!("Unknown locale '{}'", translation.locale))
+        })?;
+
+        inputs.push(MediaTranslationInput {
+            locale_id: locale.locale_id,
+            alt_text,
+            caption: non_empty(translation.caption.as_deref()),
+        });
+    }
+
+    Ok(inputs)
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value.map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+}
+
+fn media_not_found(media_id: i32) -> AppError {
+    AppError::NotFound(format!("Media {} not found", media_id))
+}
+
+// --------------------------------------------------------
+// describe_references: 409 のメッセージ用（例: featured_image of post 'a'）
+// --------------------------------------------------------
+fn describe_references(references: &[MediaReference]) -> String {
+    references
+        .iter()
+        .map(|reference| {
+            let (usage, owner) = match reference.usage {
+                MediaUsage::FeaturedImage => ("featured image", "post"),
+                MediaUsage::MetaImage => ("meta image", "post"),
+                MediaUsage::PostContent => ("content", "post"),
+                MediaUsage::BookTopImage => ("top image", "book"),
+            };
+            format!("{} of {} '{}'", usage, owner, reference.slug)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// --------------------------------------------------------
+// load_detail: 言語解決済みメディア + 全翻訳 + 使われている場所
+// --------------------------------------------------------
+async fn load_detail(
+    repo: &MediaRepository,
+    storage: &SharedStorage,
+    media_id: i32,
+    requested: &str,
+    fallback: &str,
+) -> AppResult<MediaDetailResponse> {
+    let media = repo
+        .find_by_id(media_id)
+        .await?
+        .ok_or_else(|| media_not_found(media_id))?;
+    let references = repo.find_references(&media).await?;
+
+    let localized = repo
+        .find_by_id_localized(media_id, requested, fallback)
+        .await?
+        .ok_or_else(|| media_not_found(media_id))?;
+
+    let translations = repo
+        .find_translations(media_id)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(MediaDetailResponse {
+        media: MediaResponse::from_localized(localized, requested, storage),
+        translations,
+        references: references.into_iter().map(Into::into).collect(),
+    })
+}
+
+// ============================================
+// Handler関数
+// ============================================
+
+// --------------------------------------------------------
+// list_media: メディア一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/media?type=image/&lang=en&sort=created_at
+#[utoipa::path(
+    get,
+    path = "/api/v1/media",
+    tag = "media",
+    summary = "メディア一覧取得",
+    description = "アップロードしたメディアの一覧を取得します。代替テキスト・キャプションは指定した言語で返します。\
+                   sort には created_at（デフォルト、新しい順）/ file_size / original_filename を指定できます",
+    params(LangQuery, MediaFilterQuery, ListQuery),
+    responses(
+        (status = 200, description = "メディア一覧（Link ヘッダーに前後のページのURL）", body = MediaListResponse),
+        (status = 400, description = "ページ・並び替え・type の指定が不正です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn list_media(
+    State(pool): State<PgPool>,
+    State(storage): State<SharedStorage>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<MediaFilterQuery>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<MediaListResponse>)> {
+    info!("🖼️ Fetching media (locale: {})...", locale.code);
+
+    if let Some(file_type) = &query.file_type {
+        let valid = !file_type.is_empty()
+            && file_type.len() <= 100
+            && file_type
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "/.+-".contains(c));
+        if !valid {
+            return Err(AppError::BadRequest(format!("Invalid type '{}'", file_type)));
+        }
+    }
+
+    let params = list.resolve::<MediaSort>()?;
+    let filter = MediaFilter {
+        file_type: query.file_type,
+    };
+
+    let repo = MediaRepository::new(pool);
+    let page = repo
+        .find_page_localized(&locale.code, &locale.default_code, &filter, &params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (media, pagination) =
+        page.into_parts(|media| MediaResponse::from_localized(media, &locale.code, &storage));
+    for item in &media {
+        locale.served(item.locale.as_deref());
+    }
+
+    info!("✅ Successfully fetched {} of {} media", media.len(), pagination.total);
+
+    Ok((
+        headers,
+        Json(MediaListResponse {
+            media,
+            pagination,
+            locale: locale.code,
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// get_media: メディア詳細
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/media/{id}?lang=en
+#[utoipa::path(
+    get,
+    path = "/api/v1/media/{id}",
+    tag = "media",
+    summary = "メディア詳細取得",
+    description = "メディアと全翻訳、このメディアを使っている記事・本を取得します",
+    params(
+        ("id" = i32, Path, description = "メディアID"),
+        LangQuery
+    ),
+    responses(
+        (status = 200, description = "メディア詳細", body = MediaDetailResponse),
+        (status = 404, description = "メディアが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn get_media(
+    State(pool): State<PgPool>,
+    State(storage): State<SharedStorage>,
+    AppPath(media_id): AppPath<i32>,
+    locale: ResolvedLocale,
+) -> AppResult<Json<MediaDetailResponse>> {
+    info!("🖼️ Fetching media: {} (locale: {})", media_id, locale.code);
+
+    let repo = MediaRepository::new(pool);
+    let detail = load_detail(&repo, &storage, media_id, &locale.code, &locale.default_code).await?;
+    locale.served(detail.media.locale.as_deref());
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// upload_media: メディアのアップロード
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/media（multipart/form-data）
+//   curl -F file=@logo.png -F 'translations=[{"locale":"ja","alt_text":"ロゴ"}]' ...
+//
+// 💡 このルートは axum のボディ上限（DefaultBodyLimit、2MB）を外し、
+//    read_field で media.max_upload_bytes まで読む（routes/mod.rs）
+#[utoipa::path(
+    post,
+    path = "/api/v1/media",
+    tag = "media",
+    summary = "メディアのアップロード",
+    description = "ファイルをアップロードします。形式はファイルの中身から判定し、PNG / JPEG / GIF / WebP / PDF のみ受け付けます。\
+                   画像は幅・高さを記録します",
+    request_body(content = UploadMediaForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "アップロードしたメディア", body = MediaDetailResponse),
+        (status = 400, description = "入力が不正です（file がない、画像が壊れているなど）", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "APIキーに write スコープがありません", body = ErrorResponse),
+        (status = 413, description = "ファイルが大きすぎます", body = ErrorResponse),
+        (status = 415, description = "受け付けないファイル形式です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn upload_media(
+    State(pool): State<PgPool>,
+    State(storage): State<SharedStorage>,
+    State(config): State<MediaConfig>,
+    actor: Authorized<AnyRole>,
+    client: ClientInfo,
+    locale: ResolvedLocale,
+    AppMultipart(mut multipart): AppMultipart,
+) -> AppResult<(StatusCode, Json<MediaDetailResponse>)> {
+    info!("🖼️ Uploading media (by {})", actor.name());
+
+    let mut file: Option<(Option<String>, Vec<u8>)> = None;
+    let mut translations: Vec<MediaTranslationRequest> = Vec::new();
+
+    while let Some(mut field) = multipart.next_field().await? {
+        match field.name() {
+            Some("file") if file.is_none() => {
+                let name = field.file_name().map(str::to_string);
+                let bytes = read_field(&mut field, config.max_upload_bytes).await?;
+                file = Some((name, bytes));
+            }
+            Some("translations") => {
+                let bytes = read_field(&mut field, MAX_TRANSLATIONS_BYTES).await?;
+                translations = serde_json::from_slice(&bytes).map_err(|e| {
+                    AppError::BadRequest(format!("Invalid translations JSON: {}", e))
+                })?;
+            }
+            Some("file") => {
+                return Err(AppError::BadRequest("Only one file can be uploaded at a time".to_string()));
+            }
+            other => {
+                return Err(AppError::BadRequest(format!(
+                    "Unexpected form field '{}'",
+                    other.unwrap_or("")
+                )));
+            }
+        }
+    }
+
+    let Some((original_name, bytes)) = file else {
+        return Err(AppError::BadRequest("The 'file' field is required".to_string()));
+    };
+    if bytes.is_empty() {
+        return Err(AppError::BadRequest("The uploaded file is empty".to_string()));
+    }
+
+    let info = inspect_file(&bytes)?;
+    let translations = resolve_translations(&pool, &translations).await?;
+
+    let key = storage::new_key(Utc::now(), info.extension);
+    let filename = key.rsplit('/').next().unwrap_or(&key).to_string();
+    let original_filename = clean_filename(original_name.as_deref(), &filename);
+
+    storage.put(&key, &bytes, info.mime_type).await?;
+
+    let repo = MediaRepository::new(pool);
+    let created = repo
+        .create(
+            &NewMedia {
+                filename,
+                original_filename,
+                file_path: key.clone(),
+                file_type: info.mime_type.to_string(),
+                file_size: bytes.len() as i64,
+                width: info.dimensions.map(|(width, _)| width),
+                height: info.dimensions.map(|(_, height)| height),
+                uploaded_by: actor.admin_id(),
+            },
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await;
+
+    // 💡 記録に失敗したら、保存したファイルを消してから返す（どこからも使われないファイルを残さない）
+    let media = match created {
+        Ok(media) => media,
+        Err(e) => {
+            if let Err(cleanup) = storage.delete(&key).await {
+                warn!("⚠️ Failed to remove orphaned media file {}: {:#}", key, cleanup);
+            }
+            return Err(e.into());
+        }
+    };
+
+    info!(
+        "✅ Uploaded media: {} ({}, {} bytes, id: {})",
+        media.file_path, media.file_type, media.file_size, media.media_id
+    );
+
+    let detail = load_detail(&repo, &storage, media.media_id, &locale.code, &locale.default_code).await?;
+    locale.served(detail.media.locale.as_deref());
+
+    Ok((StatusCode::CREATED, Json(detail)))
+}
+
+// --------------------------------------------------------
+// update_media: 代替テキスト・キャプションの更新
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: PATCH /api/v1/media/{id}
+//
+// 💡 author は自分がアップロードしたメディアだけ更新できる
+#[utoipa::path(
+    patch,
+    path = "/api/v1/media/{id}",
+    tag = "media",
+    summary = "メディア更新",
+    description = "指定した言語の代替テキスト・キャプションを追加・更新します。author は自分がアップロードしたメディアのみ更新できます",
+    params(
+        ("id" = i32, Path, description = "メディアID")
+    ),
+    request_body = UpdateMediaRequest,
+    responses(
+        (status = 200, description = "更新後のメディア", body = MediaDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "author は自分がアップロードしたメディア以外を更新できません", body = ErrorResponse),
+        (status = 404, description = "メディアが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn update_media(
+    State(pool): State<PgPool>,
+    State(storage): State<SharedStorage>,
+    actor: Authorized<AnyRole>,
+    client: ClientInfo,
+    AppPath(media_id): AppPath<i32>,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<UpdateMediaRequest>,
+) -> AppResult<Json<MediaDetailResponse>> {
+    info!("🖼️ Updating media: {} (by {})", media_id, actor.name());
+
+    let translations = resolve_translations(&pool, &payload.translations).await?;
+
+    let repo = MediaRepository::new(pool);
+    let current = repo
+        .find_by_id(media_id)
+        .await?
+        .ok_or_else(|| media_not_found(media_id))?;
+    ensure_can_edit_media(&actor, &current)?;
+
+    if !repo
+        .update_translations(media_id, &translations, &actor.audit_context(&client))
+        .await?
+    {
+        return Err(media_not_found(media_id));
+    }
+
+    info!("✅ Updated media: {}", media_id);
+
+    let detail = load_detail(&repo, &storage, media_id, &locale.code, &locale.default_code).await?;
+    locale.served(detail.media.locale.as_deref());
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// delete_media: メディアの削除
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: DELETE /api/v1/media/{id}
+//
+// 💡 ファイルの削除に失敗しても 204 を返す
+// - 行はもう消えているので、残ったファイルはどこからも参照されない（ログに残して手動で消す）
+#[utoipa::path(
+    delete,
+    path = "/api/v1/media/{id}",
+    tag = "media",
+    summary = "メディア削除",
+    description = "メディアとファイル本体を削除します。記事のアイキャッチ・OGP画像・本文、本のトップ画像で使われている場合は削除できません",
+    params(
+        ("id" = i32, Path, description = "メディアID")
+    ),
+    responses(
+        (status = 204, description = "削除しました"),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "メディアが見つかりません", body = ErrorResponse),
+        (status = 409, description = "記事・本で使われています", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["delete"])
+    )
+)]
+pub async fn delete_media(
+    State(pool): State<PgPool>,
+    State(storage): State<SharedStorage>,
+    actor: Authorized<EditorOrAdminDelete>,
+    client: ClientInfo,
+    AppPath(media_id): AppPath<i32>,
+) -> AppResult<StatusCode> {
+    info!("🖼️ Deleting media: {} (by {})", media_id, actor.name());
+
+    let repo = MediaRepository::new(pool);
+    let media = match repo.delete(media_id, &actor.audit_context(&client)).await? {
+        MediaDelete::Deleted(media) => media,
+        MediaDelete::NotFound => return Err(media_not_found(media_id)),
+        MediaDelete::Referenced(references) => {
+            return Err(AppError::Conflict(format!(
+                "Media {} is still in use: {}",
+                media_id,
+                describe_references(&references)
+            )));
+        }
+    };
+
+    if let Err(e) = storage.delete(&media.file_path).await {
+        warn!("⚠️ Deleted media {} but failed to remove {}: {:#}", media_id, media.file_path, e);
+    }
+
+    info!("✅ Deleted media: {} ({})", media_id, media.file_path);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// --------------------------------------------------------
+// serve_media: ファイル本体の配信
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /media/{*key}（media.public_base_url のデフォルト /media と対応）
+//
+// 💡 ヘッダー:
+// - Content-Type: アップロード時に判定した形式（ファイル名からは決めない）
+// - X-Content-Type-Options: nosniff（ブラウザに別の形式として解釈させない）
+// - Cache-Control: immutable（キーにUUIDを含み、同じキーの中身は変わらないため）
+#[utoipa::path(
+    get,
+    path = "/media/{key}",
+    tag = "media",
+    summary = "メディアファイルの取得",
+    description = "アップロードしたファイル本体を返します（MediaResponse.url のリンク先）",
+    params(
+        ("key" = String, Path, description = "ストレージのキー（例: 2026/10/3f2a....png）")
+    ),
+    responses(
+        (status = 200, description = "ファイル本体", content_type = "application/octet-stream"),
+        (status = 404, description = "ファイルが見つかりません", body = ErrorResponse)
+    )
+)]
+pub async fn serve_media(
+    State(pool): State<PgPool>,
+    State(storage): State<SharedStorage>,
+    AppPath(key): AppPath<String>,
+) -> AppResult<Response> {
+    let not_found = || AppError::NotFound(format!("Media file '{}' not found", key));
+
+    if !storage::is_valid_key(&key) {
+        return Err(not_found());
+    }
+
+    let media = MediaRepository::new(pool)
+        .find_by_file_path(&key)
+        .await?
+        .ok_or_else(not_found)?;
+
+    let Some(bytes) = storage.get(&key).await? else {
+        warn!("⚠️ Media {} is recorded but its file is missing: {}", media.media_id, key);
+        return Err(not_found());
+    };
+
+    Ok((
+        [
+            (CONTENT_TYPE, media.file_type),
+            (X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            (CACHE_CONTROL, "public, max-age=31536000, immutable".to_string()),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 幅 3 × 高さ 2 の PNG（ヘッダー部分のみ）
+    fn png_header() -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR".to_vec();
+        bytes.extend_from_slice(&3u32.to_be_bytes());
+        bytes.extend_from_slice(&2u32.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn test_inspect_file() {
+        let info = inspect_file(&png_header()).unwrap();
+        assert_eq!(info.mime_type, "image/png");
+        assert_eq!(info.extension, "png");
+        assert_eq!(info.dimensions, Some((3, 2)));
+
+        let pdf = inspect_file(b"%PDF-1.7\n...").unwrap();
+        assert_eq!(pdf.mime_type, "application/pdf");
+        assert_eq!(pdf.dimensions, None);
+    }
+
+    #[test]
+    fn test_inspect_file_rejects_unknown_types() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg"><script/></svg>"#;
+        assert!(matches!(inspect_file(svg), Err(AppError::UnsupportedMediaType(_))));
+        assert!(matches!(inspect_file(b"plain text"), Err(AppError::UnsupportedMediaType(_))));
+    }
+
+    #[test]
+    fn test_clean_filename() {
+        assert_eq!(clean_filename(Some("C:\\Users\\me\\logo.png"), "x.png"), "logo.png");
+        assert_eq!(clean_filename(Some("../../etc/passwd"), "x.png"), "passwd");
+        assert_eq!(clean_filename(Some("  "), "x.png"), "x.png");
+        assert_eq!(clean_filename(None, "x.png"), "x.png");
+    }
+}
@@ -1,3 +1,17 @@
+pub mod admins;   // 追加: 管理者API
+pub mod api_keys; // 追加: APIキー管理
+pub mod audit_logs; // 追加: 監査ログ
+pub mod books;    // 追加: 本・チャプターAPI
+pub mod auth;     // 追加: ログイン・ログアウト
+pub mod feeds;    // 追加: RSS / Atom フィード
+pub mod fallback;  // 追加: 404フォールバック
 pub mod health;
 pub mod greeting;
 pub mod locales;  // 追加: 言語情報API
+pub mod media;    // 追加: メディアライブラリ
+pub mod posts;    // 追加: ブログ記事API
+pub mod post_revisions; // 追加: 記事の翻訳の版（差分・復元）
+pub mod sitemap;  // 追加: XML サイトマップ
+pub mod search;   // 追加: 記事の全文検索
+pub mod topics;
+pub mod translation_coverage; // 追加: 翻訳の状況   // 追加: トピックAPI
@@ -0,0 +1,530 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode, Uri},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::{ensure_can_edit_post, AnyRole, AnyRoleRead},
+    diff::{diff_text, TextChanges},
+    entities::{Post, PostRevision},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{AppPath, AppQuery, Authorized, ClientInfo},
+    handlers::posts::{post_not_found, resolve_translations, PostTranslationRequest},
+    pagination::{ListQuery, PageMeta},
+    repositories::{PostChanges, PostRepository, RevisionRepository, RevisionSort},
+};
+
+// ============================================
+// 記事の翻訳の版（変更履歴・差分・復元）
+// ============================================
+//
+// 💡 版は記事の作成・更新で翻訳を保存するたびに自動で追加される
+//    （blog_post_translation_revisions、内容は変更されない）
+//
+// 💡 エンドポイント（{locale} は言語コード）:
+// - GET  /api/v1/posts/{slug}/translations/{locale}/revisions                    版の一覧
+// - GET  /api/v1/posts/{slug}/translations/{locale}/revisions/{revision}         版の内容
+// - GET  /api/v1/posts/{slug}/translations/{locale}/revisions/diff?from=1&to=3   2つの版の差分
+// - POST /api/v1/posts/{slug}/translations/{locale}/revisions/{revision}/restore 古い版に戻す
+//
+// 💡 復元は古い版を上書きで戻すのではなく、同じ内容の新しい版を作る（履歴は消えない）
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+/// 版の一覧の1件（本文なし）
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevisionListItem {
+    /// 版の番号（記事 + 言語ごとに1から）
+    #[schema(example = 3)]
+    pub revision_number: i32,
+    /// タイトル
+    #[schema(example = "Rust入門")]
+    pub title: String,
+    /// 本文の文字数
+    #[schema(example = 1204)]
+    pub content_length: usize,
+    /// 古い版から復元した場合、その版の番号
+    pub restored_from: Option<i32>,
+    /// 保存した管理者のID（APIキーの場合はキーを発行した管理者、CLIは null）
+    pub created_by: Option<i32>,
+    /// 保存した管理者のユーザー名
+    #[schema(example = "kohta")]
+    pub created_by_username: Option<String>,
+    /// 保存に使ったAPIキーのID
+    pub api_key_id: Option<i32>,
+    /// 保存日時
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PostRevision> for RevisionListItem {
+    fn from(revision: PostRevision) -> Self {
+        Self {
+            revision_number: revision.revision_number,
+            content_length: revision.content.chars().count(),
+            title: revision.title,
+            restored_from: revision.restored_from,
+            created_by: revision.created_by,
+            created_by_username: revision.created_by_username,
+            api_key_id: revision.api_key_id,
+            created_at: revision.created_at,
+        }
+    }
+}
+
+/// 版の一覧
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevisionsListResponse {
+    /// 版（新しい順）
+    pub revisions: Vec<RevisionListItem>,
+    /// ページ情報（total, page, per_page, total_pages）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// 記事のスラッグ
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+    /// 翻訳の言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+/// 版の内容
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevisionResponse {
+    /// 版の番号
+    #[schema(example = 3)]
+    pub revision_number: i32,
+    /// 翻訳の言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// タイトル
+    #[schema(example = "Rust入門")]
+    pub title: String,
+    /// 要約
+    pub summary: Option<String>,
+    /// SEO用タイトル
+    pub meta_title: Option<String>,
+    /// SEO用説明文
+    pub meta_description: Option<String>,
+    /// 本文（Markdown）
+    pub content: String,
+    /// 古い版から復元した場合、その版の番号
+    pub restored_from: Option<i32>,
+    /// 保存した管理者のID
+    pub created_by: Option<i32>,
+    /// 保存した管理者のユーザー名
+    pub created_by_username: Option<String>,
+    /// 保存に使ったAPIキーのID
+    pub api_key_id: Option<i32>,
+    /// 保存日時
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PostRevision> for RevisionResponse {
+    fn from(revision: PostRevision) -> Self {
+        Self {
+            revision_number: revision.revision_number,
+            locale: revision.locale_code,
+            title: revision.title,
+            summary: revision.summary,
+            meta_title: revision.meta_title,
+            meta_description: revision.meta_description,
+            content: revision.content,
+            restored_from: revision.restored_from,
+            created_by: revision.created_by,
+            created_by_username: revision.created_by_username,
+            api_key_id: revision.api_key_id,
+            created_at: revision.created_at,
+        }
+    }
+}
+
+/// 本文以外の項目の変更
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FieldChange {
+    /// 項目名（title / summary / meta_title / meta_description）
+    #[schema(example = "title")]
+    pub field: String,
+    /// 比較元の値
+    #[schema(example = "Rust入門")]
+    pub from: Option<String>,
+    /// 比較先の値
+    #[schema(example = "Rust入門（改訂版）")]
+    pub to: Option<String>,
+}
+
+/// 2つの版の差分
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RevisionDiffResponse {
+    /// 記事のスラッグ
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+    /// 翻訳の言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// 比較元の版の番号
+    #[schema(example = 1)]
+    pub from: i32,
+    /// 比較先の版の番号
+    #[schema(example = 3)]
+    pub to: i32,
+    /// 値が変わった項目（本文以外）
+    pub fields: Vec<FieldChange>,
+    /// 本文の行単位の差分
+    pub content: TextChanges,
+}
+
+// ============================================
+// リクエスト用の構造体
+// ============================================
+
+/// 差分のクエリパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RevisionDiffQuery {
+    /// 比較元の版の番号
+    #[param(example = 1)]
+    pub from: i32,
+    /// 比較先の版の番号
+    #[param(example = 3)]
+    pub to: i32,
+}
+
+// ============================================
+// 共通処理
+// ============================================
+
+async fn find_post(repo: &PostRepository, slug: &str) -> AppResult<Post> {
+    repo.find_by_slug(slug)
+        .await?
+        .ok_or_else(|| post_not_found(slug))
+}
+
+async fn find_revision(
+    repo: &RevisionRepository,
+    post: &Post,
+    locale: &str,
+    revision_number: i32,
+) -> AppResult<PostRevision> {
+    repo.find(post.post_id, locale, revision_number)
+        .await?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Revision {} of post '{}' ({}) not found",
+                revision_number, post.slug, locale
+            ))
+        })
+}
+
+/// 本文以外で値が変わった項目
+fn field_changes(from: &PostRevision, to: &PostRevision) -> Vec<FieldChange> {
+    let fields = [
+        ("title", Some(&from.title), Some(&to.title)),
+        ("summary", from.summary.as_ref(), to.summary.as_ref()),
+        ("meta_title", from.meta_title.as_ref(), to.meta_title.as_ref()),
+        (
+            "meta_description",
+            from.meta_description.as_ref(),
+            to.meta_description.as_ref(),
+        ),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| FieldChange {
+            field: field.to_string(),
+            from: old.cloned(),
+            to: new.cloned(),
+        })
+        .collect()
+}
+
+// ============================================
+// Handler関数
+// ============================================
+
+// --------------------------------------------------------
+// list_revisions: 版の一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/posts/{slug}/translations/{locale}/revisions
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts/{slug}/translations/{locale}/revisions",
+    tag = "posts",
+    summary = "翻訳の版の一覧",
+    description = "記事の翻訳を保存するたびに作られる版を、新しい順に返します（本文は含みません）",
+    params(
+        ("slug" = String, Path, description = "記事のスラッグ（例: getting-started-with-rust）"),
+        ("locale" = String, Path, description = "翻訳の言語コード（例: ja）"),
+        ListQuery
+    ),
+    responses(
+        (status = 200, description = "版の一覧（Link ヘッダーに前後のページのURL）", body = RevisionsListResponse),
+        (status = 400, description = "ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 404, description = "記事が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn list_revisions(
+    State(pool): State<PgPool>,
+    _actor: Authorized<AnyRoleRead>,
+    AppPath((slug, locale)): AppPath<(String, String)>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<RevisionsListResponse>)> {
+    info!("🕘 Fetching revisions: {} ({})", slug, locale);
+
+    let params = list.resolve::<RevisionSort>()?;
+    let post = find_post(&PostRepository::new(pool.clone()), &slug).await?;
+
+    let page = RevisionRepository::new(pool)
+        .find_page(post.post_id, &locale, &params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (revisions, pagination) = page.into_parts(Into::into);
+
+    Ok((
+        headers,
+        Json(RevisionsListResponse {
+            revisions,
+            pagination,
+            slug: post.slug,
+            locale,
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// get_revision: 版の内容
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/posts/{slug}/translations/{locale}/revisions/{revision}
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts/{slug}/translations/{locale}/revisions/{revision}",
+    tag = "posts",
+    summary = "翻訳の版の内容",
+    description = "指定した版のタイトル・要約・本文などを返します",
+    params(
+        ("slug" = String, Path, description = "記事のスラッグ（例: getting-started-with-rust）"),
+        ("locale" = String, Path, description = "翻訳の言語コード（例: ja）"),
+        ("revision" = i32, Path, description = "版の番号（例: 3）")
+    ),
+    responses(
+        (status = 200, description = "版の内容", body = RevisionResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 404, description = "記事・版が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn get_revision(
+    State(pool): State<PgPool>,
+    _actor: Authorized<AnyRoleRead>,
+    AppPath((slug, locale, revision_number)): AppPath<(String, String, i32)>,
+) -> AppResult<Json<RevisionResponse>> {
+    info!("🕘 Fetching revision {}: {} ({})", revision_number, slug, locale);
+
+    let post = find_post(&PostRepository::new(pool.clone()), &slug).await?;
+    let revision =
+        find_revision(&RevisionRepository::new(pool), &post, &locale, revision_number).await?;
+
+    Ok(Json(revision.into()))
+}
+
+// --------------------------------------------------------
+// diff_revisions: 2つの版の差分
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/posts/{slug}/translations/{locale}/revisions/diff?from=1&to=3
+//
+// 💡 from > to も指定できる（新しい版から古い版への差分 = 復元したときに変わる内容）
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts/{slug}/translations/{locale}/revisions/diff",
+    tag = "posts",
+    summary = "翻訳の版の差分",
+    description = "2つの版を比べ、値が変わった項目と本文の行単位の差分（unified 形式と行ごとの一覧）を返します",
+    params(
+        ("slug" = String, Path, description = "記事のスラッグ（例: getting-started-with-rust）"),
+        ("locale" = String, Path, description = "翻訳の言語コード（例: ja）"),
+        RevisionDiffQuery
+    ),
+    responses(
+        (status = 200, description = "差分", body = RevisionDiffResponse),
+        (status = 400, description = "from / to の指定が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 404, description = "記事・版が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn diff_revisions(
+    State(pool): State<PgPool>,
+    _actor: Authorized<AnyRoleRead>,
+    AppPath((slug, locale)): AppPath<(String, String)>,
+    AppQuery(query): AppQuery<RevisionDiffQuery>,
+) -> AppResult<Json<RevisionDiffResponse>> {
+    info!("🕘 Diffing revisions {}..{}: {} ({})", query.from, query.to, slug, locale);
+
+    if query.from == query.to {
+        return Err(AppError::BadRequest("from and to must be different revisions".to_string()));
+    }
+
+    let post = find_post(&PostRepository::new(pool.clone()), &slug).await?;
+    let repo = RevisionRepository::new(pool);
+    let from = find_revision(&repo, &post, &locale, query.from).await?;
+    let to = find_revision(&repo, &post, &locale, query.to).await?;
+
+    let content = diff_text(
+        &from.content,
+        &to.content,
+        &format!("revision {}", from.revision_number),
+        &format!("revision {}", to.revision_number),
+    );
+
+    Ok(Json(RevisionDiffResponse {
+        slug: post.slug,
+        fields: field_changes(&from, &to),
+        content,
+        from: from.revision_number,
+        to: to.revision_number,
+        locale,
+    }))
+}
+
+// --------------------------------------------------------
+// restore_revision: 古い版に戻す
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/posts/{slug}/translations/{locale}/revisions/{revision}/restore
+//
+// 💡 記事の更新（PATCH /api/v1/posts/{slug}）で翻訳を保存するのと同じ処理
+// - 読了目安・抜粋・検索用の search_vector も作り直す
+// - 監査ログにも記録される
+// - 新しい版の restored_from に元の版の番号が入る
+//
+// 💡 author は自分が作成した下書きだけ戻せる（記事の更新と同じ）
+#[utoipa::path(
+    post,
+    path = "/api/v1/posts/{slug}/translations/{locale}/revisions/{revision}/restore",
+    tag = "posts",
+    summary = "翻訳を古い版に戻す",
+    description = "指定した版の内容で翻訳を保存し、新しい版として記録します（それまでの版は残ります）。\
+                   author は自分が作成した下書き（予約済みを除く）のみ戻せます",
+    params(
+        ("slug" = String, Path, description = "記事のスラッグ（例: getting-started-with-rust）"),
+        ("locale" = String, Path, description = "翻訳の言語コード（例: ja）"),
+        ("revision" = i32, Path, description = "戻したい版の番号（例: 2）")
+    ),
+    responses(
+        (status = 201, description = "復元して作られた新しい版", body = RevisionResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "author は自分の下書き以外を更新できません", body = ErrorResponse),
+        (status = 404, description = "記事・版が見つかりません", body = ErrorResponse),
+        (status = 409, description = "今の翻訳と同じ内容です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn restore_revision(
+    State(pool): State<PgPool>,
+    actor: Authorized<AnyRole>,
+    client: ClientInfo,
+    AppPath((slug, locale, revision_number)): AppPath<(String, String, i32)>,
+) -> AppResult<(StatusCode, Json<RevisionResponse>)> {
+    info!("🕘 Restoring revision {}: {} ({}) (by {})", revision_number, slug, locale, actor.name());
+
+    let post_repo = PostRepository::new(pool.clone());
+    let post = find_post(&post_repo, &slug).await?;
+    ensure_can_edit_post(&actor, &post)?;
+
+    let revision_repo = RevisionRepository::new(pool.clone());
+    let revision = find_revision(&revision_repo, &post, &locale, revision_number).await?;
+
+    let current = post_repo
+        .find_translations(post.post_id)
+        .await?
+        .into_iter()
+        .find(|translation| translation.locale_code == locale);
+    let unchanged = current.is_some_and(|current| {
+        current.title == revision.title
+            && current.summary == revision.summary
+            && current.meta_title == revision.meta_title
+            && current.meta_description == revision.meta_description
+            && current.content == revision.content
+    });
+    if unchanged {
+        return Err(AppError::Conflict(format!(
+            "The current translation already matches revision {}",
+            revision_number
+        )));
+    }
+
+    let request = PostTranslationRequest {
+        locale: locale.clone(),
+        title: revision.title,
+        summary: revision.summary,
+        meta_title: revision.meta_title,
+        meta_description: revision.meta_description,
+        content: revision.content,
+    };
+    let mut translations = resolve_translations(&pool, std::slice::from_ref(&request)).await?;
+    for translation in &mut translations {
+        translation.restored_from = Some(revision_number);
+    }
+
+    post_repo
+        .update(
+            post.post_id,
+            &PostChanges {
+                last_updated_by: actor.admin_id(),
+                ..PostChanges::default()
+            },
+            &translations,
+            None,
+            &actor.audit_context(&client),
+        )
+        .await?
+        .ok_or_else(|| post_not_found(&slug))?;
+
+    // 💡 復元して作られた版 = 最新の版
+    let latest = revision_repo
+        .find_latest(post.post_id, &locale)
+        .await?
+        .ok_or_else(|| post_not_found(&slug))?;
+
+    info!(
+        "✅ Restored {} ({}) to revision {} as revision {}",
+        slug, locale, revision_number, latest.revision_number
+    );
+
+    Ok((StatusCode::CREATED, Json(latest.into())))
+}
@@ -0,0 +1,1049 @@
+use std::collections::HashSet;
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode, Uri},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::{ensure_can_edit_post, ensure_can_publish, AnyRole, EditorOrAdmin},
+    entities::{LocalizedPost, PostSummary, ScheduledPost},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{
+        require_login_for_unpublished, Actor, AppJson, AppPath, AppQuery, Authorized, ClientInfo,
+        nullable, LangQuery, ResolvedLocale,
+    },
+    markdown::{content_metrics, plain_text, MarkdownRenderer, RenderedMarkdown, TocEntry},
+    pagination::{ListQuery, PageMeta},
+    repositories::{
+        BookLink, BookRepository, LocaleRepository, MediaRepository, NewPost, PostChanges, PostFilter,
+        PostRepository, PostSort, PostTranslationInput, ScheduledPostSort, TopicRepository,
+    },
+    search::SearchDocument,
+    validation::{is_valid_slug, MAX_POST_SLUG_LEN},
+};
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+// --------------------------------------------------------
+// PostListItem: 一覧用の記事（本文なし）
+// --------------------------------------------------------
+//
+// 💡 locale / is_fallback:
+// - locale: 実際に使われた翻訳の言語コード
+// - is_fallback: 要求した言語の翻訳がなく、原文言語やデフォルト言語で代用した場合 true
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PostListItem {
+    /// 記事ID
+    #[schema(example = 1)]
+    pub post_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+    /// タイトル（翻訳がない場合はnull）
+    #[schema(example = "Rust入門")]
+    pub title: Option<String>,
+    /// 要約
+    pub summary: Option<String>,
+    /// 要約、なければ本文の最初の段落から作った抜粋（一覧のカード表示用）
+    pub excerpt: Option<String>,
+    /// タイトル・要約の言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// 要求した言語以外で代用したか
+    #[schema(example = false)]
+    pub is_fallback: bool,
+    /// OGP画像のURL
+    pub meta_image_url: Option<String>,
+    /// 読了目安（分、翻訳の保存時に本文から計算）
+    pub estimated_reading_time: Option<i32>,
+    /// 閲覧数
+    #[schema(example = 0)]
+    pub views_count: i32,
+    /// 公開フラグ
+    #[schema(example = true)]
+    pub is_published: bool,
+    /// 公開日時
+    pub published_at: Option<DateTime<Utc>>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PostListItem {
+    pub(crate) fn from_summary(post: PostSummary, requested: &str) -> Self {
+        Self {
+            is_fallback: post.is_fallback(requested),
+            post_id: post.post_id,
+            slug: post.slug,
+            title: post.title,
+            summary: post.summary,
+            excerpt: post.excerpt,
+            locale: post.locale_code,
+            meta_image_url: post.meta_image_url,
+            estimated_reading_time: post.estimated_reading_time,
+            views_count: post.views_count,
+            is_published: post.is_published,
+            published_at: post.published_at,
+            updated_at: post.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// PostsListResponse: 記事一覧
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PostsListResponse {
+    /// 記事一覧
+    pub posts: Vec<PostListItem>,
+    /// ページ情報（total, page, per_page, total_pages, next_cursor）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// 解決に使った言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+// --------------------------------------------------------
+// ScheduledPostItem: 予約公開の一覧用
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScheduledPostItem {
+    /// 記事ID
+    #[schema(example = 1)]
+    pub post_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+    /// タイトル（翻訳がない場合はnull）
+    #[schema(example = "Rust入門")]
+    pub title: Option<String>,
+    /// タイトルの言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// 公開予定の日時
+    pub scheduled_at: DateTime<Utc>,
+    /// 作成した管理者のID
+    pub created_by: Option<i32>,
+    /// 最後に更新した管理者のID（予約した人）
+    pub last_updated_by: Option<i32>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ScheduledPost> for ScheduledPostItem {
+    fn from(post: ScheduledPost) -> Self {
+        Self {
+            post_id: post.post_id,
+            slug: post.slug,
+            title: post.title,
+            locale: post.locale_code,
+            scheduled_at: post.scheduled_at,
+            created_by: post.created_by,
+            last_updated_by: post.last_updated_by,
+            updated_at: post.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// ScheduledPostsListResponse: 予約公開の一覧
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScheduledPostsListResponse {
+    /// 予約済みの記事（デフォルトは公開予定の近い順）
+    pub posts: Vec<ScheduledPostItem>,
+    /// ページ情報（total, page, per_page, total_pages）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// タイトルの解決に使った言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+// --------------------------------------------------------
+// PostDetailResponse: 記事詳細（本文あり）
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PostDetailResponse {
+    /// 記事ID
+    #[schema(example = 1)]
+    pub post_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+    /// タイトル
+    #[schema(example = "Rust入門")]
+    pub title: Option<String>,
+    /// 要約
+    pub summary: Option<String>,
+    /// 要約、なければ本文の最初の段落から作った抜粋
+    pub excerpt: Option<String>,
+    /// SEO用タイトル
+    pub meta_title: Option<String>,
+    /// SEO用説明文
+    pub meta_description: Option<String>,
+    /// 本文（Markdown）
+    pub content: Option<String>,
+    /// 本文をHTMLにしたもの（サニタイズ・構文ハイライト・見出しのアンカー付き）
+    #[schema(example = "<h2 id=\"installation\"><a class=\"heading-anchor\" href=\"#installation\" aria-hidden=\"true\">#</a>Installation</h2>")]
+    pub content_html: Option<String>,
+    /// 目次（本文の見出しの入れ子）
+    pub toc: Vec<TocEntry>,
+    /// 本文の言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// 要求した言語以外で代用したか
+    #[schema(example = false)]
+    pub is_fallback: bool,
+    /// OGP画像のURL
+    pub meta_image_url: Option<String>,
+    /// 読了目安（分、翻訳の保存時に本文から計算）
+    pub estimated_reading_time: Option<i32>,
+    /// 所属する本のID
+    pub book_id: Option<i32>,
+    /// 所属するチャプターのID
+    pub chapter_id: Option<i32>,
+    /// アイキャッチ画像のメディアID（GET /api/v1/media/{id} でURL・代替テキストを取得）
+    pub featured_image_id: Option<i32>,
+    /// 閲覧数
+    #[schema(example = 0)]
+    pub views_count: i32,
+    /// 公開フラグ
+    #[schema(example = true)]
+    pub is_published: bool,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 公開日時
+    pub published_at: Option<DateTime<Utc>>,
+    /// 予約公開の日時（この日時を過ぎると自動で公開される）
+    pub scheduled_at: Option<DateTime<Utc>>,
+    /// トピックのslug
+    #[schema(example = json!(["rust"]))]
+    pub topics: Vec<String>,
+    /// 翻訳が存在する言語コード
+    #[schema(example = json!(["ja", "en"]))]
+    pub available_locales: Vec<String>,
+}
+
+impl PostDetailResponse {
+    fn new(
+        post: LocalizedPost,
+        requested: &str,
+        topics: Vec<String>,
+        available_locales: Vec<String>,
+        rendered: Option<&RenderedMarkdown>,
+    ) -> Self {
+        Self {
+            is_fallback: post.is_fallback(requested),
+            post_id: post.post_id,
+            slug: post.slug,
+            title: post.title,
+            summary: post.summary,
+            excerpt: post.excerpt,
+            meta_title: post.meta_title,
+            meta_description: post.meta_description,
+            content: post.content,
+            content_html: rendered.map(|r| r.html.clone()),
+            toc: rendered.map(|r| r.toc.clone()).unwrap_or_default(),
+            locale: post.locale_code,
+            meta_image_url: post.meta_image_url,
+            estimated_reading_time: post.estimated_reading_time,
+            book_id: post.book_id,
+            chapter_id: post.chapter_id,
+            featured_image_id: post.featured_image_id,
+            views_count: post.views_count,
+            is_published: post.is_published,
+            created_at: post.created_at,
+            updated_at: post.updated_at,
+            published_at: post.published_at,
+            scheduled_at: post.scheduled_at,
+            topics,
+            available_locales,
+        }
+    }
+}
+
+// ============================================
+// リクエスト用の構造体
+// ============================================
+
+/// 記事取得のクエリパラメータ（言語は ResolvedLocale が ?lang= から読む）
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PostQuery {
+    /// 非公開の記事も含める（ログイン、または read スコープのAPIキーが必要）
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// 記事一覧の絞り込み条件
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PostFilterQuery {
+    /// トピックのslugで絞り込む
+    #[param(example = "rust")]
+    pub topic: Option<String>,
+}
+
+/// 翻訳の入力
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PostTranslationRequest {
+    /// 言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// タイトル
+    #[schema(example = "Rust入門")]
+    pub title: String,
+    /// 要約（省略すると本文の最初の段落から作った抜粋を excerpt で返す）
+    pub summary: Option<String>,
+    /// SEO用タイトル
+    pub meta_title: Option<String>,
+    /// SEO用説明文
+    pub meta_description: Option<String>,
+    /// 本文（Markdown）
+    #[schema(example = "# はじめに\n\nRustを始めましょう。")]
+    pub content: String,
+}
+
+/// 記事作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreatePostRequest {
+    /// URL用スラッグ
+    #[schema(example = "getting-started-with-rust")]
+    pub slug: String,
+    /// 原文の言語コード（省略時: サイトのデフォルト言語）
+    #[schema(example = "ja")]
+    pub default_locale: Option<String>,
+    /// OGP画像のURL
+    pub meta_image_url: Option<String>,
+    /// 公開フラグ（省略時: false = 下書き）
+    #[serde(default)]
+    pub is_published: bool,
+    /// 予約公開の日時（未来の日時。is_published = true とは一緒に指定できない）
+    #[schema(example = "2026-12-01T09:00:00Z")]
+    pub scheduled_at: Option<DateTime<Utc>>,
+    /// 翻訳（原文の言語の翻訳は必須）
+    pub translations: Vec<PostTranslationRequest>,
+    /// トピックのslug
+    #[serde(default)]
+    #[schema(example = json!(["rust"]))]
+    pub topics: Vec<String>,
+    /// 所属する本のslug
+    #[schema(example = "rust-tutorial")]
+    pub book: Option<String>,
+    /// 所属するチャプターのslug（book と一緒に指定）
+    #[schema(example = "installation")]
+    pub chapter: Option<String>,
+    /// アイキャッチ画像のメディアID
+    pub featured_image_id: Option<i32>,
+}
+
+/// 記事更新リクエスト（部分更新）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdatePostRequest {
+    /// URL用スラッグ
+    pub slug: Option<String>,
+    /// 原文の言語コード（その言語の翻訳がすでにあるか、translations に含める）
+    pub default_locale: Option<String>,
+    /// OGP画像のURL（null で消す、省略時はそのまま）
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>)]
+    pub meta_image_url: Option<Option<String>>,
+    /// 公開フラグ（指定すると予約公開は取り消される）
+    pub is_published: Option<bool>,
+    /// 予約公開の日時（未来の日時。下書きの記事だけ。is_published とは一緒に指定できない）
+    #[schema(example = "2026-12-01T09:00:00Z")]
+    pub scheduled_at: Option<DateTime<Utc>>,
+    /// 追加・更新する翻訳（含まれない言語はそのまま）
+    #[serde(default)]
+    pub translations: Vec<PostTranslationRequest>,
+    /// トピックのslug（指定した場合は置き換え、省略時はそのまま）
+    pub topics: Option<Vec<String>>,
+    /// 所属する本のslug（指定した場合は本・チャプターを置き換え、省略時はそのまま）
+    pub book: Option<String>,
+    /// 所属するチャプターのslug（book と一緒に指定）
+    pub chapter: Option<String>,
+    /// アイキャッチ画像のメディアID（指定した場合は置き換え、null で外す、省略時はそのまま）
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<i32>)]
+    pub featured_image_id: Option<Option<i32>>,
+}
+
+// ============================================
+// 共通処理
+// ============================================
+
+// --------------------------------------------------------
+// validate_schedule: 予約公開の指定を確認
+// --------------------------------------------------------
+//
+// 💡 記事の状態は 下書き → 予約済み → 公開済み の順に進む
+// - 予約は未来の日時だけ（過去の日時ならすぐ公開すればよい）
+// - 「すぐ公開」と「予約」は同時に指定できない
+// - 予約できるのは公開できる人だけ（author は不可）
+fn validate_schedule(
+    actor: &Actor,
+    scheduled_at: Option<DateTime<Utc>>,
+    is_published: Option<bool>,
+) -> AppResult<()> {
+    let Some(scheduled_at) = scheduled_at else {
+        return Ok(());
+    };
+    ensure_can_publish(actor, Some(true))?;
+    if is_published.is_some() {
+        return Err(AppError::BadRequest(
+            "scheduled_at cannot be combined with is_published; omit is_published to schedule a draft"
+                .to_string(),
+        ));
+    }
+    if scheduled_at <= Utc::now() {
+        return Err(AppError::BadRequest("scheduled_at must be in the future".to_string()));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_slug(slug: &str) -> AppResult<()> {
+    if is_valid_slug(slug, MAX_POST_SLUG_LEN) {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!(
+            "Invalid slug '{}' (lowercase letters, digits and single hyphens only)",
+            slug
+        )))
+    }
+}
+
+fn validate_meta_image_url(url: Option<&str>) -> AppResult<()> {
+    match url {
+        Some(url) if url.is_empty() || url.len() > 500 => Err(AppError::BadRequest(
+            "meta_image_url must be 1-500 characters".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn post_not_found(slug: &str) -> AppError {
+    AppError::NotFound(format!("Post '{}' not found", slug))
+}
+
+// --------------------------------------------------------
+// resolve_active_locale_id: 原文の言語コード → locale_id
+// --------------------------------------------------------
+//
+// 💡 無効な言語を原文の言語にはできない（フォールバック先にならないため）
+async fn resolve_active_locale_id(pool: &PgPool, code: &str) -> AppResult<i32> {
+    match LocaleRepository::new(pool.clone()).find_by_code(code).await? {
+        Some(locale) if locale.is_active => Ok(locale.locale_id),
+        Some(_) => Err(AppError::BadRequest(format!("Locale '{}' is not active", code))),
+        None => Err(AppError::BadRequest(format!("Unknown locale '{}'", code))),
+    }
+}
+
+// --------------------------------------------------------
+// resolve_translations: 言語コード → locale_id に変換して検証
+// --------------------------------------------------------
+pub(crate) async fn resolve_translations(
+    pool: &PgPool,
+    translations: &[PostTranslationRequest],
+) -> AppResult<Vec<PostTranslationInput>> {
+    let repo = LocaleRepository::new(pool.clone());
+    let mut seen = HashSet::new();
+    let mut inputs = Vec::with_capacity(translations.len());
+
+    for translation in translations {
+        if !seen.insert(translation.locale.as_str()) {
+            return Err(AppError::BadRequest(format!(
+                "Duplicate translation for locale '{}'",
+                translation.locale
+            )));
+        }
+
+        let title = translation.title.trim();
+        if title.is_empty() || title.chars().count() > 255 {
+            return Err(AppError::BadRequest(format!(
+                "Post title for locale '{}' must be 1-255 characters",
+                translation.locale
+            )));
+        }
+        if translation.content.trim().is_empty() {
+            return Err(AppError::BadRequest(format!(
+                "Post content for locale '{}' must not be empty",
+                translation.locale
+            )));
+        }
+        if translation
+            .meta_title
+            .as_deref()
+            .is_some_and(|meta_title| meta_title.chars().count() > 255)
+        {
+            return Err(AppError::BadRequest(format!(
+                "meta_title for locale '{}' must be at most 255 characters",
+                translation.locale
+            )));
+        }
+
+        let locale = repo.find_by_code(&translation.locale).await?.ok_or_else(|| {
+            AppError::BadRequest(format!("Unknown locale '{}'", translation.locale))
+        })?;
+
+        // 💡 読了目安・抜粋・検索用の search_vector は言語（locales.code）ごとの方法で作る
+        let metrics = content_metrics(&translation.content, &locale.code);
+        let search = SearchDocument::new(
+            &locale.code,
+            title,
+            translation.summary.as_deref(),
+            plain_text(&translation.content),
+        );
+
+        inputs.push(PostTranslationInput {
+            locale_id: locale.locale_id,
+            title: title.to_string(),
+            summary: translation.summary.clone(),
+            meta_title: translation.meta_title.clone(),
+            meta_description: translation.meta_description.clone(),
+            content: translation.content.clone(),
+            reading_time_minutes: metrics.reading_time_minutes,
+            excerpt: metrics.excerpt,
+            search,
+            restored_from: None,
+        });
+    }
+
+    Ok(inputs)
+}
+
+// --------------------------------------------------------
+// resolve_topic_ids: トピックのslug → topic_id
+// --------------------------------------------------------
+async fn resolve_topic_ids(pool: &PgPool, slugs: &[String]) -> AppResult<Vec<i32>> {
+    let repo = TopicRepository::new(pool.clone());
+    let mut topic_ids = Vec::with_capacity(slugs.len());
+
+    for slug in slugs {
+        let topic = repo
+            .find_by_slug(slug)
+            .await?
+            .ok_or_else(|| AppError::BadRequest(format!("Unknown topic '{}'", slug)))?;
+        topic_ids.push(topic.topic_id);
+    }
+
+    Ok(topic_ids)
+}
+
+// --------------------------------------------------------
+// resolve_book_link: 本・チャプターのslug → book_id / chapter_id
+// --------------------------------------------------------
+//
+// 💡 チャプターのslugは本ごとに一意なので、本の指定が必要
+async fn resolve_book_link(
+    pool: &PgPool,
+    book: Option<&str>,
+    chapter: Option<&str>,
+) -> AppResult<Option<BookLink>> {
+    let Some(book_slug) = book else {
+        return match chapter {
+            Some(_) => Err(AppError::BadRequest("chapter requires book".to_string())),
+            None => Ok(None),
+        };
+    };
+
+    let repo = BookRepository::new(pool.clone());
+    let book = repo
+        .find_by_slug(book_slug)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("Unknown book '{}'", book_slug)))?;
+
+    let chapter_id = match chapter {
+        Some(chapter_slug) => {
+            let chapter = repo
+                .find_chapter_by_slug(book.book_id, chapter_slug)
+                .await?
+                .ok_or_else(|| {
+                    AppError::BadRequest(format!(
+                        "Unknown chapter '{}' in book '{}'",
+                        chapter_slug, book_slug
+                    ))
+                })?;
+            Some(chapter.chapter_id)
+        }
+        None => None,
+    };
+
+    Ok(Some(BookLink {
+        book_id: book.book_id,
+        chapter_id,
+    }))
+}
+
+// --------------------------------------------------------
+// ensure_media_exists: アイキャッチ画像のメディアIDを確認
+// --------------------------------------------------------
+//
+// 💡 外部キー違反（409）になる前に、わかりやすい 400 を返す
+async fn ensure_media_exists(pool: &PgPool, media_id: Option<i32>) -> AppResult<()> {
+    let Some(media_id) = media_id else {
+        return Ok(());
+    };
+    MediaRepository::new(pool.clone())
+        .find_by_id(media_id)
+        .await?
+        .ok_or_else(|| AppError::BadRequest(format!("Unknown media {}", media_id)))?;
+    Ok(())
+}
+
+// --------------------------------------------------------
+// load_detail: 言語解決済みの記事 + トピック + 翻訳がある言語 + 本文のHTML
+// --------------------------------------------------------
+async fn load_detail(
+    repo: &PostRepository,
+    markdown: &MarkdownRenderer,
+    slug: &str,
+    requested: &str,
+    fallback: &str,
+) -> AppResult<PostDetailResponse> {
+    let post = repo
+        .find_by_slug_localized(slug, requested, fallback)
+        .await?
+        .ok_or_else(|| post_not_found(slug))?;
+
+    let topics = repo.find_topic_slugs(post.post_id).await?;
+    let available_locales = repo
+        .find_translations(post.post_id)
+        .await?
+        .into_iter()
+        .map(|translation| translation.locale_code)
+        .collect();
+
+    let rendered = match post.content.as_deref() {
+        Some(content) => Some(markdown.render(content).await?),
+        None => None,
+    };
+
+    Ok(PostDetailResponse::new(
+        post,
+        requested,
+        topics,
+        available_locales,
+        rendered.as_deref(),
+    ))
+}
+
+// ============================================
+// Handler関数
+// ============================================
+
+// --------------------------------------------------------
+// list_posts: 記事一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/posts?lang=en&topic=rust
+//
+// 💡 ページ送り:
+// - デフォルト（sort=published_at）はキーセット方式
+//   → レスポンスの next_cursor を ?cursor= に渡すと次のページ
+// - ?page=2 を指定するとオフセット方式（他の sort もオフセット方式）
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts",
+    tag = "posts",
+    summary = "記事一覧取得",
+    description = "公開済みの記事を新しい順に取得します。翻訳がない場合は原文の言語、次にデフォルト言語で返します。\
+                   sort には published_at（デフォルト、cursor 対応）/ created_at / updated_at / views_count / slug を指定できます",
+    params(LangQuery, PostQuery, PostFilterQuery, ListQuery),
+    responses(
+        (status = 200, description = "記事一覧（Link ヘッダーに次のページのURL）", body = PostsListResponse),
+        (status = 400, description = "ページ・並び替え・カーソルの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn list_posts(
+    State(pool): State<PgPool>,
+    actor: Option<Actor>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<PostQuery>,
+    AppQuery(filter): AppQuery<PostFilterQuery>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<PostsListResponse>)> {
+    info!("📝 Fetching posts (locale: {}, from {:?})...", locale.code, locale.source);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let params = list.resolve::<PostSort>()?;
+    let filter = PostFilter {
+        include_unpublished: query.include_unpublished,
+        topic: filter.topic,
+        chapter_id: None,
+    };
+
+    let repo = PostRepository::new(pool);
+    let page = repo
+        .find_page_localized(&locale.code, &locale.default_code, &filter, &params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (posts, pagination) =
+        page.into_parts(|post| PostListItem::from_summary(post, &locale.code));
+    for item in &posts {
+        locale.served(item.locale.as_deref());
+    }
+
+    info!("✅ Successfully fetched {} of {} posts", posts.len(), pagination.total);
+
+    Ok((
+        headers,
+        Json(PostsListResponse {
+            posts,
+            pagination,
+            locale: locale.code,
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// get_post: 記事詳細
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/posts/{slug}?lang=en
+#[utoipa::path(
+    get,
+    path = "/api/v1/posts/{slug}",
+    tag = "posts",
+    summary = "記事詳細取得",
+    description = "slugを指定して記事を本文付きで取得します。本文は Markdown（content）と、サニタイズ済みのHTML（content_html）・目次（toc）で返します",
+    params(
+        ("slug" = String, Path, description = "記事のスラッグ（例: getting-started-with-rust）"),
+        LangQuery,
+        PostQuery
+    ),
+    responses(
+        (status = 200, description = "記事詳細", body = PostDetailResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 404, description = "記事が見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn get_post(
+    State(pool): State<PgPool>,
+    State(markdown): State<MarkdownRenderer>,
+    actor: Option<Actor>,
+    AppPath(slug): AppPath<String>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<PostQuery>,
+) -> AppResult<Json<PostDetailResponse>> {
+    info!("📝 Fetching post: {} (locale: {})", slug, locale.code);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let repo = PostRepository::new(pool);
+
+    // 💡 下書きかどうかは本文を変換する前に確認する（未ログインのリクエストで変換キャッシュを埋めない）
+    if !query.include_unpublished {
+        let post = repo.find_by_slug(&slug).await?;
+        if !post.is_some_and(|post| post.is_published) {
+            return Err(post_not_found(&slug));
+        }
+    }
+
+    let detail = load_detail(&repo, &markdown, &slug, &locale.code, &locale.default_code).await?;
+    locale.served(detail.locale.as_deref());
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// list_scheduled_posts: 予約公開の一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/scheduled-posts
+//
+// 💡 公開予定の日時を過ぎた記事も、scheduler が公開するまではここに残る
+#[utoipa::path(
+    get,
+    path = "/api/v1/scheduled-posts",
+    tag = "posts",
+    summary = "予約公開の一覧",
+    description = "予約公開された（まだ公開されていない）記事を公開予定の近い順に返します。\
+                   sort には scheduled_at（デフォルト）/ updated_at / slug を指定できます",
+    params(LangQuery, ListQuery),
+    responses(
+        (status = 200, description = "予約済みの記事（Link ヘッダーに前後のページのURL）", body = ScheduledPostsListResponse),
+        (status = 400, description = "ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn list_scheduled_posts(
+    State(pool): State<PgPool>,
+    _actor: Authorized<EditorOrAdmin>,
+    locale: ResolvedLocale,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<ScheduledPostsListResponse>)> {
+    info!("⏰ Fetching scheduled posts (locale: {})...", locale.code);
+
+    let params = list.resolve::<ScheduledPostSort>()?;
+    let page = PostRepository::new(pool)
+        .find_scheduled_page(&locale.code, &locale.default_code, &params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (posts, pagination) = page.into_parts(ScheduledPostItem::from);
+    for item in &posts {
+        locale.served(item.locale.as_deref());
+    }
+
+    Ok((
+        headers,
+        Json(ScheduledPostsListResponse {
+            posts,
+            pagination,
+            locale: locale.code,
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// create_post: 記事作成
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/posts
+//
+// 💡 原文の言語の翻訳を必須にする理由:
+// - 他の言語の翻訳がないとき、原文の言語にフォールバックするため
+//
+// 💡 author は下書き（is_published = false）だけ作成できる
+// 💡 scheduled_at を指定すると下書きのまま予約し、その日時に scheduler が公開する
+#[utoipa::path(
+    post,
+    path = "/api/v1/posts",
+    tag = "posts",
+    summary = "記事作成",
+    description = "記事・翻訳・トピックを作成します。原文の言語の翻訳は必須です。\
+                   book / chapter を指定すると本のチャプターに所属させます。\
+                   scheduled_at を指定すると、その日時に自動で公開します（予約公開）。author は下書きのみ作成できます",
+    request_body = CreatePostRequest,
+    responses(
+        (status = 201, description = "作成された記事", body = PostDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "author は公開・予約公開の記事を作成できません", body = ErrorResponse),
+        (status = 409, description = "slugが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn create_post(
+    State(pool): State<PgPool>,
+    State(markdown): State<MarkdownRenderer>,
+    actor: Authorized<AnyRole>,
+    client: ClientInfo,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<CreatePostRequest>,
+) -> AppResult<(StatusCode, Json<PostDetailResponse>)> {
+    info!("📝 Creating post: {} (by {})", payload.slug, actor.name());
+
+    ensure_can_publish(&actor, Some(payload.is_published))?;
+    validate_schedule(&actor, payload.scheduled_at, payload.is_published.then_some(true))?;
+    validate_slug(&payload.slug)?;
+    validate_meta_image_url(payload.meta_image_url.as_deref())?;
+
+    let default_code = payload
+        .default_locale
+        .clone()
+        .unwrap_or_else(|| locale.default_code.clone());
+    let default_locale_id = resolve_active_locale_id(&pool, &default_code).await?;
+
+    if !payload.translations.iter().any(|t| t.locale == default_code) {
+        return Err(AppError::BadRequest(format!(
+            "A translation for the post's default locale '{}' is required",
+            default_code
+        )));
+    }
+
+    let translations = resolve_translations(&pool, &payload.translations).await?;
+    let topic_ids = resolve_topic_ids(&pool, &payload.topics).await?;
+    let book = resolve_book_link(&pool, payload.book.as_deref(), payload.chapter.as_deref()).await?;
+    ensure_media_exists(&pool, payload.featured_image_id).await?;
+
+    let repo = PostRepository::new(pool);
+    let post = repo
+        .create(
+            &NewPost {
+                slug: payload.slug,
+                meta_image_url: payload.meta_image_url,
+                is_published: payload.is_published,
+                published_at: None,
+                scheduled_at: payload.scheduled_at,
+                default_locale_id,
+                book,
+                featured_image_id: payload.featured_image_id,
+                created_by: actor.admin_id(),
+            },
+            &translations,
+            &topic_ids,
+            &actor.audit_context(&client),
+        )
+        .await?;
+
+    info!("✅ Created post: {} (id: {})", post.slug, post.post_id);
+
+    let detail = load_detail(&repo, &markdown, &post.slug, &locale.code, &locale.default_code).await?;
+    locale.served(detail.locale.as_deref());
+
+    Ok((StatusCode::CREATED, Json(detail)))
+}
+
+// --------------------------------------------------------
+// update_post: 記事更新
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: PATCH /api/v1/posts/{slug}
+//
+// 💡 author は自分が作成した下書き（予約済みを除く）だけ更新できる（公開・予約もできない）
+// 💡 予約の取り消し: is_published を指定する（true ですぐ公開、false で下書きに戻す）
+#[utoipa::path(
+    patch,
+    path = "/api/v1/posts/{slug}",
+    tag = "posts",
+    summary = "記事更新",
+    description = "記事の属性を更新し、指定した言語の翻訳を追加・更新します。topics・book を指定した場合は置き換えます。\
+                   scheduled_at で下書きを予約公開し、is_published を指定すると予約を取り消します。\
+                   author は自分が作成した下書き（予約済みを除く）のみ更新でき、公開はできません",
+    params(
+        ("slug" = String, Path, description = "記事のスラッグ（例: getting-started-with-rust）")
+    ),
+    request_body = UpdatePostRequest,
+    responses(
+        (status = 200, description = "更新後の記事", body = PostDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "author は自分の下書き以外を更新できません", body = ErrorResponse),
+        (status = 404, description = "記事が見つかりません", body = ErrorResponse),
+        (status = 409, description = "slugが既に存在します / 公開済みの記事は予約できません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn update_post(
+    State(pool): State<PgPool>,
+    State(markdown): State<MarkdownRenderer>,
+    actor: Authorized<AnyRole>,
+    client: ClientInfo,
+    AppPath(slug): AppPath<String>,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<UpdatePostRequest>,
+) -> AppResult<Json<PostDetailResponse>> {
+    info!("📝 Updating post: {} (by {})", slug, actor.name());
+
+    ensure_can_publish(&actor, payload.is_published)?;
+    validate_schedule(&actor, payload.scheduled_at, payload.is_published)?;
+    if let Some(new_slug) = &payload.slug {
+        validate_slug(new_slug)?;
+    }
+    validate_meta_image_url(payload.meta_image_url.as_ref().and_then(|url| url.as_deref()))?;
+
+    let default_locale_id = match &payload.default_locale {
+        Some(code) => Some(resolve_active_locale_id(&pool, code).await?),
+        None => None,
+    };
+    let translations = resolve_translations(&pool, &payload.translations).await?;
+    let topic_ids = match &payload.topics {
+        Some(slugs) => Some(resolve_topic_ids(&pool, slugs).await?),
+        None => None,
+    };
+    let book = resolve_book_link(&pool, payload.book.as_deref(), payload.chapter.as_deref()).await?;
+    ensure_media_exists(&pool, payload.featured_image_id.flatten()).await?;
+
+    let repo = PostRepository::new(pool);
+    let current = repo
+        .find_by_slug(&slug)
+        .await?
+        .ok_or_else(|| post_not_found(&slug))?;
+    ensure_can_edit_post(&actor, &current)?;
+    if payload.scheduled_at.is_some() && current.is_published {
+        return Err(AppError::Conflict(format!(
+            "Post '{}' is already published; unpublish it before scheduling",
+            slug
+        )));
+    }
+
+    // 💡 原文の言語には翻訳が必要（create_post と同じ。今ある翻訳か、このリクエストで追加する翻訳）
+    if let Some(code) = &payload.default_locale {
+        let has_translation = payload.translations.iter().any(|t| &t.locale == code)
+            || repo
+                .find_translations(current.post_id)
+                .await?
+                .iter()
+                .any(|translation| &translation.locale_code == code);
+        if !has_translation {
+            return Err(AppError::BadRequest(format!(
+                "A translation for the post's default locale '{}' is required",
+                code
+            )));
+        }
+    }
+
+    let post = repo
+        .update(
+            current.post_id,
+            &PostChanges {
+                slug: payload.slug,
+                meta_image_url: payload.meta_image_url,
+                is_published: payload.is_published,
+                scheduled_at: payload.scheduled_at,
+                default_locale_id,
+                book,
+                featured_image_id: payload.featured_image_id,
+                last_updated_by: actor.admin_id(),
+            },
+            &translations,
+            topic_ids.as_deref(),
+            &actor.audit_context(&client),
+        )
+        .await?
+        .ok_or_else(|| post_not_found(&slug))?;
+
+    info!("✅ Updated post: {} (id: {})", post.slug, post.post_id);
+
+    let detail = load_detail(&repo, &markdown, &post.slug, &locale.code, &locale.default_code).await?;
+    locale.served(detail.locale.as_deref());
+
+    Ok(Json(detail))
+}
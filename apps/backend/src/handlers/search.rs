@@ -0,0 +1,165 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, Uri},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    entities::PostSearchHit,
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{AppQuery, LangQuery, ResolvedLocale},
+    markdown,
+    pagination::{ListQuery, PageMeta},
+    repositories::{SearchRepository, SearchSort},
+    search::{self, SearchQuery},
+};
+
+// ============================================
+// 記事の全文検索
+// ============================================
+//
+// 💡 エンドポイント: GET /api/v1/search?q=所有権&lang=ja
+// - 検索するのは解決した言語（?lang= → Cookie → Accept-Language → デフォルト言語）の翻訳だけ
+// - 英語などは websearch の書き方が使える: "borrow checker"（フレーズ）、-unsafe（除外）、rust OR go
+// - 日本語・中国語は2文字ずつで検索する（空白で区切った語はすべて含む記事が一致）
+
+/// 検索語の最大文字数
+const MAX_QUERY_LEN: usize = 200;
+
+/// 検索のクエリパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchParams {
+    /// 検索語（1〜200文字）
+    #[param(example = "所有権")]
+    pub q: String,
+}
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+/// 検索結果の1件
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SearchResultItem {
+    /// 記事ID
+    #[schema(example = 1)]
+    pub post_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "rust-ownership")]
+    pub slug: String,
+    /// タイトル
+    #[schema(example = "Rustの所有権")]
+    pub title: String,
+    /// 要約（なければ本文から作った抜粋）
+    pub excerpt: Option<String>,
+    /// 本文の一致した部分（HTML。一致した語は <mark> で囲む、それ以外はエスケープ済み）
+    #[schema(example = "…Rustの<mark>所有権</mark>は、値を持つ変数が1つだけ…")]
+    pub snippet: String,
+    /// 翻訳の言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// 一致度（大きいほど上位）
+    #[schema(example = 0.12)]
+    pub rank: f32,
+    /// 公開日時
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl SearchResultItem {
+    fn from_hit(hit: PostSearchHit, query: &SearchQuery) -> Self {
+        Self {
+            snippet: search::snippet(&markdown::plain_text(&hit.content), query),
+            post_id: hit.post_id,
+            slug: hit.slug,
+            title: hit.title,
+            excerpt: hit.excerpt,
+            locale: hit.locale_code,
+            rank: hit.rank,
+            published_at: hit.published_at,
+        }
+    }
+}
+
+/// 検索結果
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SearchResponse {
+    /// 一致した記事（sort=relevance では一致度の高い順）
+    pub results: Vec<SearchResultItem>,
+    /// ページ情報（total, page, per_page, total_pages）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// 検索語
+    #[schema(example = "所有権")]
+    pub query: String,
+    /// 検索した言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+// ============================================
+// Handler関数
+// ============================================
+
+// --------------------------------------------------------
+// search_posts: 記事の全文検索
+// --------------------------------------------------------
+#[utoipa::path(
+    get,
+    path = "/api/v1/search",
+    tag = "search",
+    summary = "記事の全文検索",
+    description = "公開済みの記事のタイトル・要約・本文を検索します。検索するのは解決した言語の翻訳だけです。\
+                   英語などは語幹で一致し（running → run）、日本語・中国語は2文字ずつに区切って一致を探します。\
+                   sort には relevance（デフォルト、一致度の高い順）/ published_at を指定できます",
+    params(SearchParams, LangQuery, ListQuery),
+    responses(
+        (status = 200, description = "検索結果（Link ヘッダーに前後のページのURL）", body = SearchResponse),
+        (status = 400, description = "検索語・ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn search_posts(
+    State(pool): State<PgPool>,
+    locale: ResolvedLocale,
+    AppQuery(params): AppQuery<SearchParams>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<SearchResponse>)> {
+    let q = params.q.trim();
+    info!("🔎 Searching posts: {:?} (locale: {})", q, locale.code);
+
+    if q.is_empty() || q.chars().count() > MAX_QUERY_LEN {
+        return Err(AppError::BadRequest(format!(
+            "q must be 1-{} characters",
+            MAX_QUERY_LEN
+        )));
+    }
+    let query = SearchQuery::parse(q, &locale.code).ok_or_else(|| {
+        AppError::BadRequest("q must contain at least one letter or digit".to_string())
+    })?;
+    let list_params = list.resolve::<SearchSort>()?;
+
+    let page = SearchRepository::new(pool)
+        .search_posts(&query, &locale.code, &list_params)
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (results, pagination) = page.into_parts(|hit| SearchResultItem::from_hit(hit, &query));
+
+    info!("✅ Found {} posts", pagination.total);
+
+    Ok((
+        headers,
+        Json(SearchResponse {
+            results,
+            pagination,
+            query: q.to_string(),
+            locale: locale.code,
+        }),
+    ))
+}
@@ -0,0 +1,165 @@
+use axum::{
+    extract::State,
+    http::header::{CACHE_CONTROL, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+};
+use sqlx::PgPool;
+use tracing::info;
+
+use crate::{
+    config::SiteConfig,
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::AppPath,
+    repositories::{sitemap_repository::SitemapEntry, LocaleRepository, SitemapRepository},
+    sitemap::{self, PageTranslation, SitemapPage, SitemapUrl, MAX_URLS_PER_SITEMAP},
+};
+
+// ============================================
+// XML サイトマップ
+// ============================================
+//
+// 💡 エンドポイント:
+// - GET /sitemap.xml          → 50,000 URL 以下ならサイトマップ、超えたらサイトマップインデックス
+// - GET /sitemaps/{n}.xml     → 分割したサイトマップの n 番目（1から）
+//
+// 💡 <loc> は公開サイト（site.public_url）のURL、分割したサイトマップのURLは API（site.api_url）
+// - 公開サイトと別のホストから配信する場合は、公開サイトの robots.txt に
+//   `Sitemap: {SITE_API_URL}/sitemap.xml` を書く（sitemaps.org のクロスサブミット）
+//
+// 💡 静的ページ（/about など）はフロントエンドの app/sitemap.ts が出す
+
+/// サイトマップのキャッシュ時間（秒）
+const SITEMAP_MAX_AGE_SECS: u32 = 3600;
+
+const XML_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+// --------------------------------------------------------
+// get_sitemap: サイトマップ（またはサイトマップインデックス）
+// --------------------------------------------------------
+#[utoipa::path(
+    get,
+    path = "/sitemap.xml",
+    tag = "feeds",
+    summary = "XML サイトマップ",
+    description = "公開済みの記事・本を、有効な言語の翻訳ごとに1つのURLとして返します。\
+                   各URLには同じページの全翻訳の hreflang と、デフォルト言語の x-default を付けます。\
+                   50,000 URL を超える場合はサイトマップインデックス（/sitemaps/{n}.xml を参照）を返します",
+    responses(
+        (status = 200, description = "サイトマップ（urlset）またはサイトマップインデックス", content_type = "application/xml"),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn get_sitemap(
+    State(pool): State<PgPool>,
+    State(site): State<SiteConfig>,
+) -> AppResult<Response> {
+    info!("🗺️ Building sitemap");
+
+    let urls = load_urls(pool, &site).await?;
+
+    let xml = if urls.len() <= MAX_URLS_PER_SITEMAP {
+        sitemap::urlset(&urls)
+    } else {
+        let sitemaps: Vec<_> = urls
+            .chunks(MAX_URLS_PER_SITEMAP)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let lastmod = chunk.iter().map(|url| url.lastmod).max().unwrap_or_default();
+                (format!("{}/sitemaps/{}.xml", site.api_url, i + 1), lastmod)
+            })
+            .collect();
+        info!("📑 Split {} URLs into {} sitemaps", urls.len(), sitemaps.len());
+        sitemap::index(&sitemaps)
+    };
+
+    Ok(xml_response(xml))
+}
+
+// --------------------------------------------------------
+// get_sitemap_part: 分割したサイトマップの n 番目
+// --------------------------------------------------------
+#[utoipa::path(
+    get,
+    path = "/sitemaps/{file}",
+    tag = "feeds",
+    summary = "分割したサイトマップ",
+    description = "サイトマップインデックスから参照する、50,000 URL ごとに分割したサイトマップです（例: /sitemaps/1.xml）",
+    params(("file" = String, Path, description = "{n}.xml（n は1から）")),
+    responses(
+        (status = 200, description = "サイトマップ（urlset）", content_type = "application/xml"),
+        (status = 404, description = "その番号のサイトマップはありません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    )
+)]
+pub async fn get_sitemap_part(
+    State(pool): State<PgPool>,
+    State(site): State<SiteConfig>,
+    AppPath(file): AppPath<String>,
+) -> AppResult<Response> {
+    let not_found = || AppError::NotFound(format!("Sitemap '{}' not found", file));
+
+    let number = file
+        .strip_suffix(".xml")
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|n| *n >= 1)
+        .ok_or_else(not_found)?;
+
+    let urls = load_urls(pool, &site).await?;
+
+    // 💡 URLが 50,000 以下ならインデックスを出さないので、分割したサイトマップもない
+    if urls.len() <= MAX_URLS_PER_SITEMAP {
+        return Err(not_found());
+    }
+    let chunk = urls
+        .chunks(MAX_URLS_PER_SITEMAP)
+        .nth(number - 1)
+        .ok_or_else(not_found)?;
+
+    Ok(xml_response(sitemap::urlset(chunk)))
+}
+
+/// 公開済みのページ → 翻訳ごとのURL（x-default はデフォルト言語）
+async fn load_urls(pool: PgPool, site: &SiteConfig) -> AppResult<Vec<SitemapUrl>> {
+    let default_locale = LocaleRepository::new(pool.clone()).find_default().await?;
+    let entries = SitemapRepository::new(pool).find_entries().await?;
+
+    let pages = group_pages(entries);
+    Ok(sitemap::expand(
+        &site.public_url,
+        &pages,
+        default_locale.as_ref().map(|locale| locale.code.as_str()),
+    ))
+}
+
+/// 同じページ（section + slug）の行をまとめる（find_entries がページごとに並べて返す）
+fn group_pages(entries: Vec<SitemapEntry>) -> Vec<SitemapPage> {
+    let mut pages: Vec<SitemapPage> = Vec::new();
+
+    for entry in entries {
+        let path = format!("{}/{}", entry.section, entry.slug);
+        let translation = PageTranslation {
+            locale: entry.locale_code,
+            lastmod: entry.updated_at,
+        };
+        match pages.last_mut() {
+            Some(page) if page.path == path => page.translations.push(translation),
+            _ => pages.push(SitemapPage {
+                path,
+                translations: vec![translation],
+            }),
+        }
+    }
+
+    pages
+}
+
+fn xml_response(xml: String) -> Response {
+    (
+        [
+            (CONTENT_TYPE, XML_CONTENT_TYPE.to_string()),
+            (CACHE_CONTROL, format!("public, max-age={}", SITEMAP_MAX_AGE_SECS)),
+        ],
+        xml,
+    )
+        .into_response()
+}
@@ -0,0 +1,532 @@
+use std::collections::HashSet;
+
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode, Uri},
+    response::Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::EditorOrAdmin,
+    entities::{LocalizedTopic, TopicTranslation},
+    error::{AppError, AppResult, ErrorResponse},
+    extractors::{
+        require_login_for_unpublished, Actor, AppJson, AppPath, AppQuery, Authorized, ClientInfo,
+        LangQuery, ResolvedLocale,
+    },
+    pagination::{ListQuery, PageMeta},
+    repositories::{LocaleRepository, TopicRepository, TopicSort, TopicTranslationInput},
+    validation::{is_valid_slug, MAX_SLUG_LEN},
+};
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+// --------------------------------------------------------
+// TopicResponse: 言語解決済みのトピック
+// --------------------------------------------------------
+//
+// 💡 locale / is_fallback:
+// - locale: 実際に使われた翻訳の言語コード
+// - is_fallback: 要求した言語の翻訳がなく、デフォルト言語で代用した場合 true
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TopicResponse {
+    /// トピックID
+    #[schema(example = 1)]
+    pub topic_id: i32,
+    /// URL用スラッグ
+    #[schema(example = "rust")]
+    pub slug: String,
+    /// 表示名（翻訳がない場合はnull）
+    #[schema(example = "Rust")]
+    pub name: Option<String>,
+    /// 表示名の言語コード
+    #[schema(example = "ja")]
+    pub locale: Option<String>,
+    /// デフォルト言語で代用したか
+    #[schema(example = false)]
+    pub is_fallback: bool,
+    /// 公開フラグ
+    #[schema(example = true)]
+    pub is_published: bool,
+    /// 作成日時
+    pub created_at: DateTime<Utc>,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TopicResponse {
+    fn from_localized(topic: LocalizedTopic, requested: &str) -> Self {
+        Self {
+            is_fallback: topic.is_fallback(requested),
+            topic_id: topic.topic_id,
+            slug: topic.slug,
+            name: topic.name,
+            locale: topic.locale_code,
+            is_published: topic.is_published,
+            created_at: topic.created_at,
+            updated_at: topic.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// TopicTranslationResponse: 翻訳1件
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TopicTranslationResponse {
+    /// 言語コード
+    #[schema(example = "en")]
+    pub locale: String,
+    /// 表示名
+    #[schema(example = "Rust")]
+    pub name: String,
+    /// 更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<TopicTranslation> for TopicTranslationResponse {
+    fn from(translation: TopicTranslation) -> Self {
+        Self {
+            locale: translation.locale_code,
+            name: translation.name,
+            updated_at: translation.updated_at,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// TopicDetailResponse: トピック詳細（全翻訳付き）
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TopicDetailResponse {
+    /// 言語解決済みのトピック
+    pub topic: TopicResponse,
+    /// 登録されているすべての翻訳
+    pub translations: Vec<TopicTranslationResponse>,
+}
+
+// --------------------------------------------------------
+// TopicsListResponse: トピック一覧
+// --------------------------------------------------------
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TopicsListResponse {
+    /// トピック一覧
+    pub topics: Vec<TopicResponse>,
+    /// ページ情報（total, page, per_page, total_pages, next_cursor）
+    #[serde(flatten)]
+    pub pagination: PageMeta,
+    /// 解決に使った言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+}
+
+// ============================================
+// リクエスト用の構造体
+// ============================================
+
+/// トピック取得のクエリパラメータ（言語は ResolvedLocale が ?lang= から読む）
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TopicQuery {
+    /// 非公開のトピックも含める（ログイン、または read スコープのAPIキーが必要）
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// 翻訳の入力
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TopicTranslationRequest {
+    /// 言語コード
+    #[schema(example = "ja")]
+    pub locale: String,
+    /// 表示名
+    #[schema(example = "Rust")]
+    pub name: String,
+}
+
+/// トピック作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTopicRequest {
+    /// URL用スラッグ
+    #[schema(example = "rust")]
+    pub slug: String,
+    /// 公開フラグ（省略時: true）
+    #[serde(default = "default_is_published")]
+    pub is_published: bool,
+    /// 翻訳（デフォルト言語の翻訳は必須）
+    pub translations: Vec<TopicTranslationRequest>,
+}
+
+fn default_is_published() -> bool {
+    true
+}
+
+/// トピック更新リクエスト（部分更新）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTopicRequest {
+    /// URL用スラッグ
+    #[schema(example = "rust-lang")]
+    pub slug: Option<String>,
+    /// 公開フラグ
+    pub is_published: Option<bool>,
+    /// 追加・更新する翻訳（含まれない言語はそのまま）
+    #[serde(default)]
+    pub translations: Vec<TopicTranslationRequest>,
+}
+
+// ============================================
+// 共通処理
+// ============================================
+
+// --------------------------------------------------------
+// resolve_translations: 言語コード → locale_id に変換して検証
+// --------------------------------------------------------
+async fn resolve_translations(
+    pool: &PgPool,
+    translations: &[TopicTranslationRequest],
+) -> AppResult<Vec<TopicTranslationInput>> {
+    let repo = LocaleRepository::new(pool.clone());
+    let mut seen = HashSet::new();
+    let mut inputs = Vec::with_capacity(translations.len());
+
+    for translation in translations {
+        if !seen.insert(translation.locale.as_str()) {
+            return Err(AppError::BadRequest(format!(
+                "Duplicate translation for locale '{}'",
+                translation.locale
+            )));
+        }
+
+        let name = translation.name.trim();
+        if name.is_empty() || name.chars().count() > 100 {
+            return Err(AppError::BadRequest(format!(
+                "Topic name for locale '{}' must be 1-100 characters",
+                translation.locale
+            )));
+        }
+
+        let locale = repo.find_by_code(&translation.locale).await?.ok_or_else(|| {
+            AppError::BadRequest(format!("Unknown locale '{}'", translation.locale))
+        })?;
+
+        inputs.push(TopicTranslationInput {
+            locale_id: locale.locale_id,
+            name: name.to_string(),
+        });
+    }
+
+    Ok(inputs)
+}
+
+fn validate_slug(slug: &str) -> AppResult<()> {
+    if is_valid_slug(slug, MAX_SLUG_LEN) {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!(
+            "Invalid slug '{}' (lowercase letters, digits and single hyphens only)",
+            slug
+        )))
+    }
+}
+
+pub(crate) fn topic_not_found(slug: &str) -> AppError {
+    AppError::NotFound(format!("Topic '{}' not found", slug))
+}
+
+// --------------------------------------------------------
+// load_detail: 言語解決済みトピック + 全翻訳
+// --------------------------------------------------------
+async fn load_detail(
+    repo: &TopicRepository,
+    slug: &str,
+    requested: &str,
+    fallback: &str,
+) -> AppResult<TopicDetailResponse> {
+    let topic = repo
+        .find_by_slug_localized(slug, requested, fallback)
+        .await?
+        .ok_or_else(|| topic_not_found(slug))?;
+
+    let translations = repo
+        .find_translations(topic.topic_id)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(TopicDetailResponse {
+        topic: TopicResponse::from_localized(topic, requested),
+        translations,
+    })
+}
+
+// ============================================
+// Handler関数
+// ============================================
+
+// --------------------------------------------------------
+// list_topics: トピック一覧
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/topics?lang=en&page=1&per_page=20&sort=slug
+#[utoipa::path(
+    get,
+    path = "/api/v1/topics",
+    tag = "topics",
+    summary = "トピック一覧取得",
+    description = "トピック一覧を指定した言語で取得します。翻訳がない場合はデフォルト言語で返します。\
+                   sort には slug（デフォルト）/ created_at / updated_at を指定できます",
+    params(LangQuery, TopicQuery, ListQuery),
+    responses(
+        (status = 200, description = "トピック一覧（Link ヘッダーに前後のページのURL）", body = TopicsListResponse),
+        (status = 400, description = "ページ・並び替えの指定が不正です", body = ErrorResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn list_topics(
+    State(pool): State<PgPool>,
+    actor: Option<Actor>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<TopicQuery>,
+    AppQuery(list): AppQuery<ListQuery>,
+    uri: Uri,
+) -> AppResult<(HeaderMap, Json<TopicsListResponse>)> {
+    info!("🏷️ Fetching topics (locale: {}, from {:?})...", locale.code, locale.source);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let params = list.resolve::<TopicSort>()?;
+
+    let repo = TopicRepository::new(pool);
+    let page = repo
+        .find_page_localized(
+            &locale.code,
+            &locale.default_code,
+            query.include_unpublished,
+            &params,
+        )
+        .await?;
+    let headers = page.link_headers(&uri);
+    let (topics, pagination) =
+        page.into_parts(|topic| TopicResponse::from_localized(topic, &locale.code));
+    for item in &topics {
+        locale.served(item.locale.as_deref());
+    }
+
+    info!("✅ Successfully fetched {} of {} topics", topics.len(), pagination.total);
+
+    Ok((
+        headers,
+        Json(TopicsListResponse {
+            topics,
+            pagination,
+            locale: locale.code,
+        }),
+    ))
+}
+
+// --------------------------------------------------------
+// get_topic: トピック詳細
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: GET /api/v1/topics/{slug}?lang=en
+#[utoipa::path(
+    get,
+    path = "/api/v1/topics/{slug}",
+    tag = "topics",
+    summary = "トピック詳細取得",
+    description = "slugを指定してトピックと全翻訳を取得します",
+    params(
+        ("slug" = String, Path, description = "トピックのスラッグ（例: rust）"),
+        LangQuery,
+        TopicQuery
+    ),
+    responses(
+        (status = 200, description = "トピック詳細", body = TopicDetailResponse),
+        (status = 401, description = "include_unpublished にはログインが必要です", body = ErrorResponse),
+        (status = 403, description = "APIキーに read スコープがありません", body = ErrorResponse),
+        (status = 404, description = "トピックが見つかりません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        (),
+        ("bearer_auth" = []),
+        ("session_cookie" = []),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn get_topic(
+    State(pool): State<PgPool>,
+    actor: Option<Actor>,
+    AppPath(slug): AppPath<String>,
+    locale: ResolvedLocale,
+    AppQuery(query): AppQuery<TopicQuery>,
+) -> AppResult<Json<TopicDetailResponse>> {
+    info!("🏷️ Fetching topic: {} (locale: {})", slug, locale.code);
+
+    require_login_for_unpublished(query.include_unpublished, actor.as_ref())?;
+
+    let repo = TopicRepository::new(pool);
+    let detail = load_detail(&repo, &slug, &locale.code, &locale.default_code).await?;
+    locale.served(detail.topic.locale.as_deref());
+
+    if !detail.topic.is_published && !query.include_unpublished {
+        return Err(topic_not_found(&slug));
+    }
+
+    Ok(Json(detail))
+}
+
+// --------------------------------------------------------
+// create_topic: トピック作成
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: POST /api/v1/topics
+//
+// 💡 デフォルト言語の翻訳を必須にする理由:
+// - 他の言語の翻訳がないとき、デフォルト言語にフォールバックするため
+#[utoipa::path(
+    post,
+    path = "/api/v1/topics",
+    tag = "topics",
+    summary = "トピック作成",
+    description = "トピックと翻訳を作成します。デフォルト言語の翻訳は必須です",
+    request_body = CreateTopicRequest,
+    responses(
+        (status = 201, description = "作成されたトピック", body = TopicDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 409, description = "slugが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn create_topic(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<CreateTopicRequest>,
+) -> AppResult<(StatusCode, Json<TopicDetailResponse>)> {
+    info!("🏷️ Creating topic: {}", payload.slug);
+
+    validate_slug(&payload.slug)?;
+
+    let default_code = &locale.default_code;
+    if !payload.translations.iter().any(|t| &t.locale == default_code) {
+        return Err(AppError::BadRequest(format!(
+            "A translation for the default locale '{}' is required",
+            default_code
+        )));
+    }
+
+    let translations = resolve_translations(&pool, &payload.translations).await?;
+
+    let repo = TopicRepository::new(pool);
+    let topic = repo
+        .create(
+            &payload.slug,
+            payload.is_published,
+            actor.admin_id(),
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await?;
+
+    info!("✅ Created topic: {} (id: {})", topic.slug, topic.topic_id);
+
+    let detail = load_detail(&repo, &topic.slug, &locale.code, &locale.default_code).await?;
+    locale.served(detail.topic.locale.as_deref());
+
+    Ok((StatusCode::CREATED, Json(detail)))
+}
+
+// --------------------------------------------------------
+// update_topic: トピック更新
+// --------------------------------------------------------
+//
+// 💡 エンドポイント: PATCH /api/v1/topics/{slug}
+#[utoipa::path(
+    patch,
+    path = "/api/v1/topics/{slug}",
+    tag = "topics",
+    summary = "トピック更新",
+    description = "slug・公開フラグを更新し、指定した言語の翻訳を追加・更新します",
+    params(
+        ("slug" = String, Path, description = "トピックのスラッグ（例: rust）")
+    ),
+    request_body = UpdateTopicRequest,
+    responses(
+        (status = 200, description = "更新後のトピック", body = TopicDetailResponse),
+        (status = 400, description = "入力が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "admin または editor のみ実行できます", body = ErrorResponse),
+        (status = 404, description = "トピックが見つかりません", body = ErrorResponse),
+        (status = 409, description = "slugが既に存在します", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor"]),
+        ("session_cookie" = ["admin", "editor"]),
+        ("api_key" = ["write"])
+    )
+)]
+pub async fn update_topic(
+    State(pool): State<PgPool>,
+    actor: Authorized<EditorOrAdmin>,
+    client: ClientInfo,
+    AppPath(slug): AppPath<String>,
+    locale: ResolvedLocale,
+    AppJson(payload): AppJson<UpdateTopicRequest>,
+) -> AppResult<Json<TopicDetailResponse>> {
+    info!("🏷️ Updating topic: {}", slug);
+
+    if let Some(new_slug) = &payload.slug {
+        validate_slug(new_slug)?;
+    }
+
+    let translations = resolve_translations(&pool, &payload.translations).await?;
+
+    let repo = TopicRepository::new(pool);
+    let current = repo
+        .find_by_slug(&slug)
+        .await?
+        .ok_or_else(|| topic_not_found(&slug))?;
+
+    let topic = repo
+        .update(
+            current.topic_id,
+            payload.slug.as_deref(),
+            payload.is_published,
+            &translations,
+            &actor.audit_context(&client),
+        )
+        .await?
+        .ok_or_else(|| topic_not_found(&slug))?;
+
+    info!("✅ Updated topic: {} (id: {})", topic.slug, topic.topic_id);
+
+    let detail = load_detail(&repo, &topic.slug, &locale.code, &locale.default_code).await?;
+    locale.served(detail.topic.locale.as_deref());
+
+    Ok(Json(detail))
+}
@@ -0,0 +1,347 @@
+use axum::{extract::State, response::Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::{
+    auth::policy::AnyRoleRead,
+    entities::{Locale, TranslatableEntity, TranslationCoverageRow},
+    error::{AppResult, ErrorResponse},
+    extractors::{AppQuery, Authorized},
+    repositories::LocaleRepository,
+};
+
+// ============================================
+// 翻訳の状況（どのデータのどの言語の翻訳が足りないか）
+// ============================================
+//
+// 💡 エンドポイント: GET /api/v1/translation-coverage
+//
+// 💡 翻訳の状態（データ × 有効な言語ごと）:
+// | 状態       | 意味                                                      |
+// |------------|-----------------------------------------------------------|
+// | source     | 原文の言語の翻訳（比較の基準）                            |
+// | up_to_date | 原文の翻訳より後に更新された翻訳                          |
+// | stale      | 翻訳より後に原文の翻訳が更新された（訳し直しが必要）      |
+// | missing    | 翻訳がない                                                |
+//
+// 💡 原文の言語 = 記事・本の default_locale_id（なければサイトのデフォルト言語）
+//    原文の言語の翻訳がないデータは、ある翻訳をすべて up_to_date とみなす（比べる相手がない）
+
+/// 翻訳の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslationStatus {
+    Source,
+    UpToDate,
+    Stale,
+    Missing,
+}
+
+/// 翻訳の状況の絞り込み条件
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CoverageQuery {
+    /// データの種類（post / topic / book / book_chapter / media）
+    pub entity_type: Option<TranslatableEntity>,
+    /// この状態の言語を含むデータだけを entities に返す（missing / stale など。言語ごとの集計には影響しない）
+    pub status: Option<TranslationStatus>,
+}
+
+// ============================================
+// レスポンス用の構造体
+// ============================================
+
+/// 言語ごとの集計
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LocaleCoverage {
+    #[schema(example = "en")]
+    pub locale: String,
+    /// 対象のデータの数
+    #[schema(example = 40)]
+    pub total: i64,
+    /// 原文（source）の数
+    #[schema(example = 30)]
+    pub source: i64,
+    #[schema(example = 6)]
+    pub up_to_date: i64,
+    #[schema(example = 2)]
+    pub stale: i64,
+    #[schema(example = 2)]
+    pub missing: i64,
+    /// 翻訳がある割合（source + up_to_date + stale、%、小数第1位まで）
+    #[schema(example = 95.0)]
+    pub translated_percent: f64,
+    /// 最新の翻訳がある割合（source + up_to_date、%）
+    #[schema(example = 90.0)]
+    pub up_to_date_percent: f64,
+    #[schema(example = 5.0)]
+    pub stale_percent: f64,
+    #[schema(example = 5.0)]
+    pub missing_percent: f64,
+}
+
+/// 1つの言語の翻訳の状態
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LocaleTranslationStatus {
+    #[schema(example = "en")]
+    pub locale: String,
+    pub status: TranslationStatus,
+    /// 翻訳の更新日時（missing では null）
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// 1つのデータの翻訳の状況
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EntityCoverage {
+    pub entity_type: TranslatableEntity,
+    #[schema(example = 7)]
+    pub entity_id: i32,
+    /// slug（チャプターは 本のslug/チャプターのslug、メディアは元のファイル名）
+    #[schema(example = "rust-ownership")]
+    pub label: String,
+    /// 原文の言語
+    #[schema(example = "ja")]
+    pub source_locale: Option<String>,
+    /// 原文の翻訳の更新日時
+    pub source_updated_at: Option<DateTime<Utc>>,
+    /// 有効な言語ごとの状態（locale_id 順）
+    pub translations: Vec<LocaleTranslationStatus>,
+}
+
+/// 翻訳の状況のレスポンス
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TranslationCoverageResponse {
+    /// 有効な言語ごとの集計（locale_id 順）
+    pub locales: Vec<LocaleCoverage>,
+    /// データごとの状況
+    pub entities: Vec<EntityCoverage>,
+}
+
+// ============================================
+// ハンドラー関数
+// ============================================
+
+// --------------------------------------------------------
+// get_translation_coverage: 翻訳の状況
+// --------------------------------------------------------
+//
+// 💡 使用例（英語の翻訳がない記事を探す）:
+//   curl 'http://localhost:8000/api/v1/translation-coverage?entity_type=post&status=missing' \
+//     -H 'Authorization: Bearer <session token>'
+#[utoipa::path(
+    get,
+    path = "/api/v1/translation-coverage",
+    tag = "locales",
+    summary = "翻訳の状況",
+    description = "翻訳できるすべてのデータ（記事・トピック・本・チャプター・メディア）について、\
+                   有効な言語ごとに翻訳の状態（source / up_to_date / stale / missing）を返します。\
+                   stale は翻訳より後に原文の言語の翻訳が更新されたものです。言語ごとの割合も返します（ログインまたは読み取り権限のAPIキーが必要）",
+    params(CoverageQuery),
+    responses(
+        (status = 200, description = "翻訳の状況", body = TranslationCoverageResponse),
+        (status = 400, description = "entity_type / status の指定が不正です", body = ErrorResponse),
+        (status = 401, description = "ログインしていません", body = ErrorResponse),
+        (status = 403, description = "権限がありません", body = ErrorResponse),
+        (status = 500, description = "サーバーエラー", body = ErrorResponse)
+    ),
+    security(
+        ("bearer_auth" = ["admin", "editor", "author"]),
+        ("session_cookie" = ["admin", "editor", "author"]),
+        ("api_key" = ["read"])
+    )
+)]
+pub async fn get_translation_coverage(
+    State(pool): State<PgPool>,
+    _actor: Authorized<AnyRoleRead>,
+    AppQuery(query): AppQuery<CoverageQuery>,
+) -> AppResult<Json<TranslationCoverageResponse>> {
+    info!("🌐 Building translation coverage (entity_type: {:?})", query.entity_type);
+
+    let repo = LocaleRepository::new(pool);
+    let locales = repo.find_active().await?;
+    let rows = repo.find_translation_coverage(query.entity_type).await?;
+
+    let (locales, mut entities) = summarize(&locales, rows);
+    if let Some(status) = query.status {
+        entities.retain(|entity| entity.translations.iter().any(|tr| tr.status == status));
+    }
+
+    info!("✅ Translation coverage for {} entities", entities.len());
+
+    Ok(Json(TranslationCoverageResponse { locales, entities }))
+}
+
+// --------------------------------------------------------
+// classify: 1つの言語の翻訳の状態
+// --------------------------------------------------------
+fn classify(row: &TranslationCoverageRow) -> TranslationStatus {
+    let Some(updated_at) = row.translation_updated_at else {
+        return TranslationStatus::Missing;
+    };
+    if row.source_locale_code.as_deref() == Some(row.locale_code.as_str()) {
+        return TranslationStatus::Source;
+    }
+    match row.source_updated_at {
+        Some(source_updated_at) if source_updated_at > updated_at => TranslationStatus::Stale,
+        _ => TranslationStatus::UpToDate,
+    }
+}
+
+// --------------------------------------------------------
+// summarize: 行（データ × 言語）→ データごとの状況 + 言語ごとの集計
+// --------------------------------------------------------
+//
+// 💡 rows はデータごとにまとまった順（find_translation_coverage の ORDER BY）
+fn summarize(
+    locales: &[Locale],
+    rows: Vec<TranslationCoverageRow>,
+) -> (Vec<LocaleCoverage>, Vec<EntityCoverage>) {
+    let mut coverage: Vec<LocaleCoverage> = locales
+        .iter()
+        .map(|locale| LocaleCoverage {
+            locale: locale.code.clone(),
+            total: 0,
+            source: 0,
+            up_to_date: 0,
+            stale: 0,
+            missing: 0,
+            translated_percent: 0.0,
+            up_to_date_percent: 0.0,
+            stale_percent: 0.0,
+            missing_percent: 0.0,
+        })
+        .collect();
+    let mut entities: Vec<EntityCoverage> = Vec::new();
+
+    for row in rows {
+        let status = classify(&row);
+
+        if let Some(counts) = coverage.iter_mut().find(|c| c.locale == row.locale_code) {
+            counts.total += 1;
+            match status {
+                TranslationStatus::Source => counts.source += 1,
+                TranslationStatus::UpToDate => counts.up_to_date += 1,
+                TranslationStatus::Stale => counts.stale += 1,
+                TranslationStatus::Missing => counts.missing += 1,
+            }
+        }
+
+        let translation = LocaleTranslationStatus {
+            locale: row.locale_code,
+            status,
+            updated_at: row.translation_updated_at,
+        };
+        match entities.last_mut() {
+            Some(entity)
+                if entity.entity_type == row.entity_type && entity.entity_id == row.entity_id =>
+            {
+                entity.translations.push(translation)
+            }
+            _ => entities.push(EntityCoverage {
+                entity_type: row.entity_type,
+                entity_id: row.entity_id,
+                label: row.label,
+                source_locale: row.source_locale_code,
+                source_updated_at: row.source_updated_at,
+                translations: vec![translation],
+            }),
+        }
+    }
+
+    for counts in &mut coverage {
+        counts.translated_percent = percent(counts.source + counts.up_to_date + counts.stale, counts.total);
+        counts.up_to_date_percent = percent(counts.source + counts.up_to_date, counts.total);
+        counts.stale_percent = percent(counts.stale, counts.total);
+        counts.missing_percent = percent(counts.missing, counts.total);
+    }
+
+    (coverage, entities)
+}
+
+/// 割合（%、小数第1位まで）。対象が0件なら 0.0
+fn percent(count: i64, total: i64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (count as f64 * 1000.0 / total as f64).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn locale(locale_id: i32, code: &str) -> Locale {
+        Locale {
+            locale_id,
+            code: code.to_string(),
+            name: code.to_string(),
+            is_default: locale_id == 1,
+            is_active: true,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn row(
+        entity_id: i32,
+        locale_code: &str,
+        source: Option<DateTime<Utc>>,
+        updated: Option<DateTime<Utc>>,
+    ) -> TranslationCoverageRow {
+        TranslationCoverageRow {
+            entity_type: TranslatableEntity::Post,
+            entity_id,
+            label: format!("post-{}", entity_id),
+            source_locale_code: Some("ja".to_string()),
+            source_updated_at: source,
+            locale_code: locale_code.to_string(),
+            translation_updated_at: updated,
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let day1 = Some(Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap());
+        let day2 = Some(Utc.with_ymd_and_hms(2026, 10, 2, 0, 0, 0).unwrap());
+        let day3 = Some(Utc.with_ymd_and_hms(2026, 10, 3, 0, 0, 0).unwrap());
+
+        assert_eq!(classify(&row(1, "ja", day2, day2)), TranslationStatus::Source);
+        assert_eq!(classify(&row(1, "en", day2, day3)), TranslationStatus::UpToDate);
+        assert_eq!(classify(&row(1, "en", day2, day1)), TranslationStatus::Stale);
+        assert_eq!(classify(&row(1, "en", day2, None)), TranslationStatus::Missing);
+        // 原文の翻訳がない場合は比べる相手がない
+        assert_eq!(classify(&row(1, "en", None, day1)), TranslationStatus::UpToDate);
+    }
+
+    #[test]
+    fn test_summarize() {
+        let day1 = Some(Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap());
+        let day2 = Some(Utc.with_ymd_and_hms(2026, 10, 2, 0, 0, 0).unwrap());
+        let day4 = Some(Utc.with_ymd_and_hms(2026, 10, 4, 0, 0, 0).unwrap());
+
+        let locales = [locale(1, "ja"), locale(2, "en")];
+        let rows = vec![
+            row(1, "ja", day2, day2),
+            row(1, "en", day2, day1),
+            row(2, "ja", day1, day1),
+            row(2, "en", day1, None),
+            row(3, "ja", day1, day1),
+            row(3, "en", day1, day4),
+        ];
+
+        let (coverage, entities) = summarize(&locales, rows);
+        assert_eq!(entities.len(), 3);
+        assert_eq!(entities[1].translations[1].status, TranslationStatus::Missing);
+
+        assert_eq!(coverage[0].source, 3);
+        assert_eq!(coverage[0].up_to_date_percent, 100.0);
+        let en = &coverage[1];
+        assert_eq!((en.total, en.up_to_date, en.stale, en.missing), (3, 1, 1, 1));
+        assert_eq!(en.translated_percent, 66.7);
+        assert_eq!(en.up_to_date_percent, 33.3);
+        assert_eq!(en.missing_percent, 33.3);
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgPoolOptions, PgPool};
+use tracing::info;
+
+use crate::{
+    entities::Locale,
+    error::AppError,
+    handlers::posts::{resolve_translations, validate_slug, PostTranslationRequest},
+    repositories::{
+        legacy_post_repository::{LegacyPost, LegacyPostRepository, LegacyTranslation},
+        AuditContext, LocaleRepository, NewPost, PostRepository, PostTranslationInput,
+    },
+};
+
+// ============================================
+// 旧スキーマの記事の移行（backend legacy import-posts）
+// ============================================
+//
+// 💡 旧 posts の1行 → blog_posts 1行 + blog_post_translations（ja / en の2行まで）
+// - 言語コード（ja / en）は LocaleRepository::find_by_code で locale_id に変換する
+// - 翻訳は API で保存するときと同じ処理（handlers::posts::resolve_translations）を通す
+//   → 入力チェック・読了目安・抜粋・検索用の search_vector も同じ方法で作る
+// - 記事の作成は PostRepository::create（最初の版・監査ログ changed_via = 'cli' も残る）
+//
+// 💡 スキップする行（理由を1行ずつ表示する）:
+// - 削除済み（deleted_at あり）、content_format が markdown 以外
+// - slug が不正、または同じ slug の記事がもうある（2回目の実行では移行済みの行がスキップされる）
+// - 移行できる翻訳が1つもない
+//
+// 💡 カテゴリ・タグ・著者・アイキャッチ画像は移行しない（新しいスキーマのIDと対応しないため）
+
+/// 旧スキーマにある言語（列名の接尾辞）
+const LEGACY_LOCALES: [&str; 2] = ["ja", "en"];
+
+/// 1行の移行結果
+#[derive(Debug)]
+enum RowOutcome {
+    Imported { post_id: i32, locales: Vec<String> },
+    WouldImport { locales: Vec<String> },
+    Skipped(String),
+}
+
+/// 旧データの公開状態 → 新しいスキーマの公開状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Publication {
+    is_published: bool,
+    published_at: Option<DateTime<Utc>>,
+    scheduled_at: Option<DateTime<Utc>>,
+    /// 元の状態どおりに移行できない場合の説明
+    note: Option<String>,
+}
+
+// --------------------------------------------------------
+// import_posts: 旧 posts を読み、blog_posts + 翻訳を作る
+// --------------------------------------------------------
+//
+// 💡 source_url: 旧 posts があるデータベース（省略時は移行先と同じデータベース）
+// 💡 dry_run: 書き込まずに、移行する行・スキップする行だけを表示する
+pub async fn import_posts(
+    pool: &PgPool,
+    source_url: Option<&str>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let source = match source_url {
+        Some(url) => PgPoolOptions::new().max_connections(1).connect(url).await?,
+        None => pool.clone(),
+    };
+    let legacy_posts = LegacyPostRepository::new(source)
+        .find_all()
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to read legacy posts table: {}", e))?;
+    info!("📦 Found {} legacy posts{}", legacy_posts.len(), if dry_run { " (dry run)" } else { "" });
+
+    // 言語コード → 言語（見つからない言語の翻訳はスキップする）
+    let locale_repo = LocaleRepository::new(pool.clone());
+    let mut locales = HashMap::new();
+    for code in LEGACY_LOCALES {
+        if let Some(locale) = locale_repo.find_by_code(code).await? {
+            locales.insert(code, locale);
+        }
+    }
+
+    let posts = PostRepository::new(pool.clone());
+    let audit = AuditContext::cli();
+    let now = Utc::now();
+    let (mut imported, mut skipped) = (0, 0);
+
+    println!("{:<8} {:<40} RESULT", "ID", "SLUG");
+    for legacy in &legacy_posts {
+        let mut warnings = Vec::new();
+        let outcome =
+            import_one(pool, &posts, &locales, legacy, now, dry_run, &audit, &mut warnings).await?;
+
+        let result = match &outcome {
+            RowOutcome::Imported { post_id, locales } => {
+                imported += 1;
+                format!("imported as post {} ({})", post_id, locales.join(", "))
+            }
+            RowOutcome::WouldImport { locales } => {
+                imported += 1;
+                format!("would import ({})", locales.join(", "))
+            }
+            RowOutcome::Skipped(reason) => {
+                skipped += 1;
+                format!("skipped: {}", reason)
+            }
+        };
+        println!("{:<8} {:<40} {}", legacy.id, legacy.slug, result);
+        for warning in &warnings {
+            println!("{:<8} {:<40}   ⚠️ {}", "", "", warning);
+        }
+    }
+
+    println!();
+    if dry_run {
+        println!("Dry run: {} would be imported, {} skipped (nothing was written)", imported, skipped);
+    } else {
+        println!("Imported {}, skipped {}", imported, skipped);
+    }
+    Ok(())
+}
+
+// --------------------------------------------------------
+// import_one: 旧 posts の1行を移行する
+// --------------------------------------------------------
+//
+// 💡 行ごとの問題（不正な値・重複など）は Skipped として返し、次の行に進む
+//    データベースに接続できないなどの問題は Err（移行全体を中止する）
+#[allow(clippy::too_many_arguments)]
+async fn import_one(
+    pool: &PgPool,
+    posts: &PostRepository,
+    locales: &HashMap<&str, Locale>,
+    legacy: &LegacyPost,
+    now: DateTime<Utc>,
+    dry_run: bool,
+    audit: &AuditContext,
+    warnings: &mut Vec<String>,
+) -> anyhow::Result<RowOutcome> {
+    if legacy.deleted_at.is_some() {
+        return Ok(RowOutcome::Skipped("deleted (deleted_at is set)".to_string()));
+    }
+    if let Some(format) = legacy.content_format.as_deref().filter(|f| *f != "markdown") {
+        return Ok(RowOutcome::Skipped(format!("unsupported content_format '{}'", format)));
+    }
+    if let Err(e) = validate_slug(&legacy.slug) {
+        return Ok(RowOutcome::Skipped(e.to_string()));
+    }
+    if let Some(existing) = posts.find_by_slug(&legacy.slug).await? {
+        return Ok(RowOutcome::Skipped(format!(
+            "slug already exists in blog_posts (post {})",
+            existing.post_id
+        )));
+    }
+
+    // 翻訳: 言語ごとに API と同じチェック・計算を通す（問題のある言語だけスキップ）
+    let mut inputs: Vec<(&Locale, PostTranslationInput)> = Vec::new();
+    for (code, translation) in legacy.translations() {
+        if is_blank(&translation) {
+            continue;
+        }
+        let Some(locale) = locales.get(code) else {
+            warnings.push(format!("{}: skipped translation (locale '{}' not found)", code, code));
+            continue;
+        };
+        match resolve_translations(pool, &[translation_request(code, translation)]).await {
+            Ok(mut resolved) => inputs.extend(resolved.pop().map(|input| (locale, input))),
+            Err(AppError::BadRequest(reason)) => {
+                warnings.push(format!("{}: skipped translation ({})", code, reason));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if inputs.is_empty() {
+        return Ok(RowOutcome::Skipped("no translation could be migrated".to_string()));
+    }
+
+    // 原文の言語: 旧スキーマの主な言語（ja）を優先し、なければ移行する翻訳のうち有効な言語
+    let Some(default_locale) = inputs
+        .iter()
+        .map(|(locale, _)| *locale)
+        .filter(|locale| locale.is_active)
+        .min_by_key(|locale| LEGACY_LOCALES.iter().position(|code| *code == locale.code))
+    else {
+        return Ok(RowOutcome::Skipped("no translation in an active locale".to_string()));
+    };
+
+    let publication = publication(legacy, now);
+    warnings.extend(publication.note.clone());
+
+    let codes: Vec<String> = inputs.iter().map(|(locale, _)| locale.code.clone()).collect();
+    if dry_run {
+        return Ok(RowOutcome::WouldImport { locales: codes });
+    }
+
+    let translations: Vec<PostTranslationInput> = inputs.into_iter().map(|(_, input)| input).collect();
+    let created = posts
+        .create(
+            &NewPost {
+                slug: legacy.slug.clone(),
+                meta_image_url: None,
+                is_published: publication.is_published,
+                published_at: publication.published_at,
+                scheduled_at: publication.scheduled_at,
+                default_locale_id: default_locale.locale_id,
+                book: None,
+                featured_image_id: None,
+                created_by: None,
+            },
+            &translations,
+            &[],
+            audit,
+        )
+        .await;
+
+    match created {
+        Ok(post) => Ok(RowOutcome::Imported { post_id: post.post_id, locales: codes }),
+        // 💡 CHECK制約違反など、その行だけの問題はスキップとして報告する
+        Err(sqlx::Error::Database(e)) => Ok(RowOutcome::Skipped(format!("failed to insert: {}", e))),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// タイトルも本文もない言語（旧データで片方の言語だけ書いていた記事）
+fn is_blank(translation: &LegacyTranslation) -> bool {
+    let blank = |value: &Option<String>| value.as_deref().is_none_or(|v| v.trim().is_empty());
+    blank(&translation.title) && blank(&translation.content)
+}
+
+fn translation_request(code: &str, translation: LegacyTranslation) -> PostTranslationRequest {
+    PostTranslationRequest {
+        locale: code.to_string(),
+        title: translation.title.unwrap_or_default(),
+        summary: translation.excerpt.filter(|excerpt| !excerpt.trim().is_empty()),
+        meta_title: translation.seo_title,
+        meta_description: translation.seo_description,
+        content: translation.content.unwrap_or_default(),
+    }
+}
+
+// --------------------------------------------------------
+// publication: 旧データの status / published → 公開状態
+// --------------------------------------------------------
+//
+// | 旧データ                                  | 移行後                                         |
+// |-------------------------------------------|------------------------------------------------|
+// | published = TRUE または status = published | 公開（published_at は元の値、なければ created_at） |
+// | status = scheduled（予定が未来）          | 下書き + 予約公開（scheduled_at）              |
+// | status = scheduled（予定が過去・なし）    | 下書き（注意を表示）                           |
+// | draft                                     | 下書き                                         |
+// | archived / その他                         | 下書き（注意を表示）                           |
+fn publication(legacy: &LegacyPost, now: DateTime<Utc>) -> Publication {
+    let status = legacy.status.as_deref().unwrap_or("draft");
+
+    if legacy.published == Some(true) || status == "published" {
+        return Publication {
+            is_published: true,
+            published_at: legacy.published_at.or(legacy.created_at),
+            scheduled_at: None,
+            note: None,
+        };
+    }
+
+    let draft = |note: Option<String>| Publication {
+        is_published: false,
+        published_at: None,
+        scheduled_at: None,
+        note,
+    };
+    match (status, legacy.scheduled_at) {
+        ("scheduled", Some(at)) if at > now => Publication {
+            scheduled_at: Some(at),
+            ..draft(None)
+        },
+        ("scheduled", _) => draft(Some(
+            "scheduled date is missing or in the past; imported as a draft".to_string(),
+        )),
+        ("draft", _) => draft(None),
+        ("archived", _) => draft(Some("archived; imported as a draft".to_string())),
+        (other, _) => draft(Some(format!("unknown status '{}'; imported as a draft", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn legacy(status: &str, published: bool) -> LegacyPost {
+        LegacyPost {
+            id: 1,
+            slug: "hello-rust".to_string(),
+            title_ja: Some("こんにちは".to_string()),
+            content_ja: Some("本文".to_string()),
+            excerpt_ja: None,
+            seo_title_ja: None,
+            seo_description_ja: None,
+            title_en: None,
+            content_en: Some("  ".to_string()),
+            excerpt_en: None,
+            seo_title_en: None,
+            seo_description_en: None,
+            status: Some(status.to_string()),
+            published: Some(published),
+            published_at: None,
+            scheduled_at: None,
+            content_format: Some("markdown".to_string()),
+            created_at: Some(Utc.with_ymd_and_hms(2026, 1, 14, 0, 0, 0).unwrap()),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_publication() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap();
+
+        // 公開済み: published_at がなければ created_at
+        let published = publication(&legacy("published", false), now);
+        assert!(published.is_published);
+        assert_eq!(published.published_at, legacy("draft", false).created_at);
+
+        // 予約（未来）はそのまま予約、過去なら下書き + 注意
+        let mut scheduled = legacy("scheduled", false);
+        scheduled.scheduled_at = Some(Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap());
+        assert_eq!(publication(&scheduled, now).scheduled_at, scheduled.scheduled_at);
+        scheduled.scheduled_at = Some(Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap());
+        let overdue = publication(&scheduled, now);
+        assert!(!overdue.is_published && overdue.scheduled_at.is_none() && overdue.note.is_some());
+
+        // 下書きはそのまま、アーカイブは下書き + 注意
+        assert!(publication(&legacy("draft", false), now).note.is_none());
+        let archived = publication(&legacy("archived", false), now);
+        assert!(!archived.is_published && archived.note.is_some());
+    }
+
+    #[test]
+    fn test_blank_translation() {
+        let [(_, ja), (_, en)] = legacy("draft", false).translations();
+        assert!(!is_blank(&ja));
+        assert!(is_blank(&en));
+    }
+}
@@ -1,16 +1,43 @@
-use axum::http::{header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE}, HeaderValue, Method};
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
-use tracing::info;
+use axum::{
+    extract::Request,
+    http::{header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE}, Method},
+};
+use tower_http::{cors::{AllowOrigin, CorsLayer}, trace::TraceLayer};
+use tracing::{info, info_span};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod auth;          // 追加: 認証（パスワード・セッショントークン）
+mod cli;           // 追加: コマンドライン引数
+mod config;        // 追加: 設定（環境変数 + TOML）
 mod database;
+mod diff;          // 追加: 翻訳の版の差分
 mod entities;      // 追加: Localeエンティティ
+mod error;         // 追加: 共通エラー（AppError）
+mod extractors;    // 追加: 独自エクストラクター
+mod feed;          // 追加: RSS / Atom フィード
 mod handlers;
+mod legacy;        // 追加: 旧スキーマのデータ移行
+mod markdown;      // 追加: 本文の Markdown → HTML 変換
+mod middleware;    // 追加: リクエストIDなど
+mod migrations;    // 追加: 埋め込みマイグレーション
 mod models;
+mod pagination;    // 追加: ページネーション・並び替え
 mod repositories;  // 追加: LocaleRepository
 mod routes;
+mod scheduler;     // 追加: 予約公開のバックグラウンド処理
+mod search;        // 追加: 全文検索（言語ごとの分け方・抜粋）
+mod sitemap;       // 追加: XML サイトマップ
+mod state;         // 追加: AppState（ハンドラー共通の状態）
+mod storage;       // 追加: メディアファイルの保存先
+mod validation;   // 追加: 共通の入力チェック
 
+use clap::Parser;
+use cli::{AdminAction, Cli, Command, LegacyAction, MigrateAction, SearchAction};
+use config::AppConfig;
+use extractors::X_API_KEY;
+use middleware::request_id::{RequestId, X_REQUEST_ID};
 use routes::create_router;
+use state::AppState;
 
 
 #[tokio::main]
@@ -27,45 +54,187 @@ async fn main() -> anyhow::Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
-    info!("🦀 Starting Rust Blog Backend Server...");
+    let cli = Cli::parse();
+
+    // 設定の読み込み（不正な設定ならここで起動を中止）
+    let config = AppConfig::load()?;
+    info!("⚙️ Environment: {}", config.environment);
 
     // データベース接続プールの作成
     info!("📊 Connecting to database...");
-    let pool = database::create_pool().await?;
-    
+    let pool = database::create_pool(&config.database).await?;
+
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(config, pool).await,
+        Command::Migrate { action } => match action {
+            MigrateAction::Up => migrations::run_up(&pool).await,
+            MigrateAction::Status => migrations::print_status(&pool).await,
+            MigrateAction::Revert { target } => migrations::revert(&pool, target).await,
+            MigrateAction::Baseline { version } => migrations::baseline(&pool, version).await,
+        },
+        Command::Admin { action } => match action {
+            AdminAction::Create {
+                username,
+                email,
+                role,
+                full_name,
+            } => create_admin(&pool, username, email, role, full_name).await,
+        },
+        Command::Search { action } => match action {
+            SearchAction::Reindex => reindex_search(&pool).await,
+        },
+        Command::Legacy { action } => match action {
+            LegacyAction::ImportPosts { dry_run, source_url } => {
+                legacy::import_posts(&pool, source_url.as_deref(), dry_run).await
+            }
+        },
+    }
+}
+
+// --------------------------------------------------------
+// reindex_search: 記事の全文検索用の search_vector を作り直す（backend search reindex）
+// --------------------------------------------------------
+//
+// 💡 翻訳を保存するときと同じ方法（search::SearchDocument）で作る
+// - migration 013 より前に保存した翻訳は search_vector が空なので、一度実行する
+async fn reindex_search(pool: &sqlx::PgPool) -> anyhow::Result<()> {
+    let repo = repositories::SearchRepository::new(pool.clone());
+    let sources = repo.find_index_sources().await?;
+
+    for source in &sources {
+        let document = search::SearchDocument::new(
+            &source.locale_code,
+            &source.title,
+            source.summary.as_deref(),
+            markdown::plain_text(&source.content),
+        );
+        repo.update_search_vector(source, &document).await?;
+    }
+
+    info!("✅ Reindexed {} post translations", sources.len());
+    Ok(())
+}
+
+// --------------------------------------------------------
+// create_admin: 管理者アカウントを作成（backend admin create）
+// --------------------------------------------------------
+//
+// 💡 パスワードはコマンドライン引数では受け取らない（シェル履歴に残るため）
+// - ADMIN_PASSWORD 環境変数があればそれを使う（CI・初期構築向け）
+// - なければ標準入力から1行読む
+async fn create_admin(
+    pool: &sqlx::PgPool,
+    username: String,
+    email: String,
+    role: entities::Role,
+    full_name: Option<String>,
+) -> anyhow::Result<()> {
+    let password = match std::env::var("ADMIN_PASSWORD") {
+        Ok(password) => password,
+        Err(_) => {
+            eprint!("Password for '{}': ", username);
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line)?;
+            line.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+
+    let admin = auth::accounts::create_admin(
+        pool,
+        auth::accounts::AdminAccountInput {
+            username,
+            email,
+            password,
+            full_name,
+            role,
+        },
+        &repositories::AuditContext::cli(),
+    )
+    .await
+    .map_err(|e| anyhow::anyhow!("failed to create administrator: {}", e))?;
+
+    info!(
+        "✅ Created administrator '{}' <{}> ({})",
+        admin.username, admin.email, admin.role
+    );
+    Ok(())
+}
+
+/// APIサーバーを起動
+async fn serve(config: AppConfig, pool: sqlx::PgPool) -> anyhow::Result<()> {
+    info!("🦀 Starting Rust Blog Backend Server...");
+
     // データベース接続テスト
     database::test_connection(&pool).await?;
 
-    // CORS設定
+    // スキーマの確認（古いスキーマのままでは起動しない）
+    migrations::prepare_schema(&pool, config.migrations.run_on_startup).await?;
+
+    // CORS設定（許可するオリジンは CORS_ORIGINS / cors.allowed_origins）
     let cors = CorsLayer::new()
-        .allow_origin("http://localhost:3000".parse::<HeaderValue>()?)
-        .allow_origin("http://localhost:3001".parse::<HeaderValue>()?)
+        .allow_origin(AllowOrigin::list(config.cors.origin_header_values()))
         .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE])
         .allow_credentials(true)
-        .allow_headers([AUTHORIZATION, ACCEPT, CONTENT_TYPE]);
+        .allow_headers([AUTHORIZATION, ACCEPT, CONTENT_TYPE, X_REQUEST_ID, X_API_KEY])
+        .expose_headers([X_REQUEST_ID]);
+
+    // リクエストログにリクエストIDを含める
+    let trace = TraceLayer::new_for_http().make_span_with(|req: &Request| {
+        let request_id = req
+            .extensions()
+            .get::<RequestId>()
+            .map(|id| id.0.as_str())
+            .unwrap_or("-");
+        info_span!("request", method = %req.method(), uri = %req.uri(), request_id)
+    });
+
+    // メディアの保存先（ローカルディスク、ディレクトリがなければ作成）
+    let storage = storage::LocalStorage::new(
+        config.media.storage_dir.clone(),
+        config.media.public_base_url.clone(),
+    )
+    .await?;
+    info!("🖼️ Media storage: {}", config.media.storage_dir.display());
 
-    // ルーター設定（データベースプールを渡す）
+    // 予約公開（公開予定の日時を過ぎた記事を定期的に公開する）
+    scheduler::spawn(pool.clone(), &config.scheduler);
+
+    // ルーター設定（データベースプール・認証設定・メディアの保存先を渡す）
+    // 💡 .layer() は後に書いたものほど外側になる（request_id が最初に実行される）
     let app = create_router()
-        .layer(TraceLayer::new_for_http())
+        .layer(axum::middleware::from_fn(middleware::content_language::content_language))
+        .layer(trace)
         .layer(cors)
-        .with_state(pool);
+        .layer(axum::middleware::from_fn(middleware::request_id::request_id))
+        .with_state(AppState {
+            pool,
+            auth: config.auth.clone(),
+            media: config.media.clone(),
+            storage: std::sync::Arc::new(storage),
+            markdown: markdown::MarkdownRenderer::default(),
+            site: config.site.clone(),
+        });
 
     // サーバー設定
-    let port = std::env::var("PORT")
-        .unwrap_or_else(|_| "8000".to_string())
-        .parse::<u16>()?;
-
-    let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
+    let (host, port) = (&config.server.host, config.server.port);
+    let listener = tokio::net::TcpListener::bind((host.as_str(), port)).await?;
 
-    info!("🚀 Server running on http://0.0.0.0:{}", port);
-    info!("📋 Health check: http://0.0.0.0:{}/health", port);
-    info!("👋 Hello endpoint: http://0.0.0.0:{}/api/v1/hello", port);
-    info!("🌐 Locales API: http://0.0.0.0:{}/api/v1/locales", port);
-    info!("📚 Swagger UI: http://0.0.0.0:{}/swagger-ui", port);
-    info!("📄 OpenAPI JSON: http://0.0.0.0:{}/api-docs/openapi.json", port);
+    info!("🚀 Server running on http://{}:{}", host, port);
+    info!("📋 Health check: http://{}:{}/health", host, port);
+    info!("🩺 Readiness: http://{}:{}/health/ready", host, port);
+    info!("👋 Hello endpoint: http://{}:{}/api/v1/hello", host, port);
+    info!("🌐 Locales API: http://{}:{}/api/v1/locales", host, port);
+    info!("📚 Swagger UI: http://{}:{}/swagger-ui", host, port);
+    info!("📄 OpenAPI JSON: http://{}:{}/api-docs/openapi.json", host, port);
 
     // サーバー起動
-    axum::serve(listener, app).await?;
+    // 💡 into_make_service_with_connect_info: 接続元のIPアドレスをハンドラーで使えるようにする
+    //   （セッションに記録する、extractors::ClientInfo）
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
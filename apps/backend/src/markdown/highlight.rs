@@ -0,0 +1,72 @@
+use std::sync::LazyLock;
+
+use syntect::{
+    html::{ClassStyle, ClassedHTMLGenerator},
+    parsing::SyntaxSet,
+    util::LinesWithEndings,
+};
+
+// ============================================
+// コードブロックの構文ハイライト（syntect）
+// ============================================
+//
+// 💡 色はインラインの style ではなく class で付ける（例: <span class="hl-keyword hl-control">）
+// - 配色（テーマ）はフロントエンドのCSSで決める
+// - サニタイズで style 属性を許可しなくて済む
+//
+// 💡 言語がわからない・指定がない場合はハイライトせずエスケープだけする
+
+/// syntect に含まれる言語定義（読み込みが重いので最初に使うときに1回だけ）
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+/// ハイライトの class に付ける接頭辞
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+
+// --------------------------------------------------------
+// code_block: ```lang のコードブロック → <pre><code> のHTML
+// --------------------------------------------------------
+pub fn code_block(code: &str, lang: &str) -> String {
+    // 💡 class に入れるので、言語名は英数字と +#-_ だけにする（```rust,ignore → rust）
+    let lang: String = lang
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || "+#-_".contains(*c))
+        .collect();
+
+    let body = highlight(code, &lang).unwrap_or_else(|| escape_html(code));
+
+    if lang.is_empty() {
+        format!("<pre class=\"code-block\"><code>{}</code></pre>\n", body)
+    } else {
+        format!(
+            "<pre class=\"code-block\"><code class=\"language-{}\">{}</code></pre>\n",
+            lang, body
+        )
+    }
+}
+
+fn highlight(code: &str, lang: &str) -> Option<String> {
+    if lang.is_empty() {
+        return None;
+    }
+    let syntax = SYNTAXES.find_syntax_by_token(lang)?;
+
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, &SYNTAXES, CLASS_STYLE);
+    for line in LinesWithEndings::from(code) {
+        generator.parse_html_for_line_which_includes_newline(line).ok()?;
+    }
+    Some(generator.finalize())
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
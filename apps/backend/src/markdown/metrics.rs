@@ -0,0 +1,185 @@
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+// ============================================
+// 読了目安・抜粋の計算
+// ============================================
+//
+// 💡 旧Post（archive/old-code）の reading_time_ja / excerpt_en などは手入力だった
+// → 翻訳を保存するたびに本文（Markdown）から計算する
+//
+// 💡 読む速さは言語で変える（locales.code の最初の部分で決める: "ja", "zh-TW" → "zh"）
+// | 言語   | 数え方 | 速さ         |
+// |--------|--------|--------------|
+// | ja     | 文字数 | 500文字/分   |
+// | zh     | 文字数 | 300文字/分   |
+// | その他 | 単語数 | 200単語/分   |
+
+/// 翻訳の本文から計算した値
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentMetrics {
+    /// 読了目安（分、最低1分）
+    pub reading_time_minutes: i32,
+    /// 抜粋（本文の最初の段落から。段落がなければ None）
+    pub excerpt: Option<String>,
+}
+
+/// 読む速さ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReadingSpeed {
+    /// 文字数で数える（単語の間に空白がない言語）
+    CharactersPerMinute(usize),
+    /// 単語数で数える
+    WordsPerMinute(usize),
+}
+
+impl ReadingSpeed {
+    fn for_locale(locale_code: &str) -> Self {
+        let language = locale_code
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "ja" => Self::CharactersPerMinute(500),
+            "zh" => Self::CharactersPerMinute(300),
+            _ => Self::WordsPerMinute(200),
+        }
+    }
+
+    /// 抜粋の最大文字数（文字で数える言語は1文字の情報量が多いので短め）
+    fn excerpt_chars(self) -> usize {
+        match self {
+            Self::CharactersPerMinute(_) => 120,
+            Self::WordsPerMinute(_) => 200,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// content_metrics: 本文 → 読了目安 + 抜粋
+// --------------------------------------------------------
+//
+// 💡 読了目安はコードブロック・見出しも含めたすべてのテキストで数える
+// 💡 抜粋は段落のテキストだけ（見出し・コード・生のHTMLは使わない）
+pub fn content_metrics(source: &str, locale_code: &str) -> ContentMetrics {
+    let speed = ReadingSpeed::for_locale(locale_code);
+    let (all_text, prose) = extract_text(source);
+
+    let (count, per_minute) = match speed {
+        ReadingSpeed::CharactersPerMinute(per_minute) => {
+            (all_text.chars().filter(|c| !c.is_whitespace()).count(), per_minute)
+        }
+        ReadingSpeed::WordsPerMinute(per_minute) => (all_text.split_whitespace().count(), per_minute),
+    };
+    let minutes = count.div_ceil(per_minute).max(1);
+
+    ContentMetrics {
+        reading_time_minutes: i32::try_from(minutes).unwrap_or(i32::MAX),
+        excerpt: excerpt(&prose, speed),
+    }
+}
+
+// --------------------------------------------------------
+// plain_text: Markdown の記号を除いたテキスト（全文検索・検索結果の抜粋用）
+// --------------------------------------------------------
+pub fn plain_text(source: &str) -> String {
+    let (all_text, _) = extract_text(source);
+    all_text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Markdown → (すべてのテキスト, 段落のテキスト)
+fn extract_text(source: &str) -> (String, String) {
+    let mut all_text = String::new();
+    let mut prose = String::new();
+    let mut paragraph_depth = 0usize;
+
+    for event in Parser::new(source) {
+        match event {
+            Event::Start(Tag::Paragraph) => paragraph_depth += 1,
+            Event::End(TagEnd::Paragraph) => {
+                paragraph_depth = paragraph_depth.saturating_sub(1);
+                all_text.push(' ');
+                prose.push(' ');
+            }
+            Event::Text(text) | Event::Code(text) => {
+                all_text.push_str(&text);
+                if paragraph_depth > 0 {
+                    prose.push_str(&text);
+                }
+            }
+            // 💡 **強調** やリンクの終わりでは区切らない（単語の途中のことがある）
+            Event::End(TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link | TagEnd::Image) => {}
+            Event::SoftBreak | Event::HardBreak | Event::End(_) => {
+                all_text.push(' ');
+                if paragraph_depth > 0 {
+                    prose.push(' ');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (all_text, prose)
+}
+
+/// 段落のテキストを空白1つにまとめ、長ければ切って … を付ける
+fn excerpt(prose: &str, speed: ReadingSpeed) -> Option<String> {
+    let text = prose.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+
+    let max_chars = speed.excerpt_chars();
+    if text.chars().count() <= max_chars {
+        return Some(text);
+    }
+
+    let mut cut: String = text.chars().take(max_chars).collect();
+    // 💡 単語で数える言語は、単語の途中で切らない
+    if let (ReadingSpeed::WordsPerMinute(_), Some(last_space)) = (speed, cut.rfind(' ')) {
+        cut.truncate(last_space);
+    }
+    Some(format!("{}…", cut.trim_end()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reading_speed_for_locale() {
+        assert_eq!(ReadingSpeed::for_locale("ja"), ReadingSpeed::CharactersPerMinute(500));
+        assert_eq!(ReadingSpeed::for_locale("zh-TW"), ReadingSpeed::CharactersPerMinute(300));
+        assert_eq!(ReadingSpeed::for_locale("en"), ReadingSpeed::WordsPerMinute(200));
+        assert_eq!(ReadingSpeed::for_locale("pt_BR"), ReadingSpeed::WordsPerMinute(200));
+    }
+
+    #[test]
+    fn test_reading_time() {
+        // 日本語: 1000文字 → 2分
+        let ja = "あ".repeat(1000);
+        assert_eq!(content_metrics(&ja, "ja").reading_time_minutes, 2);
+        // 英語: 401単語 → 3分（切り上げ）
+        let en = "word ".repeat(401);
+        assert_eq!(content_metrics(&en, "en").reading_time_minutes, 3);
+        // 短くても最低1分
+        assert_eq!(content_metrics("Hi", "en").reading_time_minutes, 1);
+    }
+
+    #[test]
+    fn test_excerpt() {
+        let source = "# Title\n\nFirst *paragraph*\nwith `code`.\n\n```rust\nfn main() {}\n```\n";
+        let metrics = content_metrics(source, "en");
+        assert_eq!(metrics.excerpt.as_deref(), Some("First paragraph with code."));
+
+        let long = "lorem ipsum ".repeat(30);
+        let excerpt = content_metrics(&long, "en").excerpt.unwrap();
+        assert!(excerpt.ends_with("ipsum…") || excerpt.ends_with("lorem…"));
+        assert!(excerpt.chars().count() <= 201);
+
+        let ja = "あ".repeat(200);
+        assert_eq!(content_metrics(&ja, "ja").excerpt.unwrap().chars().count(), 121);
+
+        assert_eq!(content_metrics("## Only heading", "en").excerpt, None);
+    }
+}
@@ -0,0 +1,113 @@
+// ============================================
+// Markdown Module（本文の Markdown → HTML 変換）
+// ============================================
+//
+// 💡 blog_post_translations.content は Markdown で保存し、APIで返すときに HTML にする
+// - 旧Post（archive/old-code）の content_format は持たない（本文は常に Markdown）
+//
+// 💡 変換の流れ（render.rs）:
+// 1. pulldown-cmark で Markdown をイベント列にする
+// 2. 見出しに id（アンカー）を付け、目次（toc）用に集める
+// 3. コードブロックを syntect で構文ハイライト（highlight.rs）
+// 4. HTML にして ammonia でサニタイズ（<script> や onclick などを取り除く）
+//
+// 💡 キャッシュ（MarkdownRenderer）:
+// - 本文の SHA-256 をキーに、変換結果をメモリに保持する（LRU、古いものから捨てる）
+// - 本文が変われば別のキーになるので、記事を更新しても古いHTMLが返ることはない
+// - 変換（特にハイライト）はCPUを使うので、spawn_blocking で別スレッドで行う
+//
+// 💡 読了目安・抜粋（metrics.rs）:
+// - 翻訳の保存時に計算して blog_post_translations に保存する（表示のたびには計算しない）
+
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+};
+
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+
+mod highlight;
+mod metrics;
+mod render;
+
+pub use metrics::{content_metrics, plain_text};
+pub use render::render;
+
+/// キャッシュする変換結果の数
+const CACHE_ENTRIES: usize = 1000;
+
+/// 変換結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMarkdown {
+    /// サニタイズ済みのHTML
+    pub html: String,
+    /// 目次（見出しの入れ子）
+    pub toc: Vec<TocEntry>,
+}
+
+// --------------------------------------------------------
+// TocEntry: 目次の1項目
+// --------------------------------------------------------
+//
+// 💡 children: この見出しより深いレベルの見出し（## の下の ### など）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[schema(no_recursion)]
+pub struct TocEntry {
+    /// 見出しのレベル（1〜6）
+    #[schema(example = 2)]
+    pub level: u8,
+    /// 見出しの id（HTML の #アンカー）
+    #[schema(example = "installation")]
+    pub id: String,
+    /// 見出しのテキスト
+    #[schema(example = "Installation")]
+    pub text: String,
+    /// 子の見出し
+    pub children: Vec<TocEntry>,
+}
+
+// --------------------------------------------------------
+// MarkdownRenderer: キャッシュ付きの変換（AppState に入れて共有する）
+// --------------------------------------------------------
+#[derive(Clone)]
+pub struct MarkdownRenderer {
+    cache: Arc<Mutex<LruCache<String, Arc<RenderedMarkdown>>>>,
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        let capacity = NonZeroUsize::new(CACHE_ENTRIES).expect("cache size is not zero");
+        Self {
+            cache: Arc::new(Mutex::new(LruCache::new(capacity))),
+        }
+    }
+}
+
+impl MarkdownRenderer {
+    /// Markdown を HTML と目次に変換する（同じ本文は2回目以降キャッシュから返す）
+    pub async fn render(&self, source: &str) -> anyhow::Result<Arc<RenderedMarkdown>> {
+        let key = content_hash(source);
+        if let Some(hit) = self.lock().get(&key) {
+            return Ok(hit.clone());
+        }
+
+        let owned = source.to_string();
+        let rendered = Arc::new(tokio::task::spawn_blocking(move || render(&owned)).await?);
+        self.lock().put(key, rendered.clone());
+
+        Ok(rendered)
+    }
+
+    // 💡 変換中にパニックしてロックが壊れても、キャッシュの中身は使える
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<String, Arc<RenderedMarkdown>>> {
+        self.cache.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// キャッシュのキー（本文の SHA-256、16進数）
+fn content_hash(source: &str) -> String {
+    format!("{:x}", Sha256::digest(source.as_bytes()))
+}
@@ -0,0 +1,225 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+use super::{highlight, RenderedMarkdown, TocEntry};
+
+// ============================================
+// Markdown → HTML の変換本体
+// ============================================
+
+/// 有効にする Markdown の拡張（表・取り消し線・脚注）
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES
+}
+
+// --------------------------------------------------------
+// render: Markdown → サニタイズ済みHTML + 目次
+// --------------------------------------------------------
+//
+// 💡 見出しとコードブロックだけイベントを差し替え、残りは pulldown-cmark に任せる
+// - 見出し: <h2 id="installation"><a class="heading-anchor" href="#installation">#</a>Installation</h2>
+// - コードブロック: highlight::code_block の <pre class="code-block"><code>...</code></pre>
+pub fn render(source: &str) -> RenderedMarkdown {
+    let mut parser = Parser::new_ext(source, options());
+    let mut events = Vec::new();
+    let mut headings = Vec::new();
+    let mut used_ids = HashSet::new();
+
+    while let Some(event) = parser.next() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                // 見出しの中身（強調やリンクを含む）を End まで集める
+                let mut inner = Vec::new();
+                let mut text = String::new();
+                for event in parser.by_ref() {
+                    match &event {
+                        Event::End(TagEnd::Heading(_)) => break,
+                        Event::Text(t) | Event::Code(t) => text.push_str(t),
+                        _ => {}
+                    }
+                    inner.push(event);
+                }
+
+                let text = text.trim().to_string();
+                let id = unique_id(slugify(&text), &mut used_ids);
+
+                events.push(Event::Start(Tag::Heading {
+                    level,
+                    id: Some(CowStr::from(id.clone())),
+                    classes: Vec::new(),
+                    attrs: Vec::new(),
+                }));
+                events.push(Event::InlineHtml(CowStr::from(format!(
+                    "<a class=\"heading-anchor\" href=\"#{}\" aria-hidden=\"true\">#</a>",
+                    id
+                ))));
+                events.extend(inner);
+                events.push(Event::End(TagEnd::Heading(level)));
+
+                headings.push((level as u8, id, text));
+            }
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let lang = match &kind {
+                    CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or(""),
+                    CodeBlockKind::Indented => "",
+                };
+                let mut code = String::new();
+                for event in parser.by_ref() {
+                    match event {
+                        Event::End(TagEnd::CodeBlock) => break,
+                        Event::Text(t) => code.push_str(&t),
+                        _ => {}
+                    }
+                }
+                events.push(Event::Html(CowStr::from(highlight::code_block(&code, lang))));
+            }
+            other => events.push(other),
+        }
+    }
+
+    let mut raw_html = String::new();
+    html::push_html(&mut raw_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: SANITIZER.clean(&raw_html).to_string(),
+        toc: build_toc(headings),
+    }
+}
+
+// --------------------------------------------------------
+// サニタイズ（ammonia）
+// --------------------------------------------------------
+//
+// 💡 本文に書かれた生のHTMLもここを通るので、<script>・onclick・javascript: は残らない
+// 💡 ammonia の既定（p, a, img, table など）に加えて、変換で付けた属性だけ許可する
+// - 見出しの id、ハイライトの class、脚注の id / class
+static SANITIZER: LazyLock<ammonia::Builder<'static>> = LazyLock::new(|| {
+    let mut builder = ammonia::Builder::default();
+    builder
+        .add_tags(["sup"])
+        .add_tag_attributes("a", ["class", "aria-hidden"])
+        .add_tag_attributes("pre", ["class"])
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("span", ["class"])
+        .add_tag_attributes("sup", ["class"])
+        .add_tag_attributes("div", ["class", "id"]);
+    for tag in ["h1", "h2", "h3", "h4", "h5", "h6"] {
+        builder.add_tag_attributes(tag, ["id"]);
+    }
+    builder
+});
+
+// --------------------------------------------------------
+// 見出しの id
+// --------------------------------------------------------
+//
+// 💡 小文字にし、文字・数字以外は - にまとめる（日本語の見出しはそのまま残る）
+//   "Getting Started!" → "getting-started"
+//   "はじめに"         → "はじめに"
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if (c.is_whitespace() || c == '-' || c == '_')
+            && !slug.is_empty()
+            && !slug.ends_with('-')
+        {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// 同じ id の見出しには -1, -2 ... を付ける
+fn unique_id(base: String, used: &mut HashSet<String>) -> String {
+    let mut id = base.clone();
+    let mut n = 1;
+    while used.contains(&id) {
+        id = format!("{}-{}", base, n);
+        n += 1;
+    }
+    used.insert(id.clone());
+    id
+}
+
+// --------------------------------------------------------
+// 目次: 見出しの並び → 入れ子
+// --------------------------------------------------------
+//
+// 💡 直前の見出しより深いレベルなら、その子にする
+//   ## A / ### B / ### C / ## D → [A [B, C], D]
+fn build_toc(headings: Vec<(u8, String, String)>) -> Vec<TocEntry> {
+    let mut toc = Vec::new();
+    for (level, id, text) in headings {
+        let mut siblings = &mut toc;
+        while siblings.last().is_some_and(|last: &TocEntry| last.level < level) {
+            siblings = &mut siblings.last_mut().expect("checked above").children;
+        }
+        siblings.push(TocEntry {
+            level,
+            id,
+            text,
+            children: Vec::new(),
+        });
+    }
+    toc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Getting Started!"), "getting-started");
+        assert_eq!(slugify("はじめに"), "はじめに");
+        assert_eq!(slugify("Rust と Axum"), "rust-と-axum");
+        assert_eq!(slugify("!!!"), "section");
+    }
+
+    #[test]
+    fn test_heading_ids_are_unique() {
+        let rendered = render("## Setup\n\n## Setup\n\n## Setup\n");
+        let ids: Vec<&str> = rendered.toc.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["setup", "setup-1", "setup-2"]);
+        assert!(rendered.html.contains("<h2 id=\"setup-1\">"));
+        assert!(rendered.html.contains("href=\"#setup-1\""));
+    }
+
+    #[test]
+    fn test_toc_nesting() {
+        let rendered = render("# Title\n## A\n### B\n### C\n## D\n");
+        assert_eq!(rendered.toc.len(), 1);
+        let title = &rendered.toc[0];
+        assert_eq!(title.children.len(), 2);
+        assert_eq!(title.children[0].text, "A");
+        assert_eq!(title.children[0].children.len(), 2);
+        assert_eq!(title.children[1].text, "D");
+    }
+
+    #[test]
+    fn test_sanitize() {
+        let rendered = render(
+            "<script>alert(1)</script>\n\n<a href=\"javascript:alert(1)\" onclick=\"x()\">link</a>\n",
+        );
+        assert!(!rendered.html.contains("<script"));
+        assert!(!rendered.html.contains("javascript:"));
+        assert!(!rendered.html.contains("onclick"));
+    }
+
+    #[test]
+    fn test_code_block() {
+        let rendered = render("```rust\nfn main() {}\n```\n\n```unknown\n<b>\n```\n");
+        assert!(rendered.html.contains("<code class=\"language-rust\">"));
+        assert!(rendered.html.contains("<span class=\"hl-"));
+        // 知らない言語はエスケープだけ
+        assert!(rendered.html.contains("&lt;b&gt;"));
+    }
+}
@@ -0,0 +1,113 @@
+// ============================================
+// Content-Language Middleware
+// ============================================
+// ResolvedLocale エクストラクターで決まった言語を
+// レスポンスの Content-Language ヘッダーに設定する
+//
+// 💡 流れ:
+// 1. 空の NegotiatedLocale をリクエストのextensionsに入れる
+// 2. ハンドラーが ResolvedLocale を使うと、決まった言語コードがここに書き込まれる
+// 3. ハンドラーは実際に返した翻訳の言語も書き込む（ResolvedLocale::served）
+//    → 翻訳がなく別の言語で代用した（is_fallback: true）ときは、要求と違う言語になる
+// 4. レスポンスを返すときに、返した翻訳の言語（なければ決まった言語）を Content-Language に付ける
+//    → 一覧で複数の言語が混ざるときは "ja, en" のように並べる
+//
+// 💡 なぜミドルウェアで付ける?
+// - エクストラクターはレスポンスを触れないため
+// - ハンドラーごとにヘッダーを付ける処理を書かなくてよい
+//
+// 💡 Vary: Accept-Language, Cookie
+// - 同じURLでも言語によって内容が変わることをキャッシュに伝える
+
+use std::sync::{Arc, Mutex, PoisonError};
+
+use axum::{
+    extract::Request,
+    http::{
+        header::{CONTENT_LANGUAGE, VARY},
+        HeaderValue,
+    },
+    middleware::Next,
+    response::Response,
+};
+
+/// ハンドラーで決まった言語コード・返した翻訳の言語コードの書き込み先
+#[derive(Debug, Clone, Default)]
+pub struct NegotiatedLocale(Arc<Mutex<LocaleRecord>>);
+
+#[derive(Debug, Default)]
+struct LocaleRecord {
+    /// ResolvedLocale で決まった言語（最初の1回だけ有効）
+    negotiated: Option<String>,
+    /// 実際に返した翻訳の言語（重複なし、記録した順）
+    served: Vec<String>,
+}
+
+impl NegotiatedLocale {
+    /// 決まった言語コードを記録する（最初の1回だけ有効）
+    pub fn set(&self, code: &str) {
+        let mut record = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if record.negotiated.is_none() {
+            record.negotiated = Some(code.to_string());
+        }
+    }
+
+    /// 実際に返した翻訳の言語コードを記録する
+    pub fn served(&self, code: &str) {
+        let mut record = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if !record.served.iter().any(|served| served == code) {
+            record.served.push(code.to_string());
+        }
+    }
+
+    /// Content-Language の値（返した翻訳の言語 → なければ決まった言語）
+    fn header_value(&self) -> Option<String> {
+        let record = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        if record.served.is_empty() {
+            record.negotiated.clone()
+        } else {
+            Some(record.served.join(", "))
+        }
+    }
+}
+
+/// Content-Language を付与するミドルウェア
+pub async fn content_language(mut req: Request, next: Next) -> Response {
+    let negotiated = NegotiatedLocale::default();
+    req.extensions_mut().insert(negotiated.clone());
+
+    let mut response = next.run(req).await;
+
+    if let Some(code) = negotiated.header_value()
+        && let Ok(value) = HeaderValue::from_str(&code)
+    {
+        let headers = response.headers_mut();
+        if !headers.contains_key(CONTENT_LANGUAGE) {
+            headers.insert(CONTENT_LANGUAGE, value);
+        }
+        headers.append(VARY, HeaderValue::from_static("Accept-Language, Cookie"));
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_value() {
+        let negotiated = NegotiatedLocale::default();
+        assert_eq!(negotiated.header_value(), None);
+
+        negotiated.set("en");
+        negotiated.set("ja");
+        assert_eq!(negotiated.header_value().as_deref(), Some("en"));
+
+        // 翻訳がなく ja で代用した → 返した言語を優先する
+        negotiated.served("ja");
+        negotiated.served("en");
+        negotiated.served("ja");
+        assert_eq!(negotiated.header_value().as_deref(), Some("ja, en"));
+    }
+}
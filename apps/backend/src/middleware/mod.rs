@@ -0,0 +1,13 @@
+// ============================================
+// Middleware Module（ミドルウェア）
+// ============================================
+//
+// 💡 ミドルウェアとは?
+// - すべてのリクエスト/レスポンスの前後に挟まる処理
+// - ハンドラーごとに書く必要がない共通処理を置く
+//
+// 💡 レイヤー構成（外側から）:
+// request_id → CORS → Trace → content_language → ハンドラー
+
+pub mod content_language;
+pub mod request_id;
@@ -0,0 +1,71 @@
+// ============================================
+// Request ID Middleware（リクエストID）
+// ============================================
+// 各リクエストに一意のIDを付け、ログとエラーレスポンスに含める
+//
+// 💡 流れ:
+// 1. X-Request-Id ヘッダーがあればその値を使う（ロードバランサー等が付けたID）
+// 2. なければUUID v4を新しく発行
+// 3. リクエストのextensionsとtask-localに保存
+// 4. レスポンスにも X-Request-Id ヘッダーを付ける
+//
+// 💡 task-localとは?
+// - 「このリクエストを処理している間だけ」参照できる変数
+// - AppError::into_response() はリクエストを受け取らないため、
+//   ここからリクエストIDを読む
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use uuid::Uuid;
+
+/// リクエストIDのヘッダー名
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// リクエストIDの最大長（不正に長いヘッダーはログを汚すので採用しない）
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// extensionsに保存するリクエストID
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// 現在処理中のリクエストIDを取得（ミドルウェアの外ではNone）
+pub fn current() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// リクエストIDを付与するミドルウェア
+pub async fn request_id(mut req: Request, next: Next) -> Response {
+    let id = req
+        .headers()
+        .get(&X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_acceptable(value))
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    req.extensions_mut().insert(RequestId(id.clone()));
+
+    let mut response = CURRENT_REQUEST_ID.scope(id.clone(), next.run(req)).await;
+
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(X_REQUEST_ID, value);
+    }
+
+    response
+}
+
+fn is_acceptable(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
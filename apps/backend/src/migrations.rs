@@ -0,0 +1,532 @@
+// ============================================
+// Migrations（埋め込みマイグレーション）
+// ============================================
+// migrations/*.sql をバイナリに埋め込み、起動時・CLIから適用する
+//
+// 💡 sqlx::migrate! とは?
+// - コンパイル時に migrations/ のSQLファイルを読み込んでバイナリに含める
+// - 本番環境にSQLファイルを配置しなくてよい
+// - 適用履歴は _sqlx_migrations テーブルに記録される（sqlx-cli と同じ形式）
+//
+// 💡 ファイル名の形式:
+// - 001_create_locales_table.up.sql   → 適用（migrate up）
+// - 001_create_locales_table.down.sql → 取り消し（migrate revert）
+//
+// 💡 起動時の動作:
+// - MIGRATE_ON_STARTUP=true  → 未適用のマイグレーションを適用してから起動
+// - それ以外                 → 未適用があれば起動を中止（スキーマが古いまま動かさない）
+//
+// 💡 手で SQL を流して作ったDB（_sqlx_migrations がない）:
+// - そのまま migrate up すると 001 の CREATE TABLE locales から流し直して失敗する
+// - backend migrate baseline で、適用済みのマイグレーションを _sqlx_migrations に記録してから migrate up する
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context};
+use sqlx::{
+    migrate::{Migrate, Migration, Migrator},
+    PgPool,
+};
+use tracing::{info, warn};
+
+/// バイナリに埋め込んだマイグレーション
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// _sqlx_migrations の1行
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: String,
+    pub success: bool,
+    pub checksum: Vec<u8>,
+}
+
+// --------------------------------------------------------
+// SchemaStatus: 埋め込みマイグレーションとDBの適用状況の比較
+// --------------------------------------------------------
+#[derive(Debug, Clone)]
+pub struct SchemaStatus {
+    applied: Vec<AppliedMigration>,
+}
+
+impl SchemaStatus {
+    /// DBから適用状況を読み込む（_sqlx_migrations がなければ「何も適用していない」）
+    pub async fn load(pool: &PgPool) -> Result<Self, sqlx::Error> {
+        let (exists,): (bool,) =
+            sqlx::query_as("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+                .fetch_one(pool)
+                .await?;
+
+        let applied = if exists {
+            sqlx::query_as::<_, AppliedMigration>(
+                "SELECT version, description, success, checksum FROM _sqlx_migrations ORDER BY version",
+            )
+            .fetch_all(pool)
+            .await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self { applied })
+    }
+
+    /// 適用済みの最新バージョン
+    pub fn current_version(&self) -> Option<i64> {
+        self.applied
+            .iter()
+            .filter(|m| m.success)
+            .map(|m| m.version)
+            .max()
+    }
+
+    /// このバイナリが想定する最新バージョン
+    pub fn expected_version() -> Option<i64> {
+        up_migrations().map(|m| m.version).max()
+    }
+
+    /// 未適用のマイグレーション
+    pub fn pending(&self) -> Vec<&'static Migration> {
+        let applied = self.applied_by_version();
+        up_migrations()
+            .filter(|m| !applied.get(&m.version).is_some_and(|a| a.success))
+            .collect()
+    }
+
+    /// 途中で失敗したマイグレーション（手動での修復が必要）
+    pub fn failed(&self) -> Vec<&AppliedMigration> {
+        self.applied.iter().filter(|m| !m.success).collect()
+    }
+
+    /// 適用後にSQLファイルが書き換えられたマイグレーション
+    pub fn checksum_mismatches(&self) -> Vec<i64> {
+        let applied = self.applied_by_version();
+        up_migrations()
+            .filter(|m| {
+                applied
+                    .get(&m.version)
+                    .is_some_and(|a| a.checksum.as_slice() != &*m.checksum)
+            })
+            .map(|m| m.version)
+            .collect()
+    }
+
+    /// DBには適用済みだが、このバイナリが知らないマイグレーション（新しいバイナリが適用した）
+    pub fn unknown(&self) -> Vec<&AppliedMigration> {
+        self.applied
+            .iter()
+            .filter(|a| !up_migrations().any(|m| m.version == a.version))
+            .collect()
+    }
+
+    /// スキーマがバイナリより古い（未適用 or 失敗がある）
+    pub fn is_behind(&self) -> bool {
+        !self.pending().is_empty() || !self.failed().is_empty()
+    }
+
+    fn applied_by_version(&self) -> HashMap<i64, &AppliedMigration> {
+        self.applied.iter().map(|m| (m.version, m)).collect()
+    }
+}
+
+fn up_migrations() -> impl Iterator<Item = &'static Migration> {
+    MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+}
+
+// ============================================
+// 起動時の処理
+// ============================================
+
+// --------------------------------------------------------
+// prepare_schema: 起動前にスキーマを確認（必要なら適用）
+// --------------------------------------------------------
+//
+// 💡 DBの方が新しい場合（新しいバイナリが先に適用した）は警告だけ出して起動する
+//   （ローリングデプロイ中は古いバイナリと新しいスキーマが共存するため）
+pub async fn prepare_schema(pool: &PgPool, run_on_startup: bool) -> anyhow::Result<()> {
+    if run_on_startup {
+        info!("🗄️ Applying pending migrations (MIGRATE_ON_STARTUP)...");
+        run_up(pool).await?;
+    }
+
+    let status = SchemaStatus::load(pool).await?;
+
+    for version in status.checksum_mismatches() {
+        warn!("⚠️ Migration {} was modified after it was applied", version);
+    }
+    for migration in status.unknown() {
+        warn!(
+            "⚠️ Database has migration {} ({}) that this binary does not know about",
+            migration.version, migration.description
+        );
+    }
+
+    if status.is_behind() {
+        ensure_recorded(pool, &status).await?;
+        bail!(
+            "database schema is behind this binary (applied: {}, expected: {}); \
+             run `backend migrate up` or set MIGRATE_ON_STARTUP=true",
+            format_version(status.current_version()),
+            format_version(SchemaStatus::expected_version()),
+        );
+    }
+
+    info!(
+        "✅ Database schema is up to date (version {})",
+        format_version(status.current_version())
+    );
+    Ok(())
+}
+
+// ============================================
+// CLI: backend migrate up | status | revert | baseline
+// ============================================
+
+/// 未適用のマイグレーションをすべて適用
+pub async fn run_up(pool: &PgPool) -> anyhow::Result<()> {
+    let status = SchemaStatus::load(pool).await?;
+    ensure_recorded(pool, &status).await?;
+
+    let pending = status.pending();
+    if pending.is_empty() {
+        info!("✅ No pending migrations");
+        return Ok(());
+    }
+
+    MIGRATOR
+        .run(pool)
+        .await
+        .context("failed to apply migrations")?;
+
+    for migration in pending {
+        info!("✅ Applied {} {}", migration.version, migration.description);
+    }
+    Ok(())
+}
+
+/// 適用状況を表示
+pub async fn print_status(pool: &PgPool) -> anyhow::Result<()> {
+    let status = SchemaStatus::load(pool).await?;
+    let applied = status.applied_by_version();
+    let mismatches = status.checksum_mismatches();
+
+    println!("{:<8} {:<10} DESCRIPTION", "VERSION", "STATUS");
+    for migration in up_migrations() {
+        let state = match applied.get(&migration.version) {
+            Some(a) if !a.success => "failed",
+            Some(_) if mismatches.contains(&migration.version) => "modified",
+            Some(_) => "applied",
+            None => "pending",
+        };
+        println!("{:<8} {:<10} {}", migration.version, state, migration.description);
+    }
+    for migration in status.unknown() {
+        println!("{:<8} {:<10} {}", migration.version, "unknown", migration.description);
+    }
+
+    println!();
+    println!(
+        "current: {}, expected: {}",
+        format_version(status.current_version()),
+        format_version(SchemaStatus::expected_version())
+    );
+    Ok(())
+}
+
+// --------------------------------------------------------
+// revert: マイグレーションを取り消す
+// --------------------------------------------------------
+//
+// 💡 target を省略した場合は最新の1つだけ取り消す
+// 💡 target を指定した場合は target より新しいものをすべて取り消す（target は残る）
+pub async fn revert(pool: &PgPool, target: Option<i64>) -> anyhow::Result<()> {
+    let status = SchemaStatus::load(pool).await?;
+    let Some(current) = status.current_version() else {
+        info!("✅ Nothing to revert");
+        return Ok(());
+    };
+
+    let target = match target {
+        Some(target) if target >= current => {
+            bail!("target {} is not older than the current version {}", target, current)
+        }
+        Some(target) => target,
+        None => status
+            .applied
+            .iter()
+            .filter(|m| m.success && m.version < current)
+            .map(|m| m.version)
+            .max()
+            .unwrap_or(0),
+    };
+
+    MIGRATOR
+        .undo(pool, target)
+        .await
+        .context("failed to revert migrations")?;
+
+    info!("✅ Reverted to version {}", target);
+    Ok(())
+}
+
+// ============================================
+// baseline: 手で適用したスキーマを _sqlx_migrations に記録する
+// ============================================
+//
+// 💡 使い方:
+//   backend migrate baseline             → 存在するテーブル・列から適用済みのバージョンを判定して記録
+//   backend migrate baseline --version 2 → 001〜002 を適用済みとして記録（テーブル・初期データがあるか確認する）
+//
+// 💡 記録するのは埋め込みマイグレーションと同じ version / description / checksum
+//   → その後の migrate up は続きのバージョンから適用する
+// 💡 _sqlx_migrations にすでに行があるDBでは何もしない（sqlx で管理しているDB）
+
+/// 適用済みなら存在するはずのもの
+#[derive(Debug, Clone, Copy)]
+enum Marker {
+    /// テーブル・インデックス（to_regclass）
+    Relation(&'static str),
+    /// 列（テーブル, 列）
+    Column(&'static str, &'static str),
+    /// 初期データの言語（locales.code）
+    Locale(&'static str),
+    /// 関数の本体に含まれる文字列（関数名, 文字列。CREATE OR REPLACE FUNCTION だけのマイグレーション用）
+    FunctionSource(&'static str, &'static str),
+}
+
+/// マイグレーションごとの Marker（新しいマイグレーションを追加したらここにも追加する）
+const BASELINE_MARKERS: &[(i64, &[Marker])] = &[
+    (1, &[Marker::Relation("locales"), Marker::Relation("idx_locales_code")]),
+    (2, &[Marker::Locale("ja"), Marker::Locale("en")]),
+    (3, &[Marker::Relation("idx_locales_single_default")]),
+    (4, &[Marker::Relation("topics"), Marker::Relation("topic_translations")]),
+    (
+        5,
+        &[
+            Marker::Relation("blog_posts"),
+            Marker::Relation("blog_post_translations"),
+            Marker::Relation("blog_post_topics"),
+        ],
+    ),
+    (6, &[Marker::Relation("idx_blog_posts_published_keyset")]),
+    (7, &[Marker::Relation("administrators"), Marker::Relation("sessions")]),
+    (8, &[Marker::Relation("api_keys")]),
+    (9, &[Marker::Relation("audit_logs")]),
+    (
+        10,
+        &[
+            Marker::Relation("books"),
+            Marker::Relation("book_translations"),
+            Marker::Relation("book_chapters"),
+            Marker::Relation("book_chapter_translations"),
+        ],
+    ),
+    (
+        11,
+        &[
+            Marker::Relation("media"),
+            Marker::Relation("media_translations"),
+            Marker::Column("blog_posts", "featured_image_id"),
+        ],
+    ),
+    (
+        12,
+        &[
+            Marker::Column("blog_post_translations", "reading_time_minutes"),
+            Marker::Column("blog_post_translations", "excerpt"),
+        ],
+    ),
+    (13, &[Marker::Column("blog_post_translations", "search_vector")]),
+    (14, &[Marker::Column("blog_posts", "scheduled_at")]),
+    (15, &[Marker::Relation("blog_post_translation_revisions")]),
+    (16, &[Marker::FunctionSource("forbid_revision_content_update", "blog.migrating_locale")]),
+];
+
+impl Marker {
+    async fn exists(self, pool: &PgPool) -> Result<bool, sqlx::Error> {
+        let (exists,): (bool,) = match self {
+            Self::Relation(name) => {
+                sqlx::query_as("SELECT to_regclass($1) IS NOT NULL")
+                    .bind(name)
+                    .fetch_one(pool)
+                    .await?
+            }
+            Self::Column(table, column) => {
+                sqlx::query_as(
+                    r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM information_schema.columns
+                        WHERE table_schema = current_schema() AND table_name = $1 AND column_name = $2
+                    )
+                    "#,
+                )
+                .bind(table)
+                .bind(column)
+                .fetch_one(pool)
+                .await?
+            }
+            // 💡 001 の確認が通った後にだけ呼ぶ（locales がないとエラーになる）
+            Self::Locale(code) => {
+                sqlx::query_as("SELECT EXISTS (SELECT 1 FROM locales WHERE code = $1)")
+                    .bind(code)
+                    .fetch_one(pool)
+                    .await?
+            }
+            Self::FunctionSource(name, fragment) => {
+                sqlx::query_as(
+                    r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM pg_proc
+                        WHERE proname = $1 AND pronamespace = current_schema()::regnamespace
+                          AND strpos(prosrc, $2) > 0
+                    )
+                    "#,
+                )
+                .bind(name)
+                .bind(fragment)
+                .fetch_one(pool)
+                .await?
+            }
+        };
+        Ok(exists)
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Self::Relation(name) => name.to_string(),
+            Self::Column(table, column) => format!("{}.{}", table, column),
+            Self::Locale(code) => format!("locales row '{}'", code),
+            Self::FunctionSource(name, fragment) => format!("{}() using '{}'", name, fragment),
+        }
+    }
+}
+
+/// 判定の結果
+#[derive(Debug, Clone, Default)]
+struct DetectedSchema {
+    /// Marker がすべてそろっている最新のバージョン（1から連続して）
+    version: Option<i64>,
+    /// その次のバージョンと、足りないもの
+    missing: Option<(i64, Vec<String>)>,
+}
+
+/// 存在するテーブル・列から、手で適用済みのバージョンを判定する
+async fn detect_schema(pool: &PgPool) -> Result<DetectedSchema, sqlx::Error> {
+    let mut detected = DetectedSchema::default();
+
+    for (version, markers) in BASELINE_MARKERS {
+        let mut missing = Vec::new();
+        for marker in *markers {
+            if !marker.exists(pool).await? {
+                missing.push(marker.describe());
+            }
+        }
+        if !missing.is_empty() {
+            detected.missing = Some((*version, missing));
+            break;
+        }
+        detected.version = Some(*version);
+    }
+
+    Ok(detected)
+}
+
+/// _sqlx_migrations がないのにテーブルがある → migrate up の前に baseline が必要
+async fn ensure_recorded(pool: &PgPool, status: &SchemaStatus) -> anyhow::Result<()> {
+    if !status.applied.is_empty() {
+        return Ok(());
+    }
+    if let Some(version) = detect_schema(pool).await?.version {
+        bail!(
+            "database has tables but no migration history (looks like version {} was applied by hand); \
+             run `backend migrate baseline` first",
+            version
+        );
+    }
+    Ok(())
+}
+
+/// 手で適用したマイグレーションを _sqlx_migrations に記録する
+pub async fn baseline(pool: &PgPool, version: Option<i64>) -> anyhow::Result<()> {
+    let status = SchemaStatus::load(pool).await?;
+    if !status.applied.is_empty() {
+        bail!(
+            "migration history already exists (current: {}); baseline is only for databases set up by hand",
+            format_version(status.current_version())
+        );
+    }
+
+    let detected = detect_schema(pool).await?;
+    let describe_missing = || {
+        detected
+            .missing
+            .as_ref()
+            .map(|(version, missing)| format!("migration {} is missing {}", version, missing.join(", ")))
+            .unwrap_or_default()
+    };
+
+    let target = match version {
+        Some(version) => {
+            if !up_migrations().any(|m| m.version == version) {
+                bail!("unknown migration version {}", version);
+            }
+            if detected.version.is_none_or(|detected| detected < version) {
+                bail!("database does not match version {}: {}", version, describe_missing());
+            }
+            version
+        }
+        None => match detected.version {
+            Some(version) => version,
+            None => bail!(
+                "no hand-applied schema found ({}); run `backend migrate up` instead",
+                describe_missing()
+            ),
+        },
+    };
+
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table()
+        .await
+        .context("failed to create _sqlx_migrations")?;
+    drop(conn);
+
+    let mut tx = pool.begin().await?;
+    for migration in up_migrations().filter(|m| m.version <= target) {
+        sqlx::query(
+            r#"
+            INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time)
+            VALUES ($1, $2, TRUE, $3, 0)
+            "#,
+        )
+        .bind(migration.version)
+        .bind(&*migration.description)
+        .bind(&*migration.checksum)
+        .execute(&mut *tx)
+        .await?;
+        info!("✅ Recorded {} {} as applied", migration.version, migration.description);
+    }
+    tx.commit().await?;
+
+    info!(
+        "✅ Baselined at version {}; run `backend migrate up` to apply the rest",
+        target
+    );
+    Ok(())
+}
+
+fn format_version(version: Option<i64>) -> String {
+    version.map_or_else(|| "none".to_string(), |v| v.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline_markers_cover_every_migration() {
+        let marked: Vec<i64> = BASELINE_MARKERS.iter().map(|(version, _)| *version).collect();
+        let embedded: Vec<i64> = up_migrations().map(|m| m.version).collect();
+        assert_eq!(marked, embedded);
+    }
+}
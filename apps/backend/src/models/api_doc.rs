@@ -1,4 +1,7 @@
-use utoipa::OpenApi;
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
 
 // OpenAPI定義のみ - スキーマは各ハンドラで自動生成
 
@@ -7,26 +10,175 @@ use utoipa::OpenApi;
 #[openapi(
     paths(
         crate::handlers::health::health_check,
+        crate::handlers::health::liveness,
+        crate::handlers::health::readiness,
         crate::handlers::greeting::hello_rust,
         crate::handlers::greeting::custom_hello,
+        crate::handlers::auth::login,
+        crate::handlers::auth::logout,
+        crate::handlers::auth::me,
+        crate::handlers::admins::create_admin,
+        crate::handlers::api_keys::list_api_keys,
+        crate::handlers::api_keys::create_api_key,
+        crate::handlers::api_keys::revoke_api_key,
+        crate::handlers::audit_logs::list_audit_logs,
         crate::handlers::locales::list_locales,
         crate::handlers::locales::list_active_locales,
-        crate::handlers::locales::get_locale_by_code
+        crate::handlers::locales::get_locale_by_code,
+        crate::handlers::locales::create_locale,
+        crate::handlers::locales::update_locale,
+        crate::handlers::locales::set_default_locale,
+        crate::handlers::locales::get_locale_impact,
+        crate::handlers::locales::deactivate_locale,
+        crate::handlers::locales::delete_locale,
+        crate::handlers::topics::list_topics,
+        crate::handlers::topics::get_topic,
+        crate::handlers::topics::create_topic,
+        crate::handlers::topics::update_topic,
+        crate::handlers::posts::list_posts,
+        crate::handlers::posts::get_post,
+        crate::handlers::posts::create_post,
+        crate::handlers::posts::update_post,
+        crate::handlers::posts::list_scheduled_posts,
+        crate::handlers::translation_coverage::get_translation_coverage,
+        crate::handlers::post_revisions::list_revisions,
+        crate::handlers::post_revisions::get_revision,
+        crate::handlers::post_revisions::diff_revisions,
+        crate::handlers::post_revisions::restore_revision,
+        crate::handlers::books::list_books,
+        crate::handlers::books::get_book,
+        crate::handlers::books::create_book,
+        crate::handlers::books::update_book,
+        crate::handlers::books::create_chapter,
+        crate::handlers::books::update_chapter,
+        crate::handlers::books::move_chapter,
+        crate::handlers::books::list_chapter_posts,
+        crate::handlers::media::list_media,
+        crate::handlers::media::get_media,
+        crate::handlers::media::upload_media,
+        crate::handlers::media::update_media,
+        crate::handlers::media::delete_media,
+        crate::handlers::media::serve_media,
+        crate::handlers::search::search_posts,
+        crate::handlers::feeds::locale_rss,
+        crate::handlers::feeds::locale_atom,
+        crate::handlers::feeds::topic_rss,
+        crate::handlers::feeds::topic_atom,
+        crate::handlers::sitemap::get_sitemap,
+        crate::handlers::sitemap::get_sitemap_part
     ),
     components(schemas(
+        crate::error::ErrorResponse,
+        crate::pagination::PageMeta,
+        crate::pagination::SortDirection,
         crate::models::HealthResponse,
+        crate::models::ComponentStatus,
+        crate::models::ReadinessResponse,
+        crate::models::ReadinessComponents,
+        crate::models::DatabaseHealth,
+        crate::models::PoolHealth,
+        crate::models::MigrationHealth,
         crate::models::GreetingResponse,
         crate::models::GreetingMeta,
         crate::models::CustomGreetingMeta,
         crate::models::GreetingQuery,
+        crate::entities::Role,
+        crate::handlers::auth::LoginRequest,
+        crate::handlers::auth::LoginResponse,
+        crate::handlers::auth::AdminResponse,
+        crate::handlers::admins::CreateAdminRequest,
+        crate::entities::ApiScope,
+        crate::handlers::api_keys::CreateApiKeyRequest,
+        crate::handlers::api_keys::ApiKeyResponse,
+        crate::handlers::api_keys::CreatedApiKeyResponse,
+        crate::handlers::api_keys::ApiKeysListResponse,
+        crate::entities::AuditTable,
+        crate::entities::AuditAction,
+        crate::entities::ChangeSource,
+        crate::entities::TranslatableEntity,
+        crate::entities::LocaleReferenceKind,
+        crate::handlers::translation_coverage::TranslationStatus,
+        crate::handlers::translation_coverage::LocaleCoverage,
+        crate::handlers::translation_coverage::LocaleTranslationStatus,
+        crate::handlers::translation_coverage::EntityCoverage,
+        crate::handlers::translation_coverage::TranslationCoverageResponse,
+        crate::handlers::audit_logs::AuditLogResponse,
+        crate::handlers::audit_logs::AuditLogsListResponse,
         crate::handlers::locales::LocaleResponse,
-        crate::handlers::locales::LocalesListResponse
+        crate::handlers::locales::LocalesListResponse,
+        crate::handlers::locales::CreateLocaleRequest,
+        crate::handlers::locales::LocaleImpactResponse,
+        crate::handlers::locales::LocaleReferenceResponse,
+        crate::handlers::locales::DeactivateLocaleRequest,
+        crate::handlers::locales::DeactivateLocaleResponse,
+        crate::handlers::locales::UpdateLocaleRequest,
+        crate::handlers::topics::TopicResponse,
+        crate::handlers::topics::TopicTranslationResponse,
+        crate::handlers::topics::TopicDetailResponse,
+        crate::handlers::topics::TopicsListResponse,
+        crate::handlers::topics::TopicTranslationRequest,
+        crate::handlers::topics::CreateTopicRequest,
+        crate::handlers::topics::UpdateTopicRequest,
+        crate::handlers::posts::PostListItem,
+        crate::handlers::posts::PostsListResponse,
+        crate::handlers::posts::PostDetailResponse,
+        crate::markdown::TocEntry,
+        crate::handlers::posts::PostTranslationRequest,
+        crate::handlers::posts::CreatePostRequest,
+        crate::handlers::posts::UpdatePostRequest,
+        crate::handlers::posts::ScheduledPostItem,
+        crate::handlers::posts::ScheduledPostsListResponse,
+        crate::handlers::post_revisions::RevisionListItem,
+        crate::handlers::post_revisions::RevisionsListResponse,
+        crate::handlers::post_revisions::RevisionResponse,
+        crate::handlers::post_revisions::FieldChange,
+        crate::handlers::post_revisions::RevisionDiffResponse,
+        crate::diff::TextChanges,
+        crate::diff::DiffHunk,
+        crate::diff::DiffLine,
+        crate::diff::DiffOp,
+        crate::handlers::books::BookResponse,
+        crate::handlers::books::BookTranslationResponse,
+        crate::handlers::books::ChapterNodeResponse,
+        crate::handlers::books::BookDetailResponse,
+        crate::handlers::books::BooksListResponse,
+        crate::handlers::books::ChapterTranslationResponse,
+        crate::handlers::books::ChapterDetailResponse,
+        crate::handlers::books::BookTranslationRequest,
+        crate::handlers::books::CreateBookRequest,
+        crate::handlers::books::UpdateBookRequest,
+        crate::handlers::books::ChapterTranslationRequest,
+        crate::handlers::books::CreateChapterRequest,
+        crate::handlers::books::UpdateChapterRequest,
+        crate::handlers::books::MoveChapterRequest,
+        crate::handlers::media::MediaResponse,
+        crate::handlers::media::MediaTranslationResponse,
+        crate::handlers::media::MediaReferenceResponse,
+        crate::handlers::media::MediaDetailResponse,
+        crate::handlers::media::MediaListResponse,
+        crate::handlers::media::MediaTranslationRequest,
+        crate::handlers::media::UploadMediaForm,
+        crate::handlers::media::UpdateMediaRequest,
+        crate::handlers::search::SearchResultItem,
+        crate::handlers::search::SearchResponse,
+        crate::entities::MediaUsage
     )),
     tags(
         (name = "health", description = "ヘルスチェック関連API"),
         (name = "greeting", description = "挨拶関連API"),
-        (name = "locales", description = "言語情報関連API")
+        (name = "auth", description = "ログイン・ログアウト"),
+        (name = "admins", description = "管理者アカウント関連API"),
+        (name = "api-keys", description = "APIキー（機械クライアント用）関連API"),
+        (name = "audit-logs", description = "監査ログ（変更履歴）関連API"),
+        (name = "locales", description = "言語情報関連API"),
+        (name = "topics", description = "技術トピック関連API"),
+        (name = "posts", description = "ブログ記事関連API"),
+        (name = "books", description = "本・チャプター関連API"),
+        (name = "media", description = "メディア（画像・ファイル）関連API"),
+        (name = "search", description = "記事の全文検索API"),
+        (name = "feeds", description = "RSS 2.0 / Atom フィード・XML サイトマップ")
     ),
+    modifiers(&SecurityAddon),
     info(
         title = "Blog Backend API",
         description = "Rust製テックブログバックエンドAPI",
@@ -42,3 +194,48 @@ use utoipa::OpenApi;
     )
 )]
 pub struct ApiDoc;
+
+// --------------------------------------------------------
+// SecurityAddon: 認証方式をOpenAPIに登録
+// --------------------------------------------------------
+//
+// 💡 各ハンドラーの security(("bearer_auth" = ["admin"]), ...) から参照される
+// - bearer_auth    : Authorization: Bearer <token>
+// - session_cookie : ログイン時に設定される session Cookie
+// - api_key        : X-API-Key: blog_...（Authorization: Bearer blog_... でもよい）
+// - [] の中は呼び出せる役割・APIキーのスコープ
+//   （OpenAPI 3.1 では OAuth 以外の方式にも役割名を書ける）
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some(
+                        "POST /api/v1/auth/login で発行したセッショントークン。\
+                         各APIの security に書かれた役割（admin / editor / author）が必要です",
+                    ))
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                "X-API-Key",
+                "POST /api/v1/api-keys で発行したAPIキー（Authorization: Bearer でも送れます）。\
+                 各APIの security に書かれたスコープ（read / write / delete）が必要です",
+            ))),
+        );
+        components.add_security_scheme(
+            "session_cookie",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::with_description(
+                "session",
+                "ログイン時に設定される HttpOnly Cookie",
+            ))),
+        );
+    }
+}
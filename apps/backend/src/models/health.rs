@@ -28,3 +28,93 @@ impl HealthResponse {
         }
     }
 }
+
+// ============================================
+// Readiness（依存先まで確認するヘルスチェック）
+// ============================================
+
+/// 各コンポーネントの状態
+///
+/// 💡 up: 正常 / degraded: 動くが注意が必要 / down: リクエストを処理できない
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    Up,
+    Degraded,
+    Down,
+}
+
+/// レディネスチェックのレスポンス
+#[derive(Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    /// 全体の状態（一番悪いコンポーネントの状態）
+    #[schema(example = "up")]
+    pub status: ComponentStatus,
+    /// チェック実行時刻
+    #[schema(example = "2026-01-12T12:00:00Z")]
+    pub timestamp: String,
+    /// アプリケーションバージョン
+    #[schema(example = "0.1.0")]
+    pub version: String,
+    /// コンポーネントごとの結果
+    pub components: ReadinessComponents,
+}
+
+/// コンポーネントごとの結果
+#[derive(Serialize, ToSchema)]
+pub struct ReadinessComponents {
+    pub database: DatabaseHealth,
+    pub pool: PoolHealth,
+    pub migrations: MigrationHealth,
+}
+
+/// データベース接続（SELECT 1 の往復時間）
+#[derive(Serialize, ToSchema)]
+pub struct DatabaseHealth {
+    pub status: ComponentStatus,
+    /// 応答時間（ミリ秒）。接続できなかった場合は null
+    #[schema(example = 1.8)]
+    pub latency_ms: Option<f64>,
+    /// 状態の補足
+    pub message: Option<String>,
+}
+
+/// 接続プールの使用状況
+#[derive(Serialize, ToSchema)]
+pub struct PoolHealth {
+    pub status: ComponentStatus,
+    /// 現在開いている接続数
+    #[schema(example = 3)]
+    pub size: u32,
+    /// 空いている接続数
+    #[schema(example = 2)]
+    pub idle: u32,
+    /// 使用中の接続数
+    #[schema(example = 1)]
+    pub in_use: u32,
+    /// 最大接続数
+    #[schema(example = 10)]
+    pub max_connections: u32,
+    /// 使用率（in_use / max_connections）
+    #[schema(example = 0.1)]
+    pub saturation: f64,
+    /// 状態の補足
+    pub message: Option<String>,
+}
+
+/// マイグレーションの適用状況
+#[derive(Serialize, ToSchema)]
+pub struct MigrationHealth {
+    pub status: ComponentStatus,
+    /// DBに適用済みの最新バージョン
+    #[schema(example = 5)]
+    pub current_version: Option<i64>,
+    /// このバイナリが想定する最新バージョン
+    #[schema(example = 5)]
+    pub expected_version: Option<i64>,
+    /// 未適用のマイグレーション数
+    #[schema(example = 0)]
+    pub pending: usize,
+    /// 状態の補足
+    pub message: Option<String>,
+}
@@ -0,0 +1,597 @@
+// ============================================
+// Pagination（ページネーション・並び替え）
+// ============================================
+// 一覧APIで共通のクエリパラメータとレスポンスのメタ情報
+//
+// 💡 2種類のページネーション:
+// - オフセット方式: ?page=2&per_page=20
+//   → 好きなページに飛べる。件数が多いと後ろのページほど遅い（OFFSETは読み飛ばすだけ）
+// - キーセット方式: ?cursor=<前のレスポンスの next_cursor>
+//   → 「最後に見た行より後」をWHEREで絞るので、何ページ目でも速い
+//   → 記事の公開日時順（sort=published_at）だけ対応
+//
+// 💡 並び替え: ?sort=created_at&order=asc
+// - 指定できる項目は一覧ごとに SortKey で決める（SQLに文字列を直接埋め込まない）
+//
+// 💡 レスポンス:
+// - ボディ: total（条件に一致する総数）, page, per_page, total_pages, next_cursor
+// - ヘッダー: Link（RFC 8288）
+//   Link: </api/v1/posts?per_page=20&page=3>; rel="next", </api/v1/posts?per_page=20&page=1>; rel="first"
+//
+// 💡 使用例（ハンドラー）:
+//   let params = list.resolve::<PostSort>()?;
+//   let page = repo.find_page_localized(..., &params).await?;
+//   let headers = page.link_headers(&uri);
+
+use std::fmt;
+
+use axum::http::{header::LINK, HeaderMap, HeaderValue, Uri};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{AppError, AppResult};
+
+/// 1ページの件数（省略時）
+pub const DEFAULT_PER_PAGE: u32 = 20;
+
+/// 1ページの件数の上限
+pub const MAX_PER_PAGE: u32 = 100;
+
+// ============================================
+// リクエスト
+// ============================================
+
+/// 一覧のクエリパラメータ（ページ・並び替え）
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListQuery {
+    /// ページ番号（1始まり）。cursor と同時には指定できない
+    #[param(minimum = 1, example = 1)]
+    pub page: Option<u32>,
+    /// 1ページの件数（1〜100、省略時: 20）
+    #[param(minimum = 1, maximum = 100, example = 20)]
+    pub per_page: Option<u32>,
+    /// 前のレスポンスの next_cursor（キーセット方式に対応した一覧のみ）
+    pub cursor: Option<String>,
+    /// 並び替えの項目（一覧ごとに指定できる値が異なる）
+    pub sort: Option<String>,
+    /// 並び順（省略時は項目ごとのデフォルト）
+    pub order: Option<SortDirection>,
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+
+    /// キーセット方式で「この位置より後」を表す比較演算子
+    pub fn after_operator(self) -> &'static str {
+        match self {
+            Self::Asc => ">",
+            Self::Desc => "<",
+        }
+    }
+}
+
+impl fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        })
+    }
+}
+
+// --------------------------------------------------------
+// SortKey: 一覧ごとの並び替え項目
+// --------------------------------------------------------
+//
+// 💡 実装例:
+//   enum TopicSort { Slug, CreatedAt }
+//   const FIELDS = &[("slug", TopicSort::Slug), ("created_at", TopicSort::CreatedAt)];
+//
+// 💡 column() はSQLにそのまま埋め込むので、固定の文字列だけを返すこと
+pub trait SortKey: Copy + 'static {
+    /// クエリパラメータの値 → 項目
+    const FIELDS: &'static [(&'static str, Self)];
+    /// sort 省略時の項目
+    const DEFAULT: Self;
+
+    /// ORDER BY に使うSQL式
+    fn column(self) -> &'static str;
+
+    /// order 省略時の並び順
+    fn default_direction(self) -> SortDirection {
+        SortDirection::Asc
+    }
+
+    /// キーセット方式（cursor）に対応しているか
+    fn supports_cursor(self) -> bool {
+        false
+    }
+}
+
+/// ページの指定方法
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageMode {
+    /// オフセット方式（page は1始まり）
+    Offset { page: u32 },
+    /// キーセット方式（None は最初のページ）
+    Cursor(Option<Cursor>),
+}
+
+/// 検証済みの一覧パラメータ（リポジトリに渡す）
+#[derive(Debug, Clone)]
+pub struct ListParams<S> {
+    pub sort: S,
+    pub direction: SortDirection,
+    pub per_page: u32,
+    pub mode: PageMode,
+}
+
+impl ListQuery {
+    // --------------------------------------------------------
+    // resolve: クエリパラメータを検証して ListParams にする
+    // --------------------------------------------------------
+    //
+    // 💡 ページの決まり方:
+    // - page を指定            → オフセット方式
+    // - cursor を指定          → キーセット方式（並び替え項目が対応している場合のみ）
+    // - どちらも省略            → 項目がキーセット方式に対応していればキーセット、それ以外は1ページ目
+    pub fn resolve<S: SortKey>(&self) -> AppResult<ListParams<S>> {
+        let per_page = self.per_page.unwrap_or(DEFAULT_PER_PAGE);
+        if !(1..=MAX_PER_PAGE).contains(&per_page) {
+            return Err(AppError::BadRequest(format!(
+                "per_page must be between 1 and {}",
+                MAX_PER_PAGE
+            )));
+        }
+
+        let sort = match self.sort.as_deref() {
+            None => S::DEFAULT,
+            Some(name) => S::FIELDS
+                .iter()
+                .find(|(field, _)| *field == name)
+                .map(|(_, key)| *key)
+                .ok_or_else(|| {
+                    let allowed: Vec<&str> = S::FIELDS.iter().map(|(field, _)| *field).collect();
+                    AppError::BadRequest(format!(
+                        "Unknown sort field '{}' (allowed: {})",
+                        name,
+                        allowed.join(", ")
+                    ))
+                })?,
+        };
+        let direction = self.order.unwrap_or_else(|| sort.default_direction());
+
+        let mode = match (self.page, self.cursor.as_deref()) {
+            (Some(_), Some(_)) => {
+                return Err(AppError::BadRequest(
+                    "page and cursor cannot be used together".to_string(),
+                ));
+            }
+            (Some(0), None) => {
+                return Err(AppError::BadRequest("page must be 1 or greater".to_string()));
+            }
+            (Some(page), None) => PageMode::Offset { page },
+            (None, Some(_)) if !sort.supports_cursor() => {
+                return Err(AppError::BadRequest(
+                    "cursor is not supported for this sort field; use page instead".to_string(),
+                ));
+            }
+            (None, Some(token)) => {
+                let cursor = Cursor::decode(token)?;
+                if cursor.direction != direction {
+                    return Err(AppError::BadRequest(
+                        "cursor was issued for a different sort order".to_string(),
+                    ));
+                }
+                PageMode::Cursor(Some(cursor))
+            }
+            (None, None) if sort.supports_cursor() => PageMode::Cursor(None),
+            (None, None) => PageMode::Offset { page: 1 },
+        };
+
+        Ok(ListParams {
+            sort,
+            direction,
+            per_page,
+            mode,
+        })
+    }
+}
+
+impl<S: SortKey> ListParams<S> {
+    /// ORDER BY 句（同じ値の行の順序を固定するため、最後に id 列を付ける）
+    pub fn order_by(&self, tiebreaker: &str) -> String {
+        let direction = self.direction.as_sql();
+        format!(
+            "ORDER BY {} {direction}, {tiebreaker} {direction}",
+            self.sort.column()
+        )
+    }
+
+    /// LIMIT に渡す件数（次のページがあるか判定するため1件多く取る）
+    pub fn fetch_limit(&self) -> i64 {
+        i64::from(self.per_page) + 1
+    }
+
+    /// OFFSET に渡す件数（キーセット方式では0）
+    pub fn offset(&self) -> i64 {
+        match self.mode {
+            PageMode::Offset { page } => i64::from(page - 1) * i64::from(self.per_page),
+            PageMode::Cursor(_) => 0,
+        }
+    }
+
+    /// キーセット方式の開始位置
+    pub fn cursor(&self) -> Option<&Cursor> {
+        match &self.mode {
+            PageMode::Cursor(cursor) => cursor.as_ref(),
+            PageMode::Offset { .. } => None,
+        }
+    }
+
+    // --------------------------------------------------------
+    // finish: fetch_limit() 件で取得した行を Page にする
+    // --------------------------------------------------------
+    //
+    // 💡 per_page + 1 件目があれば「次のページがある」
+    // 💡 cursor_of: キーセット方式のとき、最後の行から次のカーソルを作る
+    pub fn finish<T>(
+        &self,
+        mut items: Vec<T>,
+        total: i64,
+        cursor_of: impl Fn(&T) -> Cursor,
+    ) -> Page<T> {
+        let has_more = items.len() > self.per_page as usize;
+        items.truncate(self.per_page as usize);
+
+        let (page, next_cursor) = match &self.mode {
+            PageMode::Offset { page } => (Some(*page), None),
+            PageMode::Cursor(_) => (
+                None,
+                items
+                    .last()
+                    .filter(|_| has_more)
+                    .map(|last| cursor_of(last).encode()),
+            ),
+        };
+
+        Page {
+            items,
+            total,
+            page,
+            per_page: self.per_page,
+            next_cursor,
+        }
+    }
+
+    /// キーセット方式に対応しない一覧用の finish
+    ///
+    /// 💡 resolve() は supports_cursor() が false の項目でキーセット方式を選ばないので、
+    ///    カーソルを作る処理は呼ばれない
+    pub fn finish_offset<T>(&self, items: Vec<T>, total: i64) -> Page<T> {
+        self.finish(items, total, |_| {
+            unreachable!("keyset pagination is not enabled for this sort key")
+        })
+    }
+}
+
+// --------------------------------------------------------
+// Cursor: キーセット方式の位置（日時 + ID）
+// --------------------------------------------------------
+//
+// 💡 クライアントには中身を意識させない（base64url で不透明な文字列にする）
+// - 形式: "<asc|desc>:<日時のマイクロ秒 | ->:<id>"
+// - 日時が NULL の行（未公開の記事など）は "-"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    pub direction: SortDirection,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub id: i32,
+}
+
+impl Cursor {
+    pub fn new(direction: SortDirection, timestamp: Option<DateTime<Utc>>, id: i32) -> Self {
+        Self {
+            direction,
+            timestamp,
+            id,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let timestamp = self
+            .timestamp
+            .map_or_else(|| "-".to_string(), |t| t.timestamp_micros().to_string());
+        URL_SAFE_NO_PAD.encode(format!("{}:{timestamp}:{}", self.direction, self.id))
+    }
+
+    pub fn decode(token: &str) -> AppResult<Self> {
+        let invalid = || AppError::BadRequest("Invalid cursor".to_string());
+
+        let bytes = URL_SAFE_NO_PAD.decode(token).map_err(|_| invalid())?;
+        let text = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let mut parts = text.splitn(3, ':');
+
+        let direction = match parts.next() {
+            Some("asc") => SortDirection::Asc,
+            Some("desc") => SortDirection::Desc,
+            _ => return Err(invalid()),
+        };
+        let timestamp = match parts.next().ok_or_else(invalid)? {
+            "-" => None,
+            micros => {
+                let micros = micros.parse::<i64>().map_err(|_| invalid())?;
+                Some(DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?)
+            }
+        };
+        let id = parts
+            .next()
+            .and_then(|id| id.parse::<i32>().ok())
+            .ok_or_else(invalid)?;
+
+        Ok(Self::new(direction, timestamp, id))
+    }
+}
+
+// ============================================
+// レスポンス
+// ============================================
+
+/// 1ページ分の結果
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// 条件に一致する総数（ページに関係なく）
+    pub total: i64,
+    /// オフセット方式のページ番号（キーセット方式では None）
+    pub page: Option<u32>,
+    pub per_page: u32,
+    pub next_cursor: Option<String>,
+}
+
+/// 一覧レスポンスに含めるページ情報
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PageMeta {
+    /// 条件に一致する総数
+    #[schema(example = 42)]
+    pub total: i64,
+    /// ページ番号（キーセット方式ではnull）
+    #[schema(example = 1)]
+    pub page: Option<u32>,
+    /// 1ページの件数
+    #[schema(example = 20)]
+    pub per_page: u32,
+    /// 総ページ数
+    #[schema(example = 3)]
+    pub total_pages: u32,
+    /// 次のページのカーソル（?cursor= に渡す。最後のページ・オフセット方式ではnull）
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    pub fn total_pages(&self) -> u32 {
+        let pages = (self.total.max(0) as u64).div_ceil(u64::from(self.per_page));
+        u32::try_from(pages).unwrap_or(u32::MAX)
+    }
+
+    /// 要素を変換し、ページ情報と分けて返す
+    pub fn into_parts<U>(self, f: impl FnMut(T) -> U) -> (Vec<U>, PageMeta) {
+        let meta = PageMeta {
+            total: self.total,
+            page: self.page,
+            per_page: self.per_page,
+            total_pages: self.total_pages(),
+            next_cursor: self.next_cursor.clone(),
+        };
+        (self.items.into_iter().map(f).collect(), meta)
+    }
+
+    // --------------------------------------------------------
+    // link_headers: RFC 8288 の Link ヘッダー
+    // --------------------------------------------------------
+    //
+    // 💡 リクエストのURLから page / cursor だけ差し替える（他の条件はそのまま残す）
+    // - オフセット方式: first / prev / next / last
+    // - キーセット方式: first / next
+    pub fn link_headers(&self, uri: &Uri) -> HeaderMap {
+        let mut links = Vec::new();
+
+        match self.page {
+            Some(page) => {
+                let last = self.total_pages().max(1);
+                links.push(("first", page_url(uri, "page", "1")));
+                if page > 1 {
+                    let prev = (page - 1).min(last);
+                    links.push(("prev", page_url(uri, "page", &prev.to_string())));
+                }
+                if page < last {
+                    links.push(("next", page_url(uri, "page", &(page + 1).to_string())));
+                }
+                links.push(("last", page_url(uri, "page", &last.to_string())));
+            }
+            None => {
+                links.push(("first", page_url(uri, "cursor", "")));
+                if let Some(cursor) = &self.next_cursor {
+                    links.push(("next", page_url(uri, "cursor", cursor)));
+                }
+            }
+        }
+
+        let value = links
+            .iter()
+            .map(|(rel, url)| format!("<{url}>; rel=\"{rel}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut headers = HeaderMap::new();
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            headers.insert(LINK, value);
+        }
+        headers
+    }
+}
+
+// --------------------------------------------------------
+// page_url: page / cursor を差し替えたURL（パス + クエリ）
+// --------------------------------------------------------
+//
+// 💡 value が空なら、そのパラメータを付けない（最初のページ）
+fn page_url(uri: &Uri, key: &str, value: &str) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    for (k, v) in form_urlencoded::parse(uri.query().unwrap_or("").as_bytes()) {
+        if k != "page" && k != "cursor" {
+            query.append_pair(&k, &v);
+        }
+    }
+    if !value.is_empty() {
+        query.append_pair(key, value);
+    }
+
+    let query = query.finish();
+    if query.is_empty() {
+        uri.path().to_string()
+    } else {
+        format!("{}?{}", uri.path(), query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum TestSort {
+        Name,
+        Date,
+    }
+
+    impl SortKey for TestSort {
+        const FIELDS: &'static [(&'static str, Self)] = &[("name", Self::Name), ("date", Self::Date)];
+        const DEFAULT: Self = Self::Date;
+
+        fn column(self) -> &'static str {
+            match self {
+                Self::Name => "name",
+                Self::Date => "date",
+            }
+        }
+
+        fn default_direction(self) -> SortDirection {
+            match self {
+                Self::Name => SortDirection::Asc,
+                Self::Date => SortDirection::Desc,
+            }
+        }
+
+        fn supports_cursor(self) -> bool {
+            self == Self::Date
+        }
+    }
+
+    #[test]
+    fn test_resolve_modes() {
+        let params = ListQuery::default().resolve::<TestSort>().unwrap();
+        assert_eq!(params.sort, TestSort::Date);
+        assert_eq!(params.direction, SortDirection::Desc);
+        assert_eq!(params.mode, PageMode::Cursor(None));
+
+        let query = ListQuery {
+            sort: Some("name".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(query.resolve::<TestSort>().unwrap().mode, PageMode::Offset { page: 1 });
+
+        let query = ListQuery {
+            page: Some(3),
+            per_page: Some(10),
+            ..Default::default()
+        };
+        let params = query.resolve::<TestSort>().unwrap();
+        assert_eq!(params.offset(), 20);
+        assert_eq!(params.fetch_limit(), 11);
+    }
+
+    #[test]
+    fn test_resolve_rejects_invalid() {
+        let cases = [
+            ListQuery { per_page: Some(0), ..Default::default() },
+            ListQuery { per_page: Some(101), ..Default::default() },
+            ListQuery { page: Some(0), ..Default::default() },
+            ListQuery { sort: Some("unknown".to_string()), ..Default::default() },
+            ListQuery { page: Some(1), cursor: Some("x".to_string()), ..Default::default() },
+            ListQuery {
+                sort: Some("name".to_string()),
+                cursor: Some(Cursor::new(SortDirection::Asc, None, 1).encode()),
+                ..Default::default()
+            },
+            // 並び順が違うカーソル
+            ListQuery {
+                cursor: Some(Cursor::new(SortDirection::Asc, None, 1).encode()),
+                ..Default::default()
+            },
+            ListQuery { cursor: Some("not-a-cursor".to_string()), ..Default::default() },
+        ];
+        for query in cases {
+            assert!(query.resolve::<TestSort>().is_err(), "{:?}", query);
+        }
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let at = DateTime::from_timestamp_micros(1_767_225_600_123_456).unwrap();
+        for cursor in [
+            Cursor::new(SortDirection::Desc, Some(at), 42),
+            Cursor::new(SortDirection::Asc, None, 7),
+        ] {
+            assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+        }
+    }
+
+    #[test]
+    fn test_link_headers() {
+        let uri: Uri = "/api/v1/topics?lang=en&page=2&per_page=2".parse().unwrap();
+        let page = Page {
+            items: vec![(), ()],
+            total: 5,
+            page: Some(2),
+            per_page: 2,
+            next_cursor: None,
+        };
+        assert_eq!(
+            page.link_headers(&uri)[LINK],
+            "</api/v1/topics?lang=en&per_page=2&page=1>; rel=\"first\", \
+             </api/v1/topics?lang=en&per_page=2&page=1>; rel=\"prev\", \
+             </api/v1/topics?lang=en&per_page=2&page=3>; rel=\"next\", \
+             </api/v1/topics?lang=en&per_page=2&page=3>; rel=\"last\""
+        );
+
+        let uri: Uri = "/api/v1/posts?cursor=abc".parse().unwrap();
+        let page = Page {
+            items: vec![()],
+            total: 5,
+            page: None,
+            per_page: 1,
+            next_cursor: Some("def".to_string()),
+        };
+        assert_eq!(
+            page.link_headers(&uri)[LINK],
+            "</api/v1/posts>; rel=\"first\", </api/v1/posts?cursor=def>; rel=\"next\""
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    entities::{Administrator, AuditAction, AuditTable, Role, Session, SessionWithAdmin},
+    repositories::audit_log_repository::{self, AuditContext},
+};
+
+// ============================================
+// AdminRepository（管理者・セッションのデータアクセス）
+// ============================================
+//
+// 💡 セッショントークンは token_hash（SHA-256）でしか扱わない
+// - 平文のトークンはこの層に渡さない（auth::token::hash_token で変換してから渡す）
+
+/// 管理者の作成用の入力（password_hash はハッシュ化済み）
+#[derive(Debug, Clone)]
+pub struct NewAdmin {
+    pub username: String,
+    pub email: String,
+    pub password_hash: String,
+    pub full_name: Option<String>,
+    pub role: Role,
+}
+
+/// セッションの作成用の入力
+#[derive(Debug, Clone)]
+pub struct NewSession {
+    pub admin_id: i32,
+    pub token_hash: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub struct AdminRepository {
+    pool: PgPool,
+}
+
+impl AdminRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_by_login: ユーザー名またはメールアドレスで1件取得
+    // --------------------------------------------------------
+    //
+    // 💡 メールアドレスは大文字小文字を区別しない
+    pub async fn find_by_login(&self, login: &str) -> Result<Option<Administrator>, sqlx::Error> {
+        let admin = sqlx::query_as::<_, Administrator>(
+            "SELECT * FROM administrators WHERE username = $1 OR LOWER(email) = LOWER($1)",
+        )
+        .bind(login)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(admin)
+    }
+
+    // --------------------------------------------------------
+    // find_by_id: IDで1件取得
+    // --------------------------------------------------------
+    pub async fn find_by_id(&self, admin_id: i32) -> Result<Option<Administrator>, sqlx::Error> {
+        let admin = sqlx::query_as::<_, Administrator>(
+            "SELECT * FROM administrators WHERE admin_id = $1",
+        )
+        .bind(admin_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(admin)
+    }
+
+    // --------------------------------------------------------
+    // create: 管理者を作成
+    // --------------------------------------------------------
+    //
+    // 💡 username / email の重複は UNIQUE制約違反 → AppError で 409 になる
+    pub async fn create(
+        &self,
+        admin: &NewAdmin,
+        audit: &AuditContext,
+    ) -> Result<Administrator, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Administrator>(
+            r#"
+            INSERT INTO administrators (username, email, password_hash, full_name, role)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(&admin.username)
+        .bind(&admin.email)
+        .bind(&admin.password_hash)
+        .bind(&admin.full_name)
+        .bind(admin.role)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Administrators, created.admin_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Administrators,
+            created.admin_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    /// 最終ログイン日時を記録
+    pub async fn record_login(&self, admin_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE administrators SET last_login_at = NOW() WHERE admin_id = $1")
+            .bind(admin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // ============================================
+    // セッション
+    // ============================================
+
+    /// セッションを作成
+    pub async fn create_session(&self, session: &NewSession) -> Result<Session, sqlx::Error> {
+        let created = sqlx::query_as::<_, Session>(
+            r#"
+            INSERT INTO sessions (admin_id, token_hash, ip_address, user_agent, expires_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(session.admin_id)
+        .bind(&session.token_hash)
+        .bind(&session.ip_address)
+        .bind(&session.user_agent)
+        .bind(session.expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    // --------------------------------------------------------
+    // find_session: トークンのハッシュでセッション + 管理者を取得
+    // --------------------------------------------------------
+    //
+    // 💡 期限の判定は呼び出し側（extractors::auth）で行う
+    // - 期限切れの理由（絶対期限 / 無操作）を区別してログに出すため
+    pub async fn find_session(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<SessionWithAdmin>, sqlx::Error> {
+        let session = sqlx::query_as::<_, SessionWithAdmin>(
+            r#"
+            SELECT s.session_id, s.expires_at, s.last_activity_at,
+                   a.admin_id, a.username, a.role, a.is_active
+            FROM sessions s
+            JOIN administrators a ON a.admin_id = s.admin_id
+            WHERE s.token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(session)
+    }
+
+    /// 最終操作日時を更新
+    pub async fn touch_session(&self, session_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE sessions SET last_activity_at = NOW() WHERE session_id = $1")
+            .bind(session_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// セッションを削除（ログアウト・期限切れ）
+    pub async fn delete_session(&self, session_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM sessions WHERE session_id = $1")
+            .bind(session_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // --------------------------------------------------------
+    // delete_expired_sessions: 期限切れのセッションをまとめて削除
+    // --------------------------------------------------------
+    //
+    // 💡 ログインのたびに呼んで、テーブルが増え続けないようにする
+    pub async fn delete_expired_sessions(&self, idle_timeout_secs: i64) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM sessions
+            WHERE expires_at < NOW()
+               OR last_activity_at < NOW() - make_interval(secs => $1)
+            "#,
+        )
+        .bind(idle_timeout_secs as f64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
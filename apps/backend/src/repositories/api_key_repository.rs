@@ -0,0 +1,161 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::{
+    entities::{ApiKey, ApiScope, AuditAction, AuditTable},
+    repositories::audit_log_repository::{self, AuditContext},
+};
+
+// ============================================
+// ApiKeyRepository（APIキーのデータアクセス）
+// ============================================
+//
+// 💡 セッションと同じく、平文のキーはこの層に渡さない（api_key_hash で扱う）
+
+/// APIキーの作成用の入力
+#[derive(Debug, Clone)]
+pub struct NewApiKey {
+    pub key_name: String,
+    pub key_prefix: String,
+    pub api_key_hash: String,
+    pub permissions: Vec<ApiScope>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_by: Option<i32>,
+}
+
+pub struct ApiKeyRepository {
+    pool: PgPool,
+}
+
+impl ApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 全件取得（新しい順、無効化したものも含む）
+    pub async fn find_all(&self) -> Result<Vec<ApiKey>, sqlx::Error> {
+        let keys = sqlx::query_as::<_, ApiKey>(
+            "SELECT * FROM api_keys ORDER BY created_at DESC, api_key_id DESC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys)
+    }
+
+    /// キーのハッシュで1件取得（認証用）
+    pub async fn find_by_hash(&self, api_key_hash: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+        let key = sqlx::query_as::<_, ApiKey>("SELECT * FROM api_keys WHERE api_key_hash = $1")
+            .bind(api_key_hash)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(key)
+    }
+
+    /// APIキーを作成
+    pub async fn create(&self, key: &NewApiKey, audit: &AuditContext) -> Result<ApiKey, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, ApiKey>(
+            r#"
+            INSERT INTO api_keys (key_name, key_prefix, api_key_hash, permissions, expires_at, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(&key.key_name)
+        .bind(&key.key_prefix)
+        .bind(&key.api_key_hash)
+        .bind(&key.permissions)
+        .bind(key.expires_at)
+        .bind(key.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::ApiKeys, created.api_key_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::ApiKeys,
+            created.api_key_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    // --------------------------------------------------------
+    // revoke: APIキーを無効化
+    // --------------------------------------------------------
+    //
+    // 💡 すでに無効化済みでも現在の行を返す（何度呼んでも同じ結果）
+    // 💡 存在しなければ None
+    pub async fn revoke(
+        &self,
+        api_key_id: i32,
+        audit: &AuditContext,
+    ) -> Result<Option<ApiKey>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) = audit_log_repository::snapshot(&mut tx, AuditTable::ApiKeys, api_key_id).await? else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let key = sqlx::query_as::<_, ApiKey>(
+            r#"
+            UPDATE api_keys
+            SET is_active = FALSE,
+                revoked_at = COALESCE(revoked_at, NOW())
+            WHERE api_key_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(api_key_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::ApiKeys, api_key_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::ApiKeys,
+            api_key_id,
+            AuditAction::Update,
+            Some(old),
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(key))
+    }
+
+    // --------------------------------------------------------
+    // touch: 最終使用日時を更新
+    // --------------------------------------------------------
+    //
+    // 💡 CIから短時間に大量に呼ばれても毎回 UPDATE しないよう、1分以上空いたときだけ更新
+    pub async fn touch(&self, api_key_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET last_used_at = NOW()
+            WHERE api_key_id = $1
+              AND (last_used_at IS NULL OR last_used_at < NOW() - INTERVAL '1 minute')
+            "#,
+        )
+        .bind(api_key_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
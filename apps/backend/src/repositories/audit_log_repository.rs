@@ -0,0 +1,316 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{
+    entities::{AuditAction, AuditLog, AuditTable, ChangeSource},
+    pagination::{Cursor, ListParams, Page, SortDirection, SortKey},
+};
+
+// ============================================
+// AuditLogRepository（監査ログのデータアクセス）
+// ============================================
+//
+// 💡 記録の流れ（各リポジトリの変更メソッドの中で行う）:
+//   let mut tx = self.pool.begin().await?;
+//   let old = snapshot(&mut tx, AuditTable::Topics, topic_id).await?;   // 変更前（行ロック）
+//   ... UPDATE ...
+//   let new = snapshot(&mut tx, AuditTable::Topics, topic_id).await?;   // 変更後
+//   record(&mut tx, audit, AuditTable::Topics, topic_id, AuditAction::Update, old, new).await?;
+//   tx.commit().await?;
+//
+// 💡 なぜ同じトランザクション?
+// - 変更だけ成功してログが残らない（またはその逆）という状態を防ぐ
+//
+// 💡 記録しないもの:
+// - ログイン日時・セッション・APIキーの最終使用日時（認証のたびに更新される記録用の値）
+
+/// 変更の実行者（誰が・どこから）
+#[derive(Debug, Clone)]
+pub struct AuditContext {
+    /// 変更した管理者（APIキーの場合はキーを発行した管理者）
+    pub changed_by: Option<i32>,
+    /// 変更に使ったAPIキー
+    pub api_key_id: Option<i32>,
+    pub changed_via: ChangeSource,
+    pub ip_address: Option<String>,
+}
+
+impl AuditContext {
+    /// backend コマンドからの変更
+    pub fn cli() -> Self {
+        Self {
+            changed_by: None,
+            api_key_id: None,
+            changed_via: ChangeSource::Cli,
+            ip_address: None,
+        }
+    }
+
+    /// バックグラウンドの予約公開（scheduler）による変更
+    pub fn scheduler() -> Self {
+        Self {
+            changed_by: None,
+            api_key_id: None,
+            changed_via: ChangeSource::Scheduler,
+            ip_address: None,
+        }
+    }
+}
+
+/// 監査ログ一覧の絞り込み条件
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub table: Option<AuditTable>,
+    pub record_id: Option<i32>,
+    pub changed_by: Option<i32>,
+    pub api_key_id: Option<i32>,
+    /// この日時以降（含む）
+    pub from: Option<DateTime<Utc>>,
+    /// この日時より前（含まない）
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// 監査ログ一覧の並び替え項目（記録日時のみ、キーセット方式に対応）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditLogSort {
+    CreatedAt,
+}
+
+impl SortKey for AuditLogSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[("created_at", Self::CreatedAt)];
+    const DEFAULT: Self = Self::CreatedAt;
+
+    fn column(self) -> &'static str {
+        "a.created_at"
+    }
+
+    fn default_direction(self) -> SortDirection {
+        SortDirection::Desc
+    }
+
+    fn supports_cursor(self) -> bool {
+        true
+    }
+}
+
+// --------------------------------------------------------
+// audit_filter: 一覧と件数で共通のWHERE条件（$1〜$6）
+// --------------------------------------------------------
+const AUDIT_FILTER: &str = r#"
+    ($1::text IS NULL OR a.table_name = $1)
+    AND ($2::int IS NULL OR a.record_id = $2)
+    AND ($3::int IS NULL OR a.changed_by = $3)
+    AND ($4::int IS NULL OR a.api_key_id = $4)
+    AND ($5::timestamptz IS NULL OR a.created_at >= $5)
+    AND ($6::timestamptz IS NULL OR a.created_at < $6)
+"#;
+
+pub struct AuditLogRepository {
+    pool: PgPool,
+}
+
+impl AuditLogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_page: 監査ログ一覧（1ページ分）
+    // --------------------------------------------------------
+    pub async fn find_page(
+        &self,
+        filter: &AuditLogFilter,
+        params: &ListParams<AuditLogSort>,
+    ) -> Result<Page<AuditLog>, sqlx::Error> {
+        let sql = format!(
+            r#"
+            SELECT a.*, ad.username AS changed_by_username
+            FROM audit_logs a
+            LEFT JOIN administrators ad ON ad.admin_id = a.changed_by
+            WHERE {AUDIT_FILTER}
+              AND ($8::int IS NULL OR (a.created_at, a.log_id) {} ($7::timestamptz, $8))
+            {}
+            LIMIT $9 OFFSET $10
+            "#,
+            params.direction.after_operator(),
+            params.order_by("a.log_id"),
+        );
+
+        let cursor = params.cursor();
+        let logs = sqlx::query_as::<_, AuditLog>(&sql)
+            .bind(filter.table)
+            .bind(filter.record_id)
+            .bind(filter.changed_by)
+            .bind(filter.api_key_id)
+            .bind(filter.from)
+            .bind(filter.to)
+            .bind(cursor.and_then(|c| c.timestamp))
+            .bind(cursor.map(|c| c.id))
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) =
+            sqlx::query_as(&format!("SELECT COUNT(*) FROM audit_logs a WHERE {AUDIT_FILTER}"))
+                .bind(filter.table)
+                .bind(filter.record_id)
+                .bind(filter.changed_by)
+                .bind(filter.api_key_id)
+                .bind(filter.from)
+                .bind(filter.to)
+                .fetch_one(&self.pool)
+                .await?;
+
+        let direction = params.direction;
+        Ok(params.finish(logs, total, |log| {
+            Cursor::new(direction, Some(log.created_at), log.log_id)
+        }))
+    }
+}
+
+// --------------------------------------------------------
+// snapshot: 1行をJSONにする（変更前・変更後の記録用）
+// --------------------------------------------------------
+//
+// 💡 to_jsonb(t): 行の全カラムを {"カラム名": 値} のJSONにする
+// - トピック・記事・本・チャプター・メディアは翻訳（と記事のトピック）も含めて1つのスナップショットにする
+// - パスワード・APIキーのハッシュ、記事の search_vector（本文から作り直せる）は「- 'カラム名'」で取り除く
+//
+// 💡 FOR UPDATE OF t:
+// - 変更前のスナップショットを取ってから UPDATE するまでに、他のリクエストが同じ行を変えないようにする
+//
+// 💡 行がなければ None（削除後のスナップショットなど）
+pub async fn snapshot(
+    tx: &mut Transaction<'_, Postgres>,
+    table: AuditTable,
+    record_id: i32,
+) -> Result<Option<Value>, sqlx::Error> {
+    let sql = match table {
+        AuditTable::Locales => "SELECT to_jsonb(t) FROM locales t WHERE t.locale_id = $1 FOR UPDATE OF t",
+        AuditTable::Topics => {
+            r#"
+            SELECT to_jsonb(t) || jsonb_build_object('translations', COALESCE((
+                SELECT jsonb_agg(to_jsonb(tr) - 'topic_id' ORDER BY tr.locale_id)
+                FROM topic_translations tr
+                WHERE tr.topic_id = t.topic_id
+            ), '[]'::jsonb))
+            FROM topics t
+            WHERE t.topic_id = $1
+            FOR UPDATE OF t
+            "#
+        }
+        AuditTable::BlogPosts => {
+            r#"
+            SELECT to_jsonb(t) || jsonb_build_object(
+                'translations', COALESCE((
+                    SELECT jsonb_agg(to_jsonb(tr) - 'post_id' - 'search_vector' ORDER BY tr.locale_id)
+                    FROM blog_post_translations tr
+                    WHERE tr.post_id = t.post_id
+                ), '[]'::jsonb),
+                'topic_ids', COALESCE((
+                    SELECT jsonb_agg(bpt.topic_id ORDER BY bpt.topic_id)
+                    FROM blog_post_topics bpt
+                    WHERE bpt.post_id = t.post_id
+                ), '[]'::jsonb)
+            )
+            FROM blog_posts t
+            WHERE t.post_id = $1
+            FOR UPDATE OF t
+            "#
+        }
+        AuditTable::Books => {
+            r#"
+            SELECT to_jsonb(t) || jsonb_build_object('translations', COALESCE((
+                SELECT jsonb_agg(to_jsonb(tr) - 'book_id' ORDER BY tr.locale_id)
+                FROM book_translations tr
+                WHERE tr.book_id = t.book_id
+            ), '[]'::jsonb))
+            FROM books t
+            WHERE t.book_id = $1
+            FOR UPDATE OF t
+            "#
+        }
+        AuditTable::BookChapters => {
+            r#"
+            SELECT to_jsonb(t) || jsonb_build_object('translations', COALESCE((
+                SELECT jsonb_agg(to_jsonb(tr) - 'chapter_id' ORDER BY tr.locale_id)
+                FROM book_chapter_translations tr
+                WHERE tr.chapter_id = t.chapter_id
+            ), '[]'::jsonb))
+            FROM book_chapters t
+            WHERE t.chapter_id = $1
+            FOR UPDATE OF t
+            "#
+        }
+        AuditTable::Media => {
+            r#"
+            SELECT to_jsonb(t) || jsonb_build_object('translations', COALESCE((
+                SELECT jsonb_agg(to_jsonb(tr) - 'media_id' ORDER BY tr.locale_id)
+                FROM media_translations tr
+                WHERE tr.media_id = t.media_id
+            ), '[]'::jsonb))
+            FROM media t
+            WHERE t.media_id = $1
+            FOR UPDATE OF t
+            "#
+        }
+        AuditTable::Administrators => {
+            "SELECT to_jsonb(t) - 'password_hash' FROM administrators t WHERE t.admin_id = $1 FOR UPDATE OF t"
+        }
+        AuditTable::ApiKeys => {
+            "SELECT to_jsonb(t) - 'api_key_hash' FROM api_keys t WHERE t.api_key_id = $1 FOR UPDATE OF t"
+        }
+    };
+
+    let row: Option<(Value,)> = sqlx::query_as(sql)
+        .bind(record_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+
+    Ok(row.map(|(value,)| value))
+}
+
+// --------------------------------------------------------
+// record: 監査ログを1行追加
+// --------------------------------------------------------
+//
+// 💡 UPDATE で変更前と変更後が同じなら記録しない
+// - 例: 無効化済みのAPIキーをもう一度無効化した
+pub async fn record(
+    tx: &mut Transaction<'_, Postgres>,
+    audit: &AuditContext,
+    table: AuditTable,
+    record_id: i32,
+    action: AuditAction,
+    old_values: Option<Value>,
+    new_values: Option<Value>,
+) -> Result<(), sqlx::Error> {
+    if action == AuditAction::Update && old_values == new_values {
+        return Ok(());
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO audit_logs (
+            table_name, record_id, action, old_values, new_values,
+            changed_by, api_key_id, changed_via, ip_address
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        "#,
+    )
+    .bind(table)
+    .bind(record_id)
+    .bind(action)
+    .bind(old_values)
+    .bind(new_values)
+    .bind(audit.changed_by)
+    .bind(audit.api_key_id)
+    .bind(audit.changed_via)
+    .bind(&audit.ip_address)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,857 @@
+use sqlx::{FromRow, PgPool, Postgres, Transaction};
+
+use crate::{
+    entities::{
+        AuditAction, AuditTable, Book, BookChapter, BookChapterTranslation, BookTranslation,
+        LocalizedBook, LocalizedChapter,
+    },
+    pagination::{ListParams, Page, SortKey},
+    repositories::audit_log_repository::{self, AuditContext},
+};
+
+// ============================================
+// BookRepository（本とチャプターのデータアクセス）
+// ============================================
+//
+// 💡 多言語の解決ルール（記事と同じ、優先度の高い順）:
+// 1. 要求された言語（requested）の翻訳
+// 2. 本の原文言語（books.default_locale_id）の翻訳
+// 3. サイトのデフォルト言語（fallback）の翻訳
+//
+// 💡 チャプターの順番（chapter_number）を変える処理:
+// - 本の行を FOR UPDATE でロックしてから行う（同じ本の並び替えが同時に走らないように）
+// - 番号が変わった行はすべて監査ログに残す
+
+/// 本の作成用の入力
+#[derive(Debug, Clone)]
+pub struct NewBook {
+    pub slug: String,
+    pub emoji: Option<String>,
+    pub top_image_url: Option<String>,
+    pub display_order: Option<i32>,
+    pub is_published: bool,
+    pub default_locale_id: i32,
+    /// 作成した管理者（last_updated_by にも同じ値を入れる）
+    pub created_by: Option<i32>,
+}
+
+/// 本の部分更新用の入力（Noneの項目は変更しない）
+#[derive(Debug, Clone, Default)]
+pub struct BookChanges {
+    pub slug: Option<String>,
+    pub emoji: Option<String>,
+    pub top_image_url: Option<String>,
+    pub display_order: Option<i32>,
+    pub is_published: Option<bool>,
+    pub default_locale_id: Option<i32>,
+    /// 更新した管理者（None なら last_updated_by を変更しない）
+    pub last_updated_by: Option<i32>,
+}
+
+/// 本の翻訳の作成・更新用の入力
+#[derive(Debug, Clone)]
+pub struct BookTranslationInput {
+    pub locale_id: i32,
+    pub title: String,
+    pub introduction: Option<String>,
+}
+
+/// チャプターの作成用の入力
+#[derive(Debug, Clone)]
+pub struct NewChapter {
+    pub slug: String,
+    /// 親チャプター（None ならトップレベル）
+    pub parent_chapter_id: Option<i32>,
+    /// 兄弟の中での位置（1始まり、None なら末尾）
+    pub position: Option<u32>,
+    pub is_published: bool,
+}
+
+/// チャプターの翻訳の作成・更新用の入力
+#[derive(Debug, Clone)]
+pub struct ChapterTranslationInput {
+    pub locale_id: i32,
+    pub title: String,
+}
+
+/// チャプター移動の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChapterMove {
+    Moved,
+    /// チャプターが見つからない
+    NotFound,
+    /// 自分自身や自分の子孫の下には移動できない
+    IntoOwnSubtree,
+}
+
+/// 本一覧の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSort {
+    DisplayOrder,
+    Slug,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl SortKey for BookSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("display_order", Self::DisplayOrder),
+        ("slug", Self::Slug),
+        ("created_at", Self::CreatedAt),
+        ("updated_at", Self::UpdatedAt),
+    ];
+    const DEFAULT: Self = Self::DisplayOrder;
+
+    // 💡 display_order が NULL の本は、昇順のとき末尾に並ぶ（PostgreSQL の NULL は最大扱い）
+    fn column(self) -> &'static str {
+        match self {
+            Self::DisplayOrder => "b.display_order",
+            Self::Slug => "b.slug",
+            Self::CreatedAt => "b.created_at",
+            Self::UpdatedAt => "b.updated_at",
+        }
+    }
+}
+
+// --------------------------------------------------------
+// LOCALIZED_SELECT: 言語解決付きのSELECT（$1 = 要求言語, $2 = サイトのデフォルト言語）
+// --------------------------------------------------------
+//
+// 💡 翻訳の選び方は PostRepository の TRANSLATION_PICK と同じ3段階
+const LOCALIZED_SELECT: &str = r#"
+    SELECT
+        b.book_id, b.slug, b.emoji, b.top_image_url, b.display_order, b.is_published,
+        b.created_at, b.updated_at, b.published_at,
+        t.title, t.introduction, t.locale_code
+    FROM books b
+    LEFT JOIN LATERAL (
+        SELECT tr.title, tr.introduction, l.code AS locale_code
+        FROM book_translations tr
+        JOIN locales l ON l.locale_id = tr.locale_id
+        WHERE tr.book_id = b.book_id
+          AND l.is_active = TRUE
+          AND (l.code IN ($1, $2) OR tr.locale_id = b.default_locale_id)
+        ORDER BY
+            (l.code = $1) DESC,
+            COALESCE(tr.locale_id = b.default_locale_id, FALSE) DESC
+        LIMIT 1
+    ) t ON TRUE
+"#;
+
+// --------------------------------------------------------
+// CHAPTER_TREE: 本のチャプターツリーを1回のクエリで取得
+// --------------------------------------------------------
+//
+// 💡 WITH RECURSIVE:
+// - 1つ目のSELECT: トップレベルのチャプター（parent_chapter_id IS NULL）
+// - 2つ目のSELECT: 直前の結果の子チャプター（これを子がなくなるまで繰り返す）
+//
+// 💡 sort_path: ルートからの chapter_number の配列（例: 2章の1節 → {2, 1}）
+// - 配列の比較は要素を先頭から比べるので、ORDER BY sort_path で深さ優先の順（目次の順）になる
+//
+// 💡 $4 = FALSE（読者向け）のとき、非公開のチャプターはその子孫ごと除く
+// - 再帰は親が結果に残った行からしか進まないため
+//
+// 💡 $1 = 要求言語, $2 = サイトのデフォルト言語, $3 = book_id
+const CHAPTER_TREE: &str = r#"
+    WITH RECURSIVE tree AS (
+        SELECT c.chapter_id, c.parent_chapter_id, c.chapter_number, c.slug, c.is_published,
+               c.created_at, c.updated_at,
+               0 AS depth, ARRAY[c.chapter_number] AS sort_path
+        FROM book_chapters c
+        WHERE c.book_id = $3
+          AND c.parent_chapter_id IS NULL
+          AND ($4 OR c.is_published)
+        UNION ALL
+        SELECT c.chapter_id, c.parent_chapter_id, c.chapter_number, c.slug, c.is_published,
+               c.created_at, c.updated_at,
+               tree.depth + 1, tree.sort_path || c.chapter_number
+        FROM book_chapters c
+        JOIN tree ON c.parent_chapter_id = tree.chapter_id
+        WHERE ($4 OR c.is_published)
+    )
+    SELECT
+        tree.chapter_id, tree.parent_chapter_id, tree.chapter_number, tree.depth,
+        tree.slug, tree.is_published, tree.created_at, tree.updated_at,
+        t.title, t.locale_code
+    FROM tree
+    JOIN books b ON b.book_id = $3
+    LEFT JOIN LATERAL (
+        SELECT ct.title, l.code AS locale_code
+        FROM book_chapter_translations ct
+        JOIN locales l ON l.locale_id = ct.locale_id
+        WHERE ct.chapter_id = tree.chapter_id
+          AND l.is_active = TRUE
+          AND (l.code IN ($1, $2) OR ct.locale_id = b.default_locale_id)
+        ORDER BY
+            (l.code = $1) DESC,
+            COALESCE(ct.locale_id = b.default_locale_id, FALSE) DESC
+        LIMIT 1
+    ) t ON TRUE
+    ORDER BY tree.sort_path
+"#;
+
+/// 並び替えの対象になるチャプターの位置
+#[derive(Debug, Clone, FromRow)]
+struct ChapterSlot {
+    chapter_id: i32,
+    parent_chapter_id: Option<i32>,
+    chapter_number: i32,
+}
+
+pub struct BookRepository {
+    pool: PgPool,
+}
+
+impl BookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_page_localized: 本の一覧（1ページ分、言語解決済み）
+    // --------------------------------------------------------
+    pub async fn find_page_localized(
+        &self,
+        requested: &str,
+        fallback: &str,
+        include_unpublished: bool,
+        params: &ListParams<BookSort>,
+    ) -> Result<Page<LocalizedBook>, sqlx::Error> {
+        let sql = format!(
+            "{LOCALIZED_SELECT} WHERE ($3 OR b.is_published) {} LIMIT $4 OFFSET $5",
+            params.order_by("b.book_id")
+        );
+
+        let books = sqlx::query_as::<_, LocalizedBook>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(include_unpublished)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM books b WHERE ($1 OR b.is_published)")
+                .bind(include_unpublished)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(params.finish_offset(books, total))
+    }
+
+    // --------------------------------------------------------
+    // find_by_slug_localized: slugで1件取得（言語解決済み）
+    // --------------------------------------------------------
+    pub async fn find_by_slug_localized(
+        &self,
+        slug: &str,
+        requested: &str,
+        fallback: &str,
+    ) -> Result<Option<LocalizedBook>, sqlx::Error> {
+        let sql = format!("{LOCALIZED_SELECT} WHERE b.slug = $3");
+
+        let book = sqlx::query_as::<_, LocalizedBook>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(book)
+    }
+
+    // --------------------------------------------------------
+    // find_by_slug: slugで1件取得（翻訳なし）
+    // --------------------------------------------------------
+    pub async fn find_by_slug(&self, slug: &str) -> Result<Option<Book>, sqlx::Error> {
+        let book = sqlx::query_as::<_, Book>("SELECT * FROM books WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(book)
+    }
+
+    // --------------------------------------------------------
+    // find_translations: 本の全翻訳（言語コード付き）
+    // --------------------------------------------------------
+    pub async fn find_translations(&self, book_id: i32) -> Result<Vec<BookTranslation>, sqlx::Error> {
+        let translations = sqlx::query_as::<_, BookTranslation>(
+            r#"
+            SELECT tr.translation_id, tr.book_id, tr.locale_id, l.code AS locale_code,
+                   tr.title, tr.introduction, tr.created_at, tr.updated_at
+            FROM book_translations tr
+            JOIN locales l ON l.locale_id = tr.locale_id
+            WHERE tr.book_id = $1
+            ORDER BY l.locale_id ASC
+            "#
+        )
+        .bind(book_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(translations)
+    }
+
+    // --------------------------------------------------------
+    // find_chapter_tree: チャプターツリー（言語解決済み、目次の順）
+    // --------------------------------------------------------
+    //
+    // 💡 include_unpublished:
+    // - false: 公開中のチャプターのみ（非公開のチャプターの子孫も除く）
+    // - true : 非公開も含む（管理画面向け）
+    pub async fn find_chapter_tree(
+        &self,
+        book_id: i32,
+        requested: &str,
+        fallback: &str,
+        include_unpublished: bool,
+    ) -> Result<Vec<LocalizedChapter>, sqlx::Error> {
+        let chapters = sqlx::query_as::<_, LocalizedChapter>(CHAPTER_TREE)
+            .bind(requested)
+            .bind(fallback)
+            .bind(book_id)
+            .bind(include_unpublished)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(chapters)
+    }
+
+    // --------------------------------------------------------
+    // find_chapter_by_slug: 本の中のチャプターをslugで1件取得
+    // --------------------------------------------------------
+    pub async fn find_chapter_by_slug(
+        &self,
+        book_id: i32,
+        slug: &str,
+    ) -> Result<Option<BookChapter>, sqlx::Error> {
+        let chapter = sqlx::query_as::<_, BookChapter>(
+            "SELECT * FROM book_chapters WHERE book_id = $1 AND slug = $2"
+        )
+        .bind(book_id)
+        .bind(slug)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(chapter)
+    }
+
+    // --------------------------------------------------------
+    // find_chapter_translations: チャプターの全翻訳（言語コード付き）
+    // --------------------------------------------------------
+    pub async fn find_chapter_translations(
+        &self,
+        chapter_id: i32,
+    ) -> Result<Vec<BookChapterTranslation>, sqlx::Error> {
+        let translations = sqlx::query_as::<_, BookChapterTranslation>(
+            r#"
+            SELECT ct.translation_id, ct.chapter_id, ct.locale_id, l.code AS locale_code,
+                   ct.title, ct.created_at, ct.updated_at
+            FROM book_chapter_translations ct
+            JOIN locales l ON l.locale_id = ct.locale_id
+            WHERE ct.chapter_id = $1
+            ORDER BY l.locale_id ASC
+            "#
+        )
+        .bind(chapter_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(translations)
+    }
+
+    // --------------------------------------------------------
+    // create: 本と翻訳をまとめて作成（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 公開状態で作成した場合は published_at に現在時刻を入れる
+    pub async fn create(
+        &self,
+        book: &NewBook,
+        translations: &[BookTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<Book, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Book>(
+            r#"
+            INSERT INTO books (
+                slug, emoji, top_image_url, display_order, is_published, default_locale_id,
+                published_at, created_by, last_updated_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, CASE WHEN $5 THEN NOW() END, $7, $7)
+            RETURNING *
+            "#
+        )
+        .bind(&book.slug)
+        .bind(&book.emoji)
+        .bind(&book.top_image_url)
+        .bind(book.display_order)
+        .bind(book.is_published)
+        .bind(book.default_locale_id)
+        .bind(book.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_book_translation(&mut tx, created.book_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Books, created.book_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Books,
+            created.book_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    // --------------------------------------------------------
+    // update: 本の部分更新 + 翻訳のUPSERT（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 published_at は記事と同じく、初めて公開されたときだけ現在時刻を入れる
+    pub async fn update(
+        &self,
+        book_id: i32,
+        changes: &BookChanges,
+        translations: &[BookTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<Option<Book>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) = audit_log_repository::snapshot(&mut tx, AuditTable::Books, book_id).await? else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let updated = sqlx::query_as::<_, Book>(
+            r#"
+            UPDATE books
+            SET slug = COALESCE($2, slug),
+                emoji = COALESCE($3, emoji),
+                top_image_url = COALESCE($4, top_image_url),
+                display_order = COALESCE($5, display_order),
+                is_published = COALESCE($6, is_published),
+                default_locale_id = COALESCE($7, default_locale_id),
+                published_at = CASE
+                    WHEN COALESCE($6, is_published) AND published_at IS NULL THEN NOW()
+                    ELSE published_at
+                END,
+                last_updated_by = COALESCE($8, last_updated_by),
+                updated_at = NOW()
+            WHERE book_id = $1
+            RETURNING *
+            "#
+        )
+        .bind(book_id)
+        .bind(&changes.slug)
+        .bind(&changes.emoji)
+        .bind(&changes.top_image_url)
+        .bind(changes.display_order)
+        .bind(changes.is_published)
+        .bind(changes.default_locale_id)
+        .bind(changes.last_updated_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_book_translation(&mut tx, book_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Books, book_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Books,
+            book_id,
+            AuditAction::Update,
+            Some(old),
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(updated))
+    }
+
+    // --------------------------------------------------------
+    // create_chapter: チャプターと翻訳をまとめて作成
+    // --------------------------------------------------------
+    //
+    // 💡 position を指定した場合:
+    // - その位置以降の兄弟チャプターの chapter_number を1つずつ後ろにずらしてから追加する
+    //
+    // 💡 parent_chapter_id は同じ本のチャプターであること（複合外部キーでも保証される）
+    pub async fn create_chapter(
+        &self,
+        book_id: i32,
+        chapter: &NewChapter,
+        translations: &[ChapterTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<BookChapter, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        lock_book(&mut tx, book_id).await?;
+
+        let siblings = find_siblings(&mut tx, book_id, chapter.parent_chapter_id).await?;
+        let index = insert_index(siblings.len(), chapter.position);
+
+        for (i, sibling) in siblings.iter().enumerate() {
+            let number = if i < index { i + 1 } else { i + 2 };
+            set_position(&mut tx, sibling, chapter.parent_chapter_id, number as i32, audit).await?;
+        }
+
+        let created = sqlx::query_as::<_, BookChapter>(
+            r#"
+            INSERT INTO book_chapters (book_id, chapter_number, slug, parent_chapter_id, is_published)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#
+        )
+        .bind(book_id)
+        .bind(index as i32 + 1)
+        .bind(&chapter.slug)
+        .bind(chapter.parent_chapter_id)
+        .bind(chapter.is_published)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_chapter_translation(&mut tx, created.chapter_id, translation).await?;
+        }
+
+        let new =
+            audit_log_repository::snapshot(&mut tx, AuditTable::BookChapters, created.chapter_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::BookChapters,
+            created.chapter_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    // --------------------------------------------------------
+    // update_chapter: チャプターの部分更新 + 翻訳のUPSERT
+    // --------------------------------------------------------
+    //
+    // 💡 is_published はチャプターごとに切り替えられる
+    // - 非公開にすると、読者向けのツリーからは子孫ごと見えなくなる
+    pub async fn update_chapter(
+        &self,
+        chapter_id: i32,
+        slug: Option<&str>,
+        is_published: Option<bool>,
+        translations: &[ChapterTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<Option<BookChapter>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) =
+            audit_log_repository::snapshot(&mut tx, AuditTable::BookChapters, chapter_id).await?
+        else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let updated = sqlx::query_as::<_, BookChapter>(
+            r#"
+            UPDATE book_chapters
+            SET slug = COALESCE($2, slug),
+                is_published = COALESCE($3, is_published),
+                updated_at = NOW()
+            WHERE chapter_id = $1
+            RETURNING *
+            "#
+        )
+        .bind(chapter_id)
+        .bind(slug)
+        .bind(is_published)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_chapter_translation(&mut tx, chapter_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::BookChapters, chapter_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::BookChapters,
+            chapter_id,
+            AuditAction::Update,
+            Some(old),
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(updated))
+    }
+
+    // --------------------------------------------------------
+    // move_chapter: チャプターの並び替え・入れ子の変更
+    // --------------------------------------------------------
+    //
+    // 💡 処理の流れ（本の行をロックした1つのトランザクション）:
+    // 1. 移動先の親が、移動するチャプター自身やその子孫でないことを確認
+    // 2. 移動元の兄弟を詰め直す（親が変わる場合）
+    // 3. 移動先の兄弟の position の位置に差し込んで番号を振り直す
+    //
+    // 💡 番号を振り直す途中で一時的に同じ番号が重なるが、
+    //   book_chapters_position_key は DEFERRABLE INITIALLY DEFERRED なので COMMIT 時にだけ検査される
+    //
+    // 💡 子孫は parent_chapter_id で親についていくので、移動するのは1行だけでよい
+    pub async fn move_chapter(
+        &self,
+        book_id: i32,
+        chapter_id: i32,
+        new_parent_id: Option<i32>,
+        position: Option<u32>,
+        audit: &AuditContext,
+    ) -> Result<ChapterMove, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        lock_book(&mut tx, book_id).await?;
+
+        let current = sqlx::query_as::<_, ChapterSlot>(
+            r#"
+            SELECT chapter_id, parent_chapter_id, chapter_number
+            FROM book_chapters
+            WHERE book_id = $1 AND chapter_id = $2
+            "#
+        )
+        .bind(book_id)
+        .bind(chapter_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(current) = current else {
+            tx.rollback().await?;
+            return Ok(ChapterMove::NotFound);
+        };
+
+        if let Some(parent_id) = new_parent_id {
+            // 💡 自分自身から子孫をたどり、移動先の親が含まれていないか
+            let (into_subtree,): (bool,) = sqlx::query_as(
+                r#"
+                WITH RECURSIVE subtree AS (
+                    SELECT chapter_id FROM book_chapters WHERE chapter_id = $1
+                    UNION ALL
+                    SELECT c.chapter_id
+                    FROM book_chapters c
+                    JOIN subtree s ON c.parent_chapter_id = s.chapter_id
+                )
+                SELECT EXISTS (SELECT 1 FROM subtree WHERE chapter_id = $2)
+                "#
+            )
+            .bind(chapter_id)
+            .bind(parent_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if into_subtree {
+                tx.rollback().await?;
+                return Ok(ChapterMove::IntoOwnSubtree);
+            }
+        }
+
+        let mut old_siblings = find_siblings(&mut tx, book_id, current.parent_chapter_id).await?;
+        old_siblings.retain(|sibling| sibling.chapter_id != chapter_id);
+
+        let mut new_siblings = if new_parent_id == current.parent_chapter_id {
+            old_siblings
+        } else {
+            for (i, sibling) in old_siblings.iter().enumerate() {
+                set_position(&mut tx, sibling, current.parent_chapter_id, i as i32 + 1, audit).await?;
+            }
+            find_siblings(&mut tx, book_id, new_parent_id).await?
+        };
+
+        let index = insert_index(new_siblings.len(), position);
+        new_siblings.insert(index, current);
+
+        for (i, sibling) in new_siblings.iter().enumerate() {
+            set_position(&mut tx, sibling, new_parent_id, i as i32 + 1, audit).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(ChapterMove::Moved)
+    }
+}
+
+// --------------------------------------------------------
+// insert_index: 兄弟の中に差し込む位置（0始まり）
+// --------------------------------------------------------
+//
+// 💡 position は1始まり。None や兄弟の数より大きい値は末尾
+fn insert_index(len: usize, position: Option<u32>) -> usize {
+    position
+        .map(|position| (position as usize).saturating_sub(1).min(len))
+        .unwrap_or(len)
+}
+
+// --------------------------------------------------------
+// lock_book: 本の行をロック（チャプターの並び替えを直列にする）
+// --------------------------------------------------------
+async fn lock_book(tx: &mut Transaction<'_, Postgres>, book_id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT 1 FROM books WHERE book_id = $1 FOR UPDATE")
+        .bind(book_id)
+        .execute(&mut **tx)
+        .await?;
+
+    Ok(())
+}
+
+// --------------------------------------------------------
+// find_siblings: 同じ親を持つチャプター（順番どおり）
+// --------------------------------------------------------
+//
+// 💡 IS NOT DISTINCT FROM: NULL 同士も等しいとみなす比較（トップレベルの兄弟を取るため）
+async fn find_siblings(
+    tx: &mut Transaction<'_, Postgres>,
+    book_id: i32,
+    parent_chapter_id: Option<i32>,
+) -> Result<Vec<ChapterSlot>, sqlx::Error> {
+    let siblings = sqlx::query_as::<_, ChapterSlot>(
+        r#"
+        SELECT chapter_id, parent_chapter_id, chapter_number
+        FROM book_chapters
+        WHERE book_id = $1 AND parent_chapter_id IS NOT DISTINCT FROM $2
+        ORDER BY chapter_number ASC
+        "#
+    )
+    .bind(book_id)
+    .bind(parent_chapter_id)
+    .fetch_all(&mut **tx)
+    .await?;
+
+    Ok(siblings)
+}
+
+// --------------------------------------------------------
+// set_position: チャプターの親と番号を変更（変わる場合だけ、監査ログ付き）
+// --------------------------------------------------------
+async fn set_position(
+    tx: &mut Transaction<'_, Postgres>,
+    slot: &ChapterSlot,
+    parent_chapter_id: Option<i32>,
+    chapter_number: i32,
+    audit: &AuditContext,
+) -> Result<(), sqlx::Error> {
+    if slot.parent_chapter_id == parent_chapter_id && slot.chapter_number == chapter_number {
+        return Ok(());
+    }
+
+    let old = audit_log_repository::snapshot(tx, AuditTable::BookChapters, slot.chapter_id).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE book_chapters
+        SET parent_chapter_id = $2, chapter_number = $3, updated_at = NOW()
+        WHERE chapter_id = $1
+        "#
+    )
+    .bind(slot.chapter_id)
+    .bind(parent_chapter_id)
+    .bind(chapter_number)
+    .execute(&mut **tx)
+    .await?;
+
+    let new = audit_log_repository::snapshot(tx, AuditTable::BookChapters, slot.chapter_id).await?;
+    audit_log_repository::record(
+        tx,
+        audit,
+        AuditTable::BookChapters,
+        slot.chapter_id,
+        AuditAction::Update,
+        old,
+        new,
+    )
+    .await
+}
+
+// --------------------------------------------------------
+// upsert_book_translation / upsert_chapter_translation: 翻訳の INSERT or UPDATE
+// --------------------------------------------------------
+async fn upsert_book_translation(
+    tx: &mut Transaction<'_, Postgres>,
+    book_id: i32,
+    translation: &BookTranslationInput,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO book_translations (book_id, locale_id, title, introduction)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (book_id, locale_id)
+        DO UPDATE SET
+            title = EXCLUDED.title,
+            introduction = EXCLUDED.introduction,
+            updated_at = NOW()
+        "#
+    )
+    .bind(book_id)
+    .bind(translation.locale_id)
+    .bind(&translation.title)
+    .bind(&translation.introduction)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn upsert_chapter_translation(
+    tx: &mut Transaction<'_, Postgres>,
+    chapter_id: i32,
+    translation: &ChapterTranslationInput,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO book_chapter_translations (chapter_id, locale_id, title)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (chapter_id, locale_id)
+        DO UPDATE SET title = EXCLUDED.title, updated_at = NOW()
+        "#
+    )
+    .bind(chapter_id)
+    .bind(translation.locale_id)
+    .bind(&translation.title)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_index() {
+        assert_eq!(insert_index(3, None), 3);
+        assert_eq!(insert_index(3, Some(1)), 0);
+        assert_eq!(insert_index(3, Some(2)), 1);
+        assert_eq!(insert_index(3, Some(10)), 3);
+        assert_eq!(insert_index(0, Some(1)), 0);
+    }
+}
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+// ============================================
+// LegacyPostRepository（旧スキーマの posts テーブルの読み取り）
+// ============================================
+//
+// 💡 旧スキーマ: archive/old-migrations/001_create_base_tables.sql
+// - 言語ごとの内容を列で持つ（title_ja / content_en / seo_title_ja など）
+// - 新しいスキーマでは blog_posts + blog_post_translations（1言語 = 1行）
+//
+// 💡 読み取りだけ（移行は src/legacy.rs の import_posts）
+
+/// 旧 posts テーブルの1行（移行に使う列だけ）
+///
+/// 💡 旧スキーマでは NOT NULL の列も Option で受け取る
+///    （手で直したデータなど、スキーマどおりでない行もスキップの理由として報告するため）
+#[derive(Debug, Clone, FromRow)]
+pub struct LegacyPost {
+    pub id: i32,
+    pub slug: String,
+    pub title_ja: Option<String>,
+    pub content_ja: Option<String>,
+    pub excerpt_ja: Option<String>,
+    pub seo_title_ja: Option<String>,
+    pub seo_description_ja: Option<String>,
+    pub title_en: Option<String>,
+    pub content_en: Option<String>,
+    pub excerpt_en: Option<String>,
+    pub seo_title_en: Option<String>,
+    pub seo_description_en: Option<String>,
+    /// draft / published / scheduled / archived
+    pub status: Option<String>,
+    pub published: Option<bool>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub scheduled_at: Option<DateTime<Utc>>,
+    /// markdown 以外は移行しない
+    pub content_format: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// 旧 posts の1つの言語の内容
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyTranslation {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub excerpt: Option<String>,
+    pub seo_title: Option<String>,
+    pub seo_description: Option<String>,
+}
+
+impl LegacyPost {
+    /// 言語コードと、その言語の列の組（旧スキーマにある言語は ja / en だけ）
+    pub fn translations(&self) -> [(&'static str, LegacyTranslation); 2] {
+        [
+            (
+                "ja",
+                LegacyTranslation {
+                    title: self.title_ja.clone(),
+                    content: self.content_ja.clone(),
+                    excerpt: self.excerpt_ja.clone(),
+                    seo_title: self.seo_title_ja.clone(),
+                    seo_description: self.seo_description_ja.clone(),
+                },
+            ),
+            (
+                "en",
+                LegacyTranslation {
+                    title: self.title_en.clone(),
+                    content: self.content_en.clone(),
+                    excerpt: self.excerpt_en.clone(),
+                    seo_title: self.seo_title_en.clone(),
+                    seo_description: self.seo_description_en.clone(),
+                },
+            ),
+        ]
+    }
+}
+
+pub struct LegacyPostRepository {
+    pool: PgPool,
+}
+
+impl LegacyPostRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_all: 旧 posts の全行（id 順）
+    // --------------------------------------------------------
+    pub async fn find_all(&self) -> Result<Vec<LegacyPost>, sqlx::Error> {
+        let posts = sqlx::query_as::<_, LegacyPost>(
+            r#"
+            SELECT
+                id, slug,
+                title_ja, content_ja, excerpt_ja, seo_title_ja, seo_description_ja,
+                title_en, content_en, excerpt_en, seo_title_en, seo_description_en,
+                status, published, published_at, scheduled_at, content_format,
+                created_at, deleted_at
+            FROM posts
+            ORDER BY id ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(posts)
+    }
+}
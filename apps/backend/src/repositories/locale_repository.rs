@@ -1,5 +1,41 @@
-use sqlx::PgPool;
-use crate::entities::Locale;
+use sqlx::{PgPool, Postgres, Transaction};
+use crate::{
+    entities::{
+        AuditAction, AuditTable, Locale, LocaleReference, TranslatableEntity, TranslationCoverageRow,
+    },
+    markdown::{content_metrics, plain_text},
+    pagination::{ListParams, Page, SortKey},
+    repositories::{
+        audit_log_repository::{self, AuditContext},
+        search_repository::search_vector_sql,
+    },
+    search::SearchDocument,
+};
+
+/// 言語一覧の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocaleSort {
+    LocaleId,
+    Code,
+    Name,
+}
+
+impl SortKey for LocaleSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("locale_id", Self::LocaleId),
+        ("code", Self::Code),
+        ("name", Self::Name),
+    ];
+    const DEFAULT: Self = Self::LocaleId;
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::LocaleId => "locale_id",
+            Self::Code => "code",
+            Self::Name => "name",
+        }
+    }
+}
 
 pub struct LocaleRepository {
     pool: PgPool,
@@ -33,6 +69,7 @@ impl LocaleRepository {
     //   for locale in locales {
     //       println!("{}", locale.name);
     //   }
+    #[allow(dead_code)]
     pub async fn find_all(&self) -> Result<Vec<Locale>, sqlx::Error> {
         // ------------------------------------------------
         // sqlx::query_as! マクロ
@@ -157,6 +194,37 @@ impl LocaleRepository {
         Ok(locale)
     }
     
+    // --------------------------------------------------------
+    // find_page: 言語一覧（1ページ分）
+    // --------------------------------------------------------
+    //
+    // 💡 active_only = true のときは有効な言語のみ
+    pub async fn find_page(
+        &self,
+        active_only: bool,
+        params: &ListParams<LocaleSort>,
+    ) -> Result<Page<Locale>, sqlx::Error> {
+        let sql = format!(
+            "SELECT * FROM locales WHERE (NOT $1 OR is_active) {} LIMIT $2 OFFSET $3",
+            params.order_by("locale_id")
+        );
+
+        let locales = sqlx::query_as::<_, Locale>(&sql)
+            .bind(active_only)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM locales WHERE (NOT $1 OR is_active)")
+                .bind(active_only)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(params.finish_offset(locales, total))
+    }
+
     // --------------------------------------------------------
     // find_active: 有効な言語のみ取得
     // --------------------------------------------------------
@@ -191,6 +259,183 @@ impl LocaleRepository {
         Ok(locale)
     }
     
+    // --------------------------------------------------------
+    // find_translation_coverage: 翻訳の状況（データ × 有効な言語）
+    // --------------------------------------------------------
+    //
+    // 💡 entities: 翻訳できるデータと、その原文の言語（TranslatableEntity の表を参照）
+    // 💡 translations: 5つの翻訳テーブルを同じ形（種類, ID, 言語, 更新日時）にまとめたもの
+    // 💡 CROSS JOIN locales で「データ × 有効な言語」の全組み合わせを作り、翻訳を LEFT JOIN する
+    //    → 翻訳がない組み合わせは translation_updated_at が NULL
+    //
+    // 💡 原文の言語が無効化されていても原文との比較には使う（行として出すのは有効な言語だけ）
+    pub async fn find_translation_coverage(
+        &self,
+        entity_type: Option<TranslatableEntity>,
+    ) -> Result<Vec<TranslationCoverageRow>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, TranslationCoverageRow>(
+            r#"
+            WITH site_default AS (
+                SELECT locale_id FROM locales WHERE is_default = TRUE
+            ),
+            entities AS (
+                SELECT 'post'::varchar AS entity_type, p.post_id AS entity_id, p.slug::text AS label,
+                       COALESCE(p.default_locale_id, (SELECT locale_id FROM site_default)) AS source_locale_id
+                FROM blog_posts p
+                UNION ALL
+                SELECT 'topic', t.topic_id, t.slug::text, (SELECT locale_id FROM site_default)
+                FROM topics t
+                UNION ALL
+                SELECT 'book', b.book_id, b.slug::text,
+                       COALESCE(b.default_locale_id, (SELECT locale_id FROM site_default))
+                FROM books b
+                UNION ALL
+                SELECT 'book_chapter', c.chapter_id, b.slug || '/' || c.slug,
+                       COALESCE(b.default_locale_id, (SELECT locale_id FROM site_default))
+                FROM book_chapters c
+                JOIN books b ON b.book_id = c.book_id
+                UNION ALL
+                SELECT 'media', m.media_id, m.original_filename::text, (SELECT locale_id FROM site_default)
+                FROM media m
+            ),
+            translations AS (
+                SELECT 'post'::varchar AS entity_type, post_id AS entity_id, locale_id, updated_at
+                FROM blog_post_translations
+                UNION ALL
+                SELECT 'topic', topic_id, locale_id, updated_at FROM topic_translations
+                UNION ALL
+                SELECT 'book', book_id, locale_id, updated_at FROM book_translations
+                UNION ALL
+                SELECT 'book_chapter', chapter_id, locale_id, updated_at FROM book_chapter_translations
+                UNION ALL
+                SELECT 'media', media_id, locale_id, updated_at FROM media_translations
+            )
+            SELECT
+                e.entity_type, e.entity_id, e.label,
+                sl.code AS source_locale_code,
+                src.updated_at AS source_updated_at,
+                l.code AS locale_code,
+                tr.updated_at AS translation_updated_at
+            FROM entities e
+            CROSS JOIN locales l
+            LEFT JOIN locales sl ON sl.locale_id = e.source_locale_id
+            LEFT JOIN translations src
+                   ON src.entity_type = e.entity_type
+                  AND src.entity_id = e.entity_id
+                  AND src.locale_id = e.source_locale_id
+            LEFT JOIN translations tr
+                   ON tr.entity_type = e.entity_type
+                  AND tr.entity_id = e.entity_id
+                  AND tr.locale_id = l.locale_id
+            WHERE l.is_active = TRUE
+              AND ($1::varchar IS NULL OR e.entity_type = $1)
+            ORDER BY e.entity_type ASC, e.entity_id ASC, l.locale_id ASC
+            "#
+        )
+        .bind(entity_type)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    // --------------------------------------------------------
+    // find_references: 言語を参照している行（無効化・削除の影響）
+    // --------------------------------------------------------
+    //
+    // 💡 参照している列は LocaleReferenceKind の表を参照
+    // - 翻訳テーブルと版の locale_id は ON DELETE RESTRICT なので、1行でもあると言語を削除できない
+    //
+    // 💡 migrate_to: 移行先の言語（Some のときだけ conflict を調べる）
+    // - 翻訳: 移行先の言語に同じデータの翻訳がある
+    // - 版: 移行先の言語に同じ記事・同じ版番号の版がある
+    pub async fn find_references(
+        &self,
+        locale_id: i32,
+        migrate_to: Option<i32>,
+    ) -> Result<Vec<LocaleReference>, sqlx::Error> {
+        let references = sqlx::query_as::<_, LocaleReference>(
+            r#"
+            SELECT 'blog_post_translations'::varchar AS table_name, 'translation'::varchar AS kind,
+                   'post'::varchar AS entity_type, p.post_id AS entity_id, p.slug::text AS label,
+                   1::bigint AS row_count,
+                   EXISTS (
+                       SELECT 1 FROM blog_post_translations x
+                       WHERE x.post_id = tr.post_id AND x.locale_id = $2
+                   ) AS conflict
+            FROM blog_post_translations tr
+            JOIN blog_posts p ON p.post_id = tr.post_id
+            WHERE tr.locale_id = $1
+            UNION ALL
+            SELECT 'topic_translations', 'translation', 'topic', t.topic_id, t.slug::text, 1,
+                   EXISTS (
+                       SELECT 1 FROM topic_translations x
+                       WHERE x.topic_id = tr.topic_id AND x.locale_id = $2
+                   )
+            FROM topic_translations tr
+            JOIN topics t ON t.topic_id = tr.topic_id
+            WHERE tr.locale_id = $1
+            UNION ALL
+            SELECT 'book_translations', 'translation', 'book', b.book_id, b.slug::text, 1,
+                   EXISTS (
+                       SELECT 1 FROM book_translations x
+                       WHERE x.book_id = tr.book_id AND x.locale_id = $2
+                   )
+            FROM book_translations tr
+            JOIN books b ON b.book_id = tr.book_id
+            WHERE tr.locale_id = $1
+            UNION ALL
+            SELECT 'book_chapter_translations', 'translation', 'book_chapter', c.chapter_id,
+                   b.slug || '/' || c.slug, 1,
+                   EXISTS (
+                       SELECT 1 FROM book_chapter_translations x
+                       WHERE x.chapter_id = tr.chapter_id AND x.locale_id = $2
+                   )
+            FROM book_chapter_translations tr
+            JOIN book_chapters c ON c.chapter_id = tr.chapter_id
+            JOIN books b ON b.book_id = c.book_id
+            WHERE tr.locale_id = $1
+            UNION ALL
+            SELECT 'media_translations', 'translation', 'media', m.media_id, m.original_filename::text, 1,
+                   EXISTS (
+                       SELECT 1 FROM media_translations x
+                       WHERE x.media_id = tr.media_id AND x.locale_id = $2
+                   )
+            FROM media_translations tr
+            JOIN media m ON m.media_id = tr.media_id
+            WHERE tr.locale_id = $1
+            UNION ALL
+            SELECT 'blog_post_translation_revisions', 'revision', 'post', p.post_id, p.slug::text,
+                   COUNT(*),
+                   BOOL_OR(EXISTS (
+                       SELECT 1 FROM blog_post_translation_revisions x
+                       WHERE x.post_id = r.post_id
+                         AND x.locale_id = $2
+                         AND x.revision_number = r.revision_number
+                   ))
+            FROM blog_post_translation_revisions r
+            JOIN blog_posts p ON p.post_id = r.post_id
+            WHERE r.locale_id = $1
+            GROUP BY p.post_id, p.slug
+            UNION ALL
+            SELECT 'blog_posts', 'default_locale', 'post', post_id, slug::text, 1, FALSE
+            FROM blog_posts
+            WHERE default_locale_id = $1
+            UNION ALL
+            SELECT 'books', 'default_locale', 'book', book_id, slug::text, 1, FALSE
+            FROM books
+            WHERE default_locale_id = $1
+            ORDER BY entity_type ASC, entity_id ASC, table_name ASC
+            "#
+        )
+        .bind(locale_id)
+        .bind(migrate_to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(references)
+    }
+
     // --------------------------------------------------------
     // count: 言語の総数を取得
     // --------------------------------------------------------
@@ -206,6 +451,7 @@ impl LocaleRepository {
     // 💡 使用例:
     //   let total = repo.count().await?;
     //   println!("Total locales: {}", total);
+    #[allow(dead_code)]
     pub async fn count(&self) -> Result<i64, sqlx::Error> {
         // ------------------------------------------------
         // COUNT(*)の結果を取得
@@ -228,6 +474,500 @@ impl LocaleRepository {
         Ok(result.0)
         // result.0 は i64 のカウント値
     }
+
+    // --------------------------------------------------------
+    // create: 言語を新規作成
+    // --------------------------------------------------------
+    //
+    // 💡 is_default = true で作成する場合:
+    // - 既存のデフォルト言語を FALSE に戻してから INSERT する
+    // - 2つの更新を1つのトランザクションで行う（set_defaultと同じ考え方）
+    //
+    // 💡 エラー:
+    // - codeが重複している場合、UNIQUE制約違反（sqlx::Error::Database）
+    pub async fn create(
+        &self,
+        code: &str,
+        name: &str,
+        is_default: bool,
+        is_active: bool,
+        audit: &AuditContext,
+    ) -> Result<Locale, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        if is_default {
+            Self::lock_defaults(&mut tx).await?;
+            Self::clear_default(&mut tx, None, audit).await?;
+        }
+
+        let locale = sqlx::query_as::<_, Locale>(
+            r#"
+            INSERT INTO locales (code, name, is_default, is_active)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#
+        )
+        .bind(code)
+        .bind(name)
+        .bind(is_default)
+        .bind(is_active)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, locale.locale_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Locales,
+            locale.locale_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(locale)
+    }
+
+    // --------------------------------------------------------
+    // update: 言語名・有効フラグを部分更新
+    // --------------------------------------------------------
+    //
+    // 💡 COALESCE($2, name):
+    // - $2 が NULL（= Noneが渡された）なら現在の値のまま
+    // - 値が渡された項目だけを更新できる（PATCHの考え方）
+    //
+    // 💡 デフォルト言語は無効化できない:
+    // - WHERE句の (is_default = FALSE OR COALESCE($3, TRUE)) により、
+    //   「デフォルト言語の is_active を FALSE にする」UPDATEは0行になる
+    //
+    // 💡 戻り値:
+    // - Ok(Some(locale)) : 更新成功（RETURNING * で更新後の行を返す）
+    // - Ok(None)         : 該当する言語がない、またはデフォルト言語の無効化
+    pub async fn update(
+        &self,
+        code: &str,
+        name: Option<&str>,
+        is_active: Option<bool>,
+        audit: &AuditContext,
+    ) -> Result<Option<Locale>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let locale_id: Option<(i32,)> = sqlx::query_as("SELECT locale_id FROM locales WHERE code = $1")
+            .bind(code)
+            .fetch_optional(&mut *tx)
+            .await?;
+        let Some((locale_id,)) = locale_id else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+        let old = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, locale_id).await?;
+
+        let locale = sqlx::query_as::<_, Locale>(
+            r#"
+            UPDATE locales
+            SET name = COALESCE($2, name),
+                is_active = COALESCE($3, is_active)
+            WHERE locale_id = $1
+              AND (is_default = FALSE OR COALESCE($3, TRUE))
+            RETURNING *
+            "#
+        )
+        .bind(locale_id)
+        .bind(name)
+        .bind(is_active)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(locale) = locale else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, locale_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Locales,
+            locale_id,
+            AuditAction::Update,
+            old,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(locale))
+    }
+
+    // --------------------------------------------------------
+    // set_default: デフォルト言語を切り替え（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 なぜトランザクション?
+    // - 「旧デフォルトをFALSE」「新デフォルトをTRUE」の2つの更新が必要
+    // - 片方だけ成功すると、デフォルト言語が0個または2個になってしまう
+    // - トランザクション内なら、両方成功するか両方失敗するかのどちらか
+    //
+    // 💡 対象の言語が無効（is_active = FALSE）または存在しない場合:
+    // - 何も変更せずに Ok(None) を返す
+    pub async fn set_default(
+        &self,
+        code: &str,
+        audit: &AuditContext,
+    ) -> Result<Option<Locale>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        Self::lock_defaults(&mut tx).await?;
+
+        let target = sqlx::query_as::<_, Locale>(
+            "SELECT * FROM locales WHERE code = $1 AND is_active = TRUE"
+        )
+        .bind(code)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(target) = target else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        Self::clear_default(&mut tx, Some(target.locale_id), audit).await?;
+
+        let old = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, target.locale_id).await?;
+        let locale = sqlx::query_as::<_, Locale>(
+            "UPDATE locales SET is_default = TRUE WHERE locale_id = $1 RETURNING *"
+        )
+        .bind(target.locale_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, target.locale_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Locales,
+            target.locale_id,
+            AuditAction::Update,
+            old,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(locale))
+    }
+
+    // --------------------------------------------------------
+    // deactivate: 言語を無効化（参照を別の言語に付け替えてから）
+    // --------------------------------------------------------
+    //
+    // 💡 migrate_to を指定すると、1つのトランザクションで次を行う
+    //    1. 翻訳テーブル・版の locale_id を移行先の言語に付け替える
+    //    2. blog_posts / books の default_locale_id を付け替える
+    //    3. 言語を無効化する（is_active = FALSE）
+    //    → 途中で失敗すると全部取り消される（付け替えだけ済んだ状態にはならない）
+    //
+    // 💡 記事の翻訳は、読了目安・抜粋・search_vector を移行先の言語の方法で作り直す
+    // 💡 監査ログ: 付け替えたデータ（記事・トピックなど）ごとに UPDATE を記録する
+    //
+    // 💡 移行先に同じデータの翻訳がある場合は UNIQUE制約違反になる
+    //    （ハンドラーで find_references の conflict を先に確認しておく）
+    //
+    // 💡 戻り値:
+    // - Ok(Some((locale, moved))) : 無効化した言語と、付け替えた行数
+    // - Ok(None)                  : 言語がない、デフォルト言語、または移行先が有効な言語でない
+    pub async fn deactivate(
+        &self,
+        locale_id: i32,
+        migrate_to: Option<i32>,
+        audit: &AuditContext,
+    ) -> Result<Option<(Locale, u64)>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // 💡 デフォルトの切り替え・言語の更新と同時に実行されないようにする
+        Self::lock_defaults(&mut tx).await?;
+
+        let mut moved = 0;
+        if let Some(target_id) = migrate_to {
+            let target: Option<(i32,)> = sqlx::query_as(
+                "SELECT locale_id FROM locales WHERE locale_id = $1 AND is_active = TRUE AND locale_id <> $2"
+            )
+            .bind(target_id)
+            .bind(locale_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+            if target.is_none() {
+                tx.rollback().await?;
+                return Ok(None);
+            }
+
+            moved = Self::migrate_references(&mut tx, locale_id, target_id, audit).await?;
+        }
+
+        let old = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, locale_id).await?;
+        let locale = sqlx::query_as::<_, Locale>(
+            r#"
+            UPDATE locales
+            SET is_active = FALSE
+            WHERE locale_id = $1 AND is_default = FALSE
+            RETURNING *
+            "#
+        )
+        .bind(locale_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(locale) = locale else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, locale_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Locales,
+            locale_id,
+            AuditAction::Update,
+            old,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some((locale, moved)))
+    }
+
+    // --------------------------------------------------------
+    // delete: 言語を削除
+    // --------------------------------------------------------
+    //
+    // 💡 参照している行が残っていると、外部キー（ON DELETE RESTRICT）でエラーになる
+    //    → AppError では 409 foreign_key_violation
+    // 💡 デフォルト言語は WHERE句で弾く（0行 = false）
+    pub async fn delete(&self, locale_id: i32, audit: &AuditContext) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        Self::lock_defaults(&mut tx).await?;
+
+        let old = audit_log_repository::snapshot(&mut tx, AuditTable::Locales, locale_id).await?;
+        let deleted = sqlx::query("DELETE FROM locales WHERE locale_id = $1 AND is_default = FALSE")
+            .bind(locale_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+        if deleted == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Locales,
+            locale_id,
+            AuditAction::Delete,
+            old,
+            None,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    // --------------------------------------------------------
+    // migrate_references: 言語の参照を別の言語に付け替える（deactivate から呼ぶ）
+    // --------------------------------------------------------
+    //
+    // 💡 監査ログのスナップショットは親テーブルごと（翻訳は親のスナップショットに含まれる）
+    //    → 付け替える前に全部の old を取り、付け替えた後に new を取って記録する
+    async fn migrate_references(
+        tx: &mut Transaction<'_, Postgres>,
+        from: i32,
+        to: i32,
+        audit: &AuditContext,
+    ) -> Result<u64, sqlx::Error> {
+        // (監査ログのテーブル, 付け替えの対象になる親のIDを返すSQL)
+        let parents = [
+            (
+                AuditTable::BlogPosts,
+                r#"
+                SELECT post_id FROM blog_post_translations WHERE locale_id = $1
+                UNION
+                SELECT post_id FROM blog_post_translation_revisions WHERE locale_id = $1
+                UNION
+                SELECT post_id FROM blog_posts WHERE default_locale_id = $1
+                ORDER BY 1
+                "#,
+            ),
+            (AuditTable::Topics, "SELECT topic_id FROM topic_translations WHERE locale_id = $1 ORDER BY 1"),
+            (
+                AuditTable::Books,
+                r#"
+                SELECT book_id FROM book_translations WHERE locale_id = $1
+                UNION
+                SELECT book_id FROM books WHERE default_locale_id = $1
+                ORDER BY 1
+                "#,
+            ),
+            (
+                AuditTable::BookChapters,
+                "SELECT chapter_id FROM book_chapter_translations WHERE locale_id = $1 ORDER BY 1",
+            ),
+            (AuditTable::Media, "SELECT media_id FROM media_translations WHERE locale_id = $1 ORDER BY 1"),
+        ];
+
+        let mut snapshots = Vec::new();
+        for (table, sql) in parents {
+            let ids: Vec<(i32,)> = sqlx::query_as(sql).bind(from).fetch_all(&mut **tx).await?;
+            for (id,) in ids {
+                let old = audit_log_repository::snapshot(tx, table, id).await?;
+                snapshots.push((table, id, old));
+            }
+        }
+
+        // 💡 版の locale_id はトリガーで変更できないので、このトランザクションの間だけ許可する（migration 016）
+        sqlx::query("SELECT set_config('blog.migrating_locale', 'on', true)")
+            .execute(&mut **tx)
+            .await?;
+
+        let mut moved = 0;
+        for sql in [
+            "UPDATE topic_translations SET locale_id = $2 WHERE locale_id = $1",
+            "UPDATE book_translations SET locale_id = $2 WHERE locale_id = $1",
+            "UPDATE book_chapter_translations SET locale_id = $2 WHERE locale_id = $1",
+            "UPDATE media_translations SET locale_id = $2 WHERE locale_id = $1",
+            "UPDATE blog_post_translation_revisions SET locale_id = $2 WHERE locale_id = $1",
+            "UPDATE blog_posts SET default_locale_id = $2 WHERE default_locale_id = $1",
+            "UPDATE books SET default_locale_id = $2 WHERE default_locale_id = $1",
+        ] {
+            moved += sqlx::query(sql)
+                .bind(from)
+                .bind(to)
+                .execute(&mut **tx)
+                .await?
+                .rows_affected();
+        }
+
+        // ------------------------------------------------
+        // 記事の翻訳: 読了目安・抜粋・search_vector は言語ごとに作り方が違う
+        // ------------------------------------------------
+        //
+        // 💡 updated_at は変えない（内容は変わっていないため。search reindex と同じ）
+        let post_translations: Vec<(i32, String, Option<String>, String)> = sqlx::query_as(
+            r#"
+            UPDATE blog_post_translations
+            SET locale_id = $2
+            WHERE locale_id = $1
+            RETURNING translation_id, title, summary, content
+            "#
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        if !post_translations.is_empty() {
+            let (target_code,): (String,) = sqlx::query_as("SELECT code FROM locales WHERE locale_id = $1")
+                .bind(to)
+                .fetch_one(&mut **tx)
+                .await?;
+            let sql = format!(
+                r#"
+                UPDATE blog_post_translations
+                SET reading_time_minutes = $2, excerpt = $3, search_vector = {}
+                WHERE translation_id = $1
+                "#,
+                search_vector_sql("$4", "$5", "title", "summary", "$6"),
+            );
+
+            for (translation_id, title, summary, content) in &post_translations {
+                let metrics = content_metrics(content, &target_code);
+                let search = SearchDocument::new(&target_code, title, summary.as_deref(), plain_text(content));
+                sqlx::query(&sql)
+                    .bind(translation_id)
+                    .bind(metrics.reading_time_minutes)
+                    .bind(&metrics.excerpt)
+                    .bind(search.config)
+                    .bind(&search.vector)
+                    .bind(&search.content_text)
+                    .execute(&mut **tx)
+                    .await?;
+            }
+            moved += post_translations.len() as u64;
+        }
+
+        for (table, id, old) in snapshots {
+            let new = audit_log_repository::snapshot(tx, table, id).await?;
+            audit_log_repository::record(tx, audit, table, id, AuditAction::Update, old, new).await?;
+        }
+
+        Ok(moved)
+    }
+
+    // --------------------------------------------------------
+    // clear_default: 現在のデフォルト言語を解除（監査ログ付き）
+    // --------------------------------------------------------
+    //
+    // 💡 keep: 解除しない言語（set_default で新しいデフォルトにする言語）
+    // 💡 lock_defaults の後に呼ぶこと
+    async fn clear_default(
+        tx: &mut Transaction<'_, Postgres>,
+        keep: Option<i32>,
+        audit: &AuditContext,
+    ) -> Result<(), sqlx::Error> {
+        let defaults: Vec<(i32,)> = sqlx::query_as(
+            "SELECT locale_id FROM locales WHERE is_default = TRUE AND locale_id IS DISTINCT FROM $1"
+        )
+        .bind(keep)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        for (locale_id,) in defaults {
+            let old = audit_log_repository::snapshot(tx, AuditTable::Locales, locale_id).await?;
+            sqlx::query("UPDATE locales SET is_default = FALSE WHERE locale_id = $1")
+                .bind(locale_id)
+                .execute(&mut **tx)
+                .await?;
+            let new = audit_log_repository::snapshot(tx, AuditTable::Locales, locale_id).await?;
+            audit_log_repository::record(
+                tx,
+                audit,
+                AuditTable::Locales,
+                locale_id,
+                AuditAction::Update,
+                old,
+                new,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    // --------------------------------------------------------
+    // lock_defaults: デフォルト切り替え中の同時更新を防ぐ
+    // --------------------------------------------------------
+    //
+    // 💡 SHARE ROW EXCLUSIVE:
+    // - 同じモードのロック同士がぶつかるので、デフォルト切り替えは1つずつ順番に実行される
+    // - 通常のSELECTはブロックしない
+    // - ロックはトランザクション終了（COMMIT / ROLLBACK）で自動的に解放
+    async fn lock_defaults(tx: &mut Transaction<'_, Postgres>) -> Result<(), sqlx::Error> {
+        sqlx::query("LOCK TABLE locales IN SHARE ROW EXCLUSIVE MODE")
+            .execute(&mut **tx)
+            .await?;
+
+        Ok(())
+    }
 }
 
 // ============================================
@@ -0,0 +1,441 @@
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{
+    entities::{AuditAction, AuditTable, LocalizedMedia, Media, MediaReference, MediaTranslation},
+    pagination::{ListParams, Page, SortDirection, SortKey},
+    repositories::audit_log_repository::{self, AuditContext},
+};
+
+// ============================================
+// MediaRepository（メディアのデータアクセス）
+// ============================================
+//
+// 💡 このリポジトリが扱うのはメタ情報（media / media_translations）だけ
+// - ファイル本体の保存・削除はハンドラーが storage::MediaStorage で行う
+// - アップロード: ストレージに保存 → create（失敗したらファイルを消す）
+// - 削除: delete（参照がなければ行を削除）→ コミット後にファイルを消す
+//
+// 💡 多言語の解決ルール（alt_text / caption）:
+// 1. 要求された言語（requested）の翻訳があればそれを使う
+// 2. なければデフォルト言語（fallback）の翻訳を使う
+// 3. どちらもなければ NULL
+
+/// メディアの作成用の入力
+#[derive(Debug, Clone)]
+pub struct NewMedia {
+    /// ストレージに保存したファイル名（例: 3f2a....png）
+    pub filename: String,
+    /// アップロードされたときのファイル名
+    pub original_filename: String,
+    /// ストレージのキー
+    pub file_path: String,
+    pub file_type: String,
+    pub file_size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub uploaded_by: Option<i32>,
+}
+
+/// 翻訳の作成・更新用の入力
+#[derive(Debug, Clone)]
+pub struct MediaTranslationInput {
+    pub locale_id: i32,
+    pub alt_text: Option<String>,
+    pub caption: Option<String>,
+}
+
+/// メディア一覧の絞り込み条件
+#[derive(Debug, Clone, Default)]
+pub struct MediaFilter {
+    /// MIMEタイプの前方一致（例: image/ → 画像のみ）
+    pub file_type: Option<String>,
+}
+
+/// delete の結果
+#[derive(Debug)]
+pub enum MediaDelete {
+    Deleted(Media),
+    NotFound,
+    /// まだ使われている（削除しなかった）
+    Referenced(Vec<MediaReference>),
+}
+
+/// メディア一覧の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaSort {
+    CreatedAt,
+    FileSize,
+    OriginalFilename,
+}
+
+impl SortKey for MediaSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("created_at", Self::CreatedAt),
+        ("file_size", Self::FileSize),
+        ("original_filename", Self::OriginalFilename),
+    ];
+    const DEFAULT: Self = Self::CreatedAt;
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::CreatedAt => "m.created_at",
+            Self::FileSize => "m.file_size",
+            Self::OriginalFilename => "m.original_filename",
+        }
+    }
+
+    fn default_direction(self) -> SortDirection {
+        match self {
+            Self::OriginalFilename => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// LOCALIZED_SELECT: 言語解決付きのSELECT
+// --------------------------------------------------------
+//
+// 💡 トピックと同じく LEFT JOIN LATERAL で「$1 を優先して $1 か $2 の翻訳を1件」取り出す
+const LOCALIZED_SELECT: &str = r#"
+    SELECT
+        m.media_id, m.filename, m.original_filename, m.file_path, m.file_type, m.file_size,
+        m.width, m.height, m.uploaded_by, m.created_at,
+        tr.alt_text, tr.caption, tr.locale_code
+    FROM media m
+    LEFT JOIN LATERAL (
+        SELECT mt.alt_text, mt.caption, l.code AS locale_code
+        FROM media_translations mt
+        JOIN locales l ON l.locale_id = mt.locale_id
+        WHERE mt.media_id = m.media_id
+          AND l.code IN ($1, $2)
+        ORDER BY (l.code = $1) DESC
+        LIMIT 1
+    ) tr ON TRUE
+"#;
+
+// --------------------------------------------------------
+// REFERENCES: メディアを使っている記事・本
+// --------------------------------------------------------
+//
+// 💡 $1 = media_id, $2 = file_path（ストレージのキー）
+// - アイキャッチは外部キーで確実にわかる
+// - URLで参照する場所（OGP画像・本文・本のトップ画像）はキーを含むかで探す
+//   → 公開URLの先頭（/media や CDN のURL）が変わっても見つけられる
+// - 'featured_image'::varchar: MediaUsage（varchar）として読むためのキャスト
+const REFERENCES: &str = r#"
+    SELECT 'featured_image'::varchar AS usage, p.slug
+    FROM blog_posts p
+    WHERE p.featured_image_id = $1
+    UNION
+    SELECT 'meta_image'::varchar, p.slug
+    FROM blog_posts p
+    WHERE strpos(p.meta_image_url, $2) > 0
+    UNION
+    SELECT 'post_content'::varchar, p.slug
+    FROM blog_posts p
+    JOIN blog_post_translations tr ON tr.post_id = p.post_id
+    WHERE strpos(tr.content, $2) > 0
+    UNION
+    SELECT 'book_top_image'::varchar, b.slug
+    FROM books b
+    WHERE strpos(b.top_image_url, $2) > 0
+    ORDER BY usage, slug
+"#;
+
+pub struct MediaRepository {
+    pool: PgPool,
+}
+
+impl MediaRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_page_localized: メディア一覧（1ページ分、言語解決済み）
+    // --------------------------------------------------------
+    pub async fn find_page_localized(
+        &self,
+        requested: &str,
+        fallback: &str,
+        filter: &MediaFilter,
+        params: &ListParams<MediaSort>,
+    ) -> Result<Page<LocalizedMedia>, sqlx::Error> {
+        let sql = format!(
+            "{LOCALIZED_SELECT} WHERE ($3::text IS NULL OR m.file_type LIKE $3 || '%') {} LIMIT $4 OFFSET $5",
+            params.order_by("m.media_id")
+        );
+
+        let media = sqlx::query_as::<_, LocalizedMedia>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(&filter.file_type)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM media m WHERE ($1::text IS NULL OR m.file_type LIKE $1 || '%')",
+        )
+        .bind(&filter.file_type)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(params.finish_offset(media, total))
+    }
+
+    // --------------------------------------------------------
+    // find_by_id_localized: IDで1件取得（言語解決済み）
+    // --------------------------------------------------------
+    pub async fn find_by_id_localized(
+        &self,
+        media_id: i32,
+        requested: &str,
+        fallback: &str,
+    ) -> Result<Option<LocalizedMedia>, sqlx::Error> {
+        let sql = format!("{LOCALIZED_SELECT} WHERE m.media_id = $3");
+
+        let media = sqlx::query_as::<_, LocalizedMedia>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(media_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(media)
+    }
+
+    // --------------------------------------------------------
+    // find_by_id: IDで1件取得（翻訳なし）
+    // --------------------------------------------------------
+    pub async fn find_by_id(&self, media_id: i32) -> Result<Option<Media>, sqlx::Error> {
+        let media = sqlx::query_as::<_, Media>("SELECT * FROM media WHERE media_id = $1")
+            .bind(media_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(media)
+    }
+
+    // --------------------------------------------------------
+    // find_by_file_path: ストレージのキーで1件取得（ファイルの配信用）
+    // --------------------------------------------------------
+    pub async fn find_by_file_path(&self, file_path: &str) -> Result<Option<Media>, sqlx::Error> {
+        let media = sqlx::query_as::<_, Media>("SELECT * FROM media WHERE file_path = $1")
+            .bind(file_path)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(media)
+    }
+
+    // --------------------------------------------------------
+    // find_translations: メディアの全翻訳
+    // --------------------------------------------------------
+    pub async fn find_translations(&self, media_id: i32) -> Result<Vec<MediaTranslation>, sqlx::Error> {
+        let translations = sqlx::query_as::<_, MediaTranslation>(
+            r#"
+            SELECT mt.translation_id, mt.media_id, mt.locale_id, l.code AS locale_code,
+                   mt.alt_text, mt.caption, mt.created_at, mt.updated_at
+            FROM media_translations mt
+            JOIN locales l ON l.locale_id = mt.locale_id
+            WHERE mt.media_id = $1
+            ORDER BY l.locale_id ASC
+            "#
+        )
+        .bind(media_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(translations)
+    }
+
+    // --------------------------------------------------------
+    // find_references: メディアを使っている記事・本
+    // --------------------------------------------------------
+    pub async fn find_references(&self, media: &Media) -> Result<Vec<MediaReference>, sqlx::Error> {
+        let references = sqlx::query_as::<_, MediaReference>(REFERENCES)
+            .bind(media.media_id)
+            .bind(&media.file_path)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(references)
+    }
+
+    // --------------------------------------------------------
+    // create: メディアと翻訳をまとめて作成（トランザクション）
+    // --------------------------------------------------------
+    pub async fn create(
+        &self,
+        media: &NewMedia,
+        translations: &[MediaTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<Media, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Media>(
+            r#"
+            INSERT INTO media (
+                filename, original_filename, file_path, file_type, file_size,
+                width, height, uploaded_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#
+        )
+        .bind(&media.filename)
+        .bind(&media.original_filename)
+        .bind(&media.file_path)
+        .bind(&media.file_type)
+        .bind(media.file_size)
+        .bind(media.width)
+        .bind(media.height)
+        .bind(media.uploaded_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_translation(&mut tx, created.media_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Media, created.media_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Media,
+            created.media_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    // --------------------------------------------------------
+    // update_translations: 翻訳のUPSERT（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 ファイル本体・サイズなどは変更できない（差し替えは新しくアップロードする）
+    //
+    // 💡 戻り値:
+    // - Ok(false): 該当するメディアがない
+    pub async fn update_translations(
+        &self,
+        media_id: i32,
+        translations: &[MediaTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) = audit_log_repository::snapshot(&mut tx, AuditTable::Media, media_id).await? else {
+            tx.rollback().await?;
+            return Ok(false);
+        };
+
+        for translation in translations {
+            upsert_translation(&mut tx, media_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Media, media_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Media,
+            media_id,
+            AuditAction::Update,
+            Some(old),
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    // --------------------------------------------------------
+    // delete: 参照がなければメディアを削除（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 参照のチェックと削除を同じトランザクションで行う
+    // - snapshot の FOR UPDATE で行をロックしてから参照を数える
+    // - アイキャッチは外部キー（ON DELETE RESTRICT）でも守られる
+    //
+    // 💡 ファイル本体は消さない（コミット後にハンドラーが消す）
+    pub async fn delete(&self, media_id: i32, audit: &AuditContext) -> Result<MediaDelete, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) = audit_log_repository::snapshot(&mut tx, AuditTable::Media, media_id).await? else {
+            tx.rollback().await?;
+            return Ok(MediaDelete::NotFound);
+        };
+
+        let media = sqlx::query_as::<_, Media>("SELECT * FROM media WHERE media_id = $1")
+            .bind(media_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let references = sqlx::query_as::<_, MediaReference>(REFERENCES)
+            .bind(media.media_id)
+            .bind(&media.file_path)
+            .fetch_all(&mut *tx)
+            .await?;
+        if !references.is_empty() {
+            tx.rollback().await?;
+            return Ok(MediaDelete::Referenced(references));
+        }
+
+        sqlx::query("DELETE FROM media WHERE media_id = $1")
+            .bind(media_id)
+            .execute(&mut *tx)
+            .await?;
+
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Media,
+            media_id,
+            AuditAction::Delete,
+            Some(old),
+            None,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(MediaDelete::Deleted(media))
+    }
+}
+
+// --------------------------------------------------------
+// upsert_translation: 翻訳の INSERT or UPDATE
+// --------------------------------------------------------
+async fn upsert_translation(
+    tx: &mut Transaction<'_, Postgres>,
+    media_id: i32,
+    translation: &MediaTranslationInput,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO media_translations (media_id, locale_id, alt_text, caption)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (media_id, locale_id)
+        DO UPDATE SET alt_text = EXCLUDED.alt_text, caption = EXCLUDED.caption, updated_at = NOW()
+        "#
+    )
+    .bind(media_id)
+    .bind(translation.locale_id)
+    .bind(&translation.alt_text)
+    .bind(&translation.caption)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
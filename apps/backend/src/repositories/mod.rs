@@ -19,19 +19,48 @@
 //   ↓
 // Database（PostgreSQL）
 
+pub mod admin_repository;
+pub use admin_repository::{AdminRepository, NewAdmin, NewSession};
+
+pub mod audit_log_repository;
+pub use audit_log_repository::{AuditContext, AuditLogFilter, AuditLogRepository, AuditLogSort};
+
+pub mod api_key_repository;
+pub use api_key_repository::{ApiKeyRepository, NewApiKey};
+
 // locale_repositoryモジュールを公開
 pub mod locale_repository;
 
 // LocaleRepositoryを再エクスポート
-pub use locale_repository::LocaleRepository;
+pub use locale_repository::{LocaleRepository, LocaleSort};
 
-// ============================================
-// 💡 将来の拡張例
-// ============================================
-// 他のテーブル用のRepositoryも同様に追加していく:
-//
-// pub mod topic_repository;
-// pub use topic_repository::TopicRepository;
-//
-// pub mod post_repository;
-// pub use post_repository::PostRepository;
+pub mod topic_repository;
+pub use topic_repository::{TopicRepository, TopicSort, TopicTranslationInput};
+
+pub mod post_repository;
+pub use post_repository::{
+    BookLink, NewPost, PostChanges, PostFilter, PostRepository, PostSort, PostTranslationInput,
+    ScheduledPostSort,
+};
+
+pub mod book_repository;
+pub use book_repository::{
+    BookChanges, BookRepository, BookSort, BookTranslationInput, ChapterMove,
+    ChapterTranslationInput, NewBook, NewChapter,
+};
+
+pub mod media_repository;
+pub use media_repository::{
+    MediaDelete, MediaFilter, MediaRepository, MediaSort, MediaTranslationInput, NewMedia,
+};
+
+pub mod search_repository;
+pub use search_repository::{SearchRepository, SearchSort};
+
+pub mod sitemap_repository;
+pub use sitemap_repository::SitemapRepository;
+
+pub mod legacy_post_repository;
+
+pub mod revision_repository;
+pub use revision_repository::{RevisionRepository, RevisionSort};
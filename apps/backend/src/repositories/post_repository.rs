@@ -0,0 +1,835 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{
+    entities::{
+        AuditAction, AuditTable, LocalizedPost, Post, PostFeedItem, PostSummary, PostTranslation,
+        ScheduledPost,
+    },
+    pagination::{Cursor, ListParams, Page, SortDirection, SortKey},
+    repositories::{
+        audit_log_repository::{self, AuditContext},
+        revision_repository,
+        search_repository::search_vector_sql,
+    },
+    search::SearchDocument,
+};
+
+// ============================================
+// PostRepository（ブログ記事のデータアクセス）
+// ============================================
+//
+// 💡 多言語の解決ルール（優先度の高い順）:
+// 1. 要求された言語（requested）の翻訳
+// 2. 記事の原文言語（blog_posts.default_locale_id）の翻訳
+// 3. サイトのデフォルト言語（fallback）の翻訳
+//
+// 💡 無効化された言語（locales.is_active = FALSE）の翻訳は使わない
+
+/// 記事の作成用の入力
+#[derive(Debug, Clone)]
+pub struct NewPost {
+    pub slug: String,
+    pub meta_image_url: Option<String>,
+    pub is_published: bool,
+    /// 公開日時（None なら作成時の現在時刻。旧データの移行で元の公開日時を残すときに指定）
+    pub published_at: Option<DateTime<Utc>>,
+    /// 予約公開の日時（is_published = false のときだけ）
+    pub scheduled_at: Option<DateTime<Utc>>,
+    pub default_locale_id: i32,
+    /// 所属する本・チャプター
+    pub book: Option<BookLink>,
+    /// アイキャッチ画像（media.media_id）
+    pub featured_image_id: Option<i32>,
+    /// 作成した管理者（last_updated_by にも同じ値を入れる）
+    pub created_by: Option<i32>,
+}
+
+/// 記事の部分更新用の入力（Noneの項目は変更しない）
+#[derive(Debug, Clone, Default)]
+pub struct PostChanges {
+    pub slug: Option<String>,
+    /// OGP画像のURL（Some(None) なら消す）
+    pub meta_image_url: Option<Option<String>>,
+    pub is_published: Option<bool>,
+    /// 予約公開の日時（Some なら予約する。is_published を指定したときは予約を取り消す）
+    pub scheduled_at: Option<DateTime<Utc>>,
+    pub default_locale_id: Option<i32>,
+    /// 所属する本・チャプター（Some なら book_id / chapter_id を置き換える）
+    pub book: Option<BookLink>,
+    /// アイキャッチ画像（media.media_id。Some(None) なら外す）
+    pub featured_image_id: Option<Option<i32>>,
+    /// 更新した管理者（None なら last_updated_by を変更しない）
+    pub last_updated_by: Option<i32>,
+}
+
+/// 記事が所属する本とチャプター
+///
+/// 💡 chapter_id のチャプターは book_id の本に属していること（複合外部キーでも保証される）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BookLink {
+    pub book_id: i32,
+    pub chapter_id: Option<i32>,
+}
+
+/// 翻訳の作成・更新用の入力
+#[derive(Debug, Clone)]
+pub struct PostTranslationInput {
+    pub locale_id: i32,
+    pub title: String,
+    pub summary: Option<String>,
+    pub meta_title: Option<String>,
+    pub meta_description: Option<String>,
+    pub content: String,
+    /// 本文から計算した読了目安（分）
+    pub reading_time_minutes: i32,
+    /// 本文から計算した抜粋
+    pub excerpt: Option<String>,
+    /// 全文検索の search_vector の材料
+    pub search: SearchDocument,
+    /// 古い版から復元する場合、その版の番号（新しい版に記録する）
+    pub restored_from: Option<i32>,
+}
+
+/// 記事一覧の絞り込み条件
+#[derive(Debug, Clone, Default)]
+pub struct PostFilter {
+    /// 非公開の記事も含める
+    pub include_unpublished: bool,
+    /// トピックのslugで絞り込む
+    pub topic: Option<String>,
+    /// チャプターで絞り込む
+    pub chapter_id: Option<i32>,
+}
+
+// --------------------------------------------------------
+// PostSort: 記事一覧の並び替え項目
+// --------------------------------------------------------
+//
+// 💡 published_at だけキーセット方式に対応（migration 006 のインデックスと同じ式）
+// - 未公開（NULL）の記事は -infinity 扱いで、新しい順のとき末尾に並ぶ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostSort {
+    PublishedAt,
+    CreatedAt,
+    UpdatedAt,
+    ViewsCount,
+    Slug,
+}
+
+const PUBLISHED_AT_KEY: &str = "COALESCE(p.published_at, '-infinity'::timestamptz)";
+
+impl SortKey for PostSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("published_at", Self::PublishedAt),
+        ("created_at", Self::CreatedAt),
+        ("updated_at", Self::UpdatedAt),
+        ("views_count", Self::ViewsCount),
+        ("slug", Self::Slug),
+    ];
+    const DEFAULT: Self = Self::PublishedAt;
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::PublishedAt => PUBLISHED_AT_KEY,
+            Self::CreatedAt => "p.created_at",
+            Self::UpdatedAt => "p.updated_at",
+            Self::ViewsCount => "p.views_count",
+            Self::Slug => "p.slug",
+        }
+    }
+
+    fn default_direction(self) -> SortDirection {
+        match self {
+            Self::Slug => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+
+    fn supports_cursor(self) -> bool {
+        self == Self::PublishedAt
+    }
+}
+
+// --------------------------------------------------------
+// ScheduledPostSort: 予約公開一覧の並び替え項目
+// --------------------------------------------------------
+//
+// 💡 デフォルトは公開予定の近い順（scheduled_at の昇順）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduledPostSort {
+    ScheduledAt,
+    UpdatedAt,
+    Slug,
+}
+
+impl SortKey for ScheduledPostSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("scheduled_at", Self::ScheduledAt),
+        ("updated_at", Self::UpdatedAt),
+        ("slug", Self::Slug),
+    ];
+    const DEFAULT: Self = Self::ScheduledAt;
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::ScheduledAt => "p.scheduled_at",
+            Self::UpdatedAt => "p.updated_at",
+            Self::Slug => "p.slug",
+        }
+    }
+
+    fn default_direction(self) -> SortDirection {
+        match self {
+            Self::UpdatedAt => SortDirection::Desc,
+            _ => SortDirection::Asc,
+        }
+    }
+}
+
+// --------------------------------------------------------
+// post_filter: 一覧と件数で共通のWHERE条件
+// --------------------------------------------------------
+//
+// 💡 first: 非公開を含めるか（$first）・トピック（$first + 1）・チャプター（$first + 2）のパラメータ番号
+fn post_filter(first: usize) -> String {
+    let topic = first + 1;
+    let chapter = first + 2;
+    format!(
+        r#"(${first} OR p.is_published)
+        AND (${topic}::text IS NULL OR EXISTS (
+            SELECT 1
+            FROM blog_post_topics bpt
+            JOIN topics tp ON tp.topic_id = bpt.topic_id
+            WHERE bpt.post_id = p.post_id AND tp.slug = ${topic}
+        ))
+        AND (${chapter}::int IS NULL OR p.chapter_id = ${chapter})"#
+    )
+}
+
+// --------------------------------------------------------
+// 翻訳の選び方（LATERALサブクエリのWHERE + ORDER BY）
+// --------------------------------------------------------
+//
+// 💡 ORDER BY の3段階:
+// - (l.code = $1) DESC : 要求言語が最優先
+// - COALESCE(tr.locale_id = p.default_locale_id, FALSE) DESC : 次に原文言語
+//   （default_locale_id が NULL のとき比較結果も NULL になるため COALESCE）
+// - 残りはサイトのデフォルト言語（$2）
+const TRANSLATION_PICK: &str = r#"
+        FROM blog_post_translations tr
+        JOIN locales l ON l.locale_id = tr.locale_id
+        WHERE tr.post_id = p.post_id
+          AND l.is_active = TRUE
+          AND (l.code IN ($1, $2) OR tr.locale_id = p.default_locale_id)
+        ORDER BY
+            (l.code = $1) DESC,
+            COALESCE(tr.locale_id = p.default_locale_id, FALSE) DESC
+        LIMIT 1
+"#;
+
+/// 読了目安: 選んだ翻訳の計算値（なければ blog_posts.estimated_reading_time）
+const READING_TIME: &str = "COALESCE(t.reading_time_minutes, p.estimated_reading_time)";
+
+/// 抜粋: 手入力の要約があればそれ、なければ本文から計算した抜粋
+const EXCERPT: &str = "COALESCE(NULLIF(btrim(t.summary), ''), t.excerpt)";
+
+fn summary_select() -> String {
+    format!(
+        r#"
+        SELECT
+            p.post_id, p.slug, p.meta_image_url,
+            {READING_TIME} AS estimated_reading_time,
+            p.is_published, p.views_count, p.created_at, p.updated_at, p.published_at,
+            t.title, t.summary, {EXCERPT} AS excerpt, t.locale_code
+        FROM blog_posts p
+        LEFT JOIN LATERAL (
+            SELECT tr.title, tr.summary, tr.reading_time_minutes, tr.excerpt,
+                   l.code AS locale_code
+            {TRANSLATION_PICK}
+        ) t ON TRUE
+        "#
+    )
+}
+
+fn detail_select() -> String {
+    format!(
+        r#"
+        SELECT
+            p.post_id, p.slug, p.meta_image_url,
+            {READING_TIME} AS estimated_reading_time,
+            p.book_id, p.chapter_id, p.featured_image_id,
+            p.is_published, p.views_count, p.created_at, p.updated_at, p.published_at,
+            p.scheduled_at,
+            t.title, t.summary, {EXCERPT} AS excerpt,
+            t.meta_title, t.meta_description, t.content, t.locale_code
+        FROM blog_posts p
+        LEFT JOIN LATERAL (
+            SELECT tr.title, tr.summary, tr.meta_title, tr.meta_description, tr.content,
+                   tr.reading_time_minutes, tr.excerpt, l.code AS locale_code
+            {TRANSLATION_PICK}
+        ) t ON TRUE
+        "#
+    )
+}
+
+pub struct PostRepository {
+    pool: PgPool,
+}
+
+impl PostRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_page_localized: 記事一覧（1ページ分）
+    // --------------------------------------------------------
+    //
+    // 💡 キーセット方式（sort=published_at）:
+    // - カーソルの位置 ($6, $7) より後の行だけを取る
+    // - (a, b) < (x, y) は「a < x、または a = x かつ b < y」という行の比較
+    //
+    // 💡 total は絞り込み条件に一致する総数（カーソル・ページに関係なく）
+    pub async fn find_page_localized(
+        &self,
+        requested: &str,
+        fallback: &str,
+        filter: &PostFilter,
+        params: &ListParams<PostSort>,
+    ) -> Result<Page<PostSummary>, sqlx::Error> {
+        let sql = format!(
+            r#"{}
+            WHERE {}
+              AND ($7::int IS NULL
+                   OR ({PUBLISHED_AT_KEY}, p.post_id) {} (COALESCE($6::timestamptz, '-infinity'::timestamptz), $7))
+            {}
+            LIMIT $8 OFFSET $9"#,
+            summary_select(),
+            post_filter(3),
+            params.direction.after_operator(),
+            params.order_by("p.post_id"),
+        );
+
+        let cursor = params.cursor();
+        let posts = sqlx::query_as::<_, PostSummary>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(filter.include_unpublished)
+            .bind(filter.topic.as_deref())
+            .bind(filter.chapter_id)
+            .bind(cursor.and_then(|c| c.timestamp))
+            .bind(cursor.map(|c| c.id))
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) = sqlx::query_as(&format!(
+            "SELECT COUNT(*) FROM blog_posts p WHERE {}",
+            post_filter(1)
+        ))
+        .bind(filter.include_unpublished)
+        .bind(filter.topic.as_deref())
+        .bind(filter.chapter_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let direction = params.direction;
+        Ok(params.finish(posts, total, |post| {
+            Cursor::new(direction, post.published_at, post.post_id)
+        }))
+    }
+
+    // --------------------------------------------------------
+    // find_by_slug_localized: slugで1件取得（言語解決済み、本文あり）
+    // --------------------------------------------------------
+    pub async fn find_by_slug_localized(
+        &self,
+        slug: &str,
+        requested: &str,
+        fallback: &str,
+    ) -> Result<Option<LocalizedPost>, sqlx::Error> {
+        let sql = format!("{} WHERE p.slug = $3", detail_select());
+
+        let post = sqlx::query_as::<_, LocalizedPost>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(post)
+    }
+
+    // --------------------------------------------------------
+    // find_by_slug: slugで1件取得（翻訳なし）
+    // --------------------------------------------------------
+    pub async fn find_by_slug(&self, slug: &str) -> Result<Option<Post>, sqlx::Error> {
+        let post = sqlx::query_as::<_, Post>("SELECT * FROM blog_posts WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(post)
+    }
+
+    // --------------------------------------------------------
+    // find_translations: 記事の全翻訳（言語コード付き）
+    // --------------------------------------------------------
+    pub async fn find_translations(&self, post_id: i32) -> Result<Vec<PostTranslation>, sqlx::Error> {
+        let translations = sqlx::query_as::<_, PostTranslation>(
+            r#"
+            SELECT tr.translation_id, tr.post_id, tr.locale_id, l.code AS locale_code,
+                   tr.title, tr.summary, tr.meta_title, tr.meta_description, tr.content,
+                   tr.reading_time_minutes, tr.excerpt, tr.created_at, tr.updated_at
+            FROM blog_post_translations tr
+            JOIN locales l ON l.locale_id = tr.locale_id
+            WHERE tr.post_id = $1
+            ORDER BY l.locale_id ASC
+            "#
+        )
+        .bind(post_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(translations)
+    }
+
+    // --------------------------------------------------------
+    // find_topic_slugs: 記事に紐づくトピックのslug
+    // --------------------------------------------------------
+    pub async fn find_topic_slugs(&self, post_id: i32) -> Result<Vec<String>, sqlx::Error> {
+        let slugs: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT t.slug
+            FROM blog_post_topics bpt
+            JOIN topics t ON t.topic_id = bpt.topic_id
+            WHERE bpt.post_id = $1
+            ORDER BY t.slug ASC
+            "#
+        )
+        .bind(post_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(slugs.into_iter().map(|(slug,)| slug).collect())
+    }
+
+    // --------------------------------------------------------
+    // find_feed_items: フィード用の記事（新しい順、1つの言語の翻訳だけ）
+    // --------------------------------------------------------
+    //
+    // 💡 topic を指定するとそのトピックの記事だけ（公開中のトピックかどうかはハンドラーで確認）
+    // 💡 トピック名は同じ言語の名前（翻訳がなければ slug）
+    pub async fn find_feed_items(
+        &self,
+        locale_code: &str,
+        topic: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<PostFeedItem>, sqlx::Error> {
+        let items = sqlx::query_as::<_, PostFeedItem>(
+            r#"
+            SELECT
+                p.post_id, p.slug, tr.title,
+                COALESCE(tr.summary, tr.excerpt) AS summary,
+                p.published_at,
+                GREATEST(p.updated_at, tr.updated_at) AS updated_at,
+                ARRAY(
+                    SELECT COALESCE(tt.name, t.slug)
+                    FROM blog_post_topics bpt
+                    JOIN topics t ON t.topic_id = bpt.topic_id AND t.is_published
+                    LEFT JOIN topic_translations tt
+                           ON tt.topic_id = t.topic_id AND tt.locale_id = l.locale_id
+                    WHERE bpt.post_id = p.post_id
+                    ORDER BY t.slug ASC
+                ) AS topics
+            FROM blog_posts p
+            JOIN blog_post_translations tr ON tr.post_id = p.post_id
+            JOIN locales l ON l.locale_id = tr.locale_id AND l.code = $1 AND l.is_active
+            WHERE p.is_published
+              AND p.published_at IS NOT NULL
+              AND ($2::text IS NULL OR EXISTS (
+                    SELECT 1
+                    FROM blog_post_topics bpt
+                    JOIN topics t ON t.topic_id = bpt.topic_id
+                    WHERE bpt.post_id = p.post_id AND t.slug = $2
+                  ))
+            ORDER BY p.published_at DESC, p.post_id DESC
+            LIMIT $3
+            "#
+        )
+        .bind(locale_code)
+        .bind(topic)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(items)
+    }
+
+    // --------------------------------------------------------
+    // create: 記事 + 翻訳 + トピックをまとめて作成（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 公開状態で作成した場合は published_at に現在時刻を入れる（post.published_at があればその値）
+    // 💡 予約公開（scheduled_at）は下書きで作成した場合だけ（CHECK制約 blog_posts_scheduled_check）
+    pub async fn create(
+        &self,
+        post: &NewPost,
+        translations: &[PostTranslationInput],
+        topic_ids: &[i32],
+        audit: &AuditContext,
+    ) -> Result<Post, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Post>(
+            r#"
+            INSERT INTO blog_posts (
+                slug, meta_image_url, is_published, default_locale_id, published_at,
+                book_id, chapter_id, created_by, last_updated_by, featured_image_id,
+                scheduled_at
+            )
+            VALUES ($1, $2, $3, $4, CASE WHEN $3 THEN COALESCE($10, NOW()) END, $5, $6, $7, $7, $8, $9)
+            RETURNING *
+            "#
+        )
+        .bind(&post.slug)
+        .bind(&post.meta_image_url)
+        .bind(post.is_published)
+        .bind(post.default_locale_id)
+        .bind(post.book.map(|link| link.book_id))
+        .bind(post.book.and_then(|link| link.chapter_id))
+        .bind(post.created_by)
+        .bind(post.featured_image_id)
+        .bind(post.scheduled_at)
+        .bind(post.published_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_translation(&mut tx, created.post_id, translation, audit).await?;
+        }
+        replace_topics(&mut tx, created.post_id, topic_ids).await?;
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::BlogPosts, created.post_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::BlogPosts,
+            created.post_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+
+    // --------------------------------------------------------
+    // update: 記事の部分更新 + 翻訳UPSERT + トピック置き換え
+    // --------------------------------------------------------
+    //
+    // 💡 published_at:
+    // - 初めて公開されたときだけ現在時刻を入れる
+    // - 非公開に戻しても初回公開日時は残す
+    //
+    // 💡 scheduled_at:
+    // - $11 があれば予約する
+    // - is_published を指定した（$4 が NULL でない）ときは予約を取り消す
+    //   （すぐ公開した、または下書きに戻した）
+    //
+    // 💡 changes.book:
+    // - Some: book_id と chapter_id を両方置き換える（$7 = TRUE）
+    // - None: 変更しない
+    //
+    // 💡 changes.meta_image_url / featured_image_id も同じ考え方（$12 / $13 = TRUE なら置き換える）
+    // - Some(None) で NULL に戻せる（COALESCE だと消せない）
+    //
+    // 💡 topic_ids:
+    // - Some(ids): 紐づくトピックを ids で置き換える
+    // - None     : トピックは変更しない
+    pub async fn update(
+        &self,
+        post_id: i32,
+        changes: &PostChanges,
+        translations: &[PostTranslationInput],
+        topic_ids: Option<&[i32]>,
+        audit: &AuditContext,
+    ) -> Result<Option<Post>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) = audit_log_repository::snapshot(&mut tx, AuditTable::BlogPosts, post_id).await? else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let updated = sqlx::query_as::<_, Post>(
+            r#"
+            UPDATE blog_posts
+            SET slug = COALESCE($2, slug),
+                meta_image_url = CASE WHEN $12 THEN $3 ELSE meta_image_url END,
+                is_published = COALESCE($4, is_published),
+                default_locale_id = COALESCE($5, default_locale_id),
+                published_at = CASE
+                    WHEN COALESCE($4, is_published) AND published_at IS NULL THEN NOW()
+                    ELSE published_at
+                END,
+                last_updated_by = COALESCE($6, last_updated_by),
+                book_id = CASE WHEN $7 THEN $8 ELSE book_id END,
+                chapter_id = CASE WHEN $7 THEN $9 ELSE chapter_id END,
+                featured_image_id = CASE WHEN $13 THEN $10 ELSE featured_image_id END,
+                scheduled_at = CASE
+                    WHEN $11::timestamptz IS NOT NULL THEN $11
+                    WHEN $4::boolean IS NOT NULL THEN NULL
+                    ELSE scheduled_at
+                END,
+                updated_at = NOW()
+            WHERE post_id = $1
+            RETURNING *
+            "#
+        )
+        .bind(post_id)
+        .bind(&changes.slug)
+        .bind(changes.meta_image_url.as_ref().and_then(|url| url.as_deref()))
+        .bind(changes.is_published)
+        .bind(changes.default_locale_id)
+        .bind(changes.last_updated_by)
+        .bind(changes.book.is_some())
+        .bind(changes.book.map(|link| link.book_id))
+        .bind(changes.book.and_then(|link| link.chapter_id))
+        .bind(changes.featured_image_id.flatten())
+        .bind(changes.scheduled_at)
+        .bind(changes.meta_image_url.is_some())
+        .bind(changes.featured_image_id.is_some())
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_translation(&mut tx, post_id, translation, audit).await?;
+        }
+        if let Some(topic_ids) = topic_ids {
+            replace_topics(&mut tx, post_id, topic_ids).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::BlogPosts, post_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::BlogPosts,
+            post_id,
+            AuditAction::Update,
+            Some(old),
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(updated))
+    }
+
+    // --------------------------------------------------------
+    // find_scheduled_page: 予約公開の一覧（1ページ分、言語解決済みのタイトル付き）
+    // --------------------------------------------------------
+    pub async fn find_scheduled_page(
+        &self,
+        requested: &str,
+        fallback: &str,
+        params: &ListParams<ScheduledPostSort>,
+    ) -> Result<Page<ScheduledPost>, sqlx::Error> {
+        let sql = format!(
+            r#"
+            SELECT
+                p.post_id, p.slug, p.scheduled_at, p.created_by, p.last_updated_by,
+                p.updated_at, t.title, t.locale_code
+            FROM blog_posts p
+            LEFT JOIN LATERAL (
+                SELECT tr.title, l.code AS locale_code
+                {TRANSLATION_PICK}
+            ) t ON TRUE
+            WHERE p.scheduled_at IS NOT NULL
+            {}
+            LIMIT $3 OFFSET $4
+            "#,
+            params.order_by("p.post_id"),
+        );
+
+        let posts = sqlx::query_as::<_, ScheduledPost>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM blog_posts WHERE scheduled_at IS NOT NULL")
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(params.finish_offset(posts, total))
+    }
+
+    // --------------------------------------------------------
+    // publish_due: 公開予定の日時を過ぎた記事を公開する（scheduler 用）
+    // --------------------------------------------------------
+    //
+    // 💡 FOR UPDATE SKIP LOCKED:
+    // - 取り出した行をロックし、他のトランザクションがロック中の行は飛ばす
+    // - サーバーを複数台で動かしても、同じ記事を2回公開しない
+    //   （他のインスタンスは残りの記事を処理するか、何もしない）
+    //
+    // 💡 published_at には予約していた日時を入れる（scheduler の実行が遅れても公開日はずれない）
+    //    ただし一度公開した記事（published_at あり）は初回公開日時を残す
+    //
+    // 💡 1件ずつ監査ログを残す（changed_via = 'scheduler'）
+    pub async fn publish_due(
+        &self,
+        limit: i64,
+        audit: &AuditContext,
+    ) -> Result<Vec<Post>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let due: Vec<(i32,)> = sqlx::query_as(
+            r#"
+            SELECT post_id
+            FROM blog_posts
+            WHERE scheduled_at IS NOT NULL
+              AND scheduled_at <= NOW()
+              AND NOT is_published
+            ORDER BY scheduled_at ASC, post_id ASC
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+            "#
+        )
+        .bind(limit)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut published = Vec::with_capacity(due.len());
+        for (post_id,) in due {
+            let old = audit_log_repository::snapshot(&mut tx, AuditTable::BlogPosts, post_id).await?;
+
+            let post = sqlx::query_as::<_, Post>(
+                r#"
+                UPDATE blog_posts
+                SET is_published = TRUE,
+                    published_at = COALESCE(published_at, scheduled_at),
+                    scheduled_at = NULL,
+                    updated_at = NOW()
+                WHERE post_id = $1
+                RETURNING *
+                "#
+            )
+            .bind(post_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            let new = audit_log_repository::snapshot(&mut tx, AuditTable::BlogPosts, post_id).await?;
+            audit_log_repository::record(
+                &mut tx,
+                audit,
+                AuditTable::BlogPosts,
+                post_id,
+                AuditAction::Update,
+                old,
+                new,
+            )
+            .await?;
+
+            published.push(post);
+        }
+
+        tx.commit().await?;
+
+        Ok(published)
+    }
+}
+
+// --------------------------------------------------------
+// upsert_translation: 翻訳の INSERT or UPDATE
+// --------------------------------------------------------
+//
+// 💡 search_vector も同時に作り直す（$10〜$12、search_repository::search_vector_sql）
+// 💡 保存した内容を版として残す（revision_repository::record）
+async fn upsert_translation(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: i32,
+    translation: &PostTranslationInput,
+    audit: &AuditContext,
+) -> Result<(), sqlx::Error> {
+    let sql = format!(
+        r#"
+        INSERT INTO blog_post_translations
+            (post_id, locale_id, title, summary, meta_title, meta_description, content,
+             reading_time_minutes, excerpt, search_vector)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, {})
+        ON CONFLICT (post_id, locale_id)
+        DO UPDATE SET
+            title = EXCLUDED.title,
+            summary = EXCLUDED.summary,
+            meta_title = EXCLUDED.meta_title,
+            meta_description = EXCLUDED.meta_description,
+            content = EXCLUDED.content,
+            reading_time_minutes = EXCLUDED.reading_time_minutes,
+            excerpt = EXCLUDED.excerpt,
+            search_vector = EXCLUDED.search_vector,
+            updated_at = NOW()
+        "#,
+        search_vector_sql("$10", "$11", "$3", "$4", "$12"),
+    );
+
+    sqlx::query(&sql)
+        .bind(post_id)
+        .bind(translation.locale_id)
+        .bind(&translation.title)
+        .bind(&translation.summary)
+        .bind(&translation.meta_title)
+        .bind(&translation.meta_description)
+        .bind(&translation.content)
+        .bind(translation.reading_time_minutes)
+        .bind(&translation.excerpt)
+        .bind(translation.search.config)
+        .bind(&translation.search.vector)
+        .bind(&translation.search.content_text)
+        .execute(&mut **tx)
+        .await?;
+
+    revision_repository::record(tx, post_id, translation, audit).await
+}
+
+// --------------------------------------------------------
+// replace_topics: 記事のトピックを置き換える
+// --------------------------------------------------------
+//
+// 💡 UNNEST($2::int[]):
+// - 配列を行に展開する（[1, 2] → 2行）
+// - 1回のINSERTで複数行を追加できる
+async fn replace_topics(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: i32,
+    topic_ids: &[i32],
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM blog_post_topics WHERE post_id = $1")
+        .bind(post_id)
+        .execute(&mut **tx)
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO blog_post_topics (post_id, topic_id)
+        SELECT $1, topic_id FROM UNNEST($2::int[]) AS topic_id
+        ON CONFLICT (post_id, topic_id) DO NOTHING
+        "#
+    )
+    .bind(post_id)
+    .bind(topic_ids)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
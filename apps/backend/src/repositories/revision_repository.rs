@@ -0,0 +1,187 @@
+use sqlx::{PgPool, Postgres, Transaction};
+
+use crate::{
+    entities::PostRevision,
+    pagination::{ListParams, Page, SortDirection, SortKey},
+    repositories::{audit_log_repository::AuditContext, post_repository::PostTranslationInput},
+};
+
+// ============================================
+// RevisionRepository（記事の翻訳の版）
+// ============================================
+//
+// 💡 版は PostRepository の create / update で翻訳を保存するときに
+//    同じトランザクションで record() が追加する（このリポジトリからは追加しない）
+
+/// 版の一覧の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionSort {
+    RevisionNumber,
+}
+
+impl SortKey for RevisionSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[("revision_number", Self::RevisionNumber)];
+    const DEFAULT: Self = Self::RevisionNumber;
+
+    fn column(self) -> &'static str {
+        "r.revision_number"
+    }
+
+    fn default_direction(self) -> SortDirection {
+        SortDirection::Desc
+    }
+}
+
+const REVISION_SELECT: &str = r#"
+    SELECT r.revision_id, r.post_id, l.code AS locale_code, r.revision_number,
+           r.title, r.summary, r.meta_title, r.meta_description, r.content,
+           r.restored_from, r.created_by, a.username AS created_by_username,
+           r.api_key_id, r.created_at
+    FROM blog_post_translation_revisions r
+    JOIN locales l ON l.locale_id = r.locale_id
+    LEFT JOIN administrators a ON a.admin_id = r.created_by
+"#;
+
+pub struct RevisionRepository {
+    pool: PgPool,
+}
+
+impl RevisionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_page: 記事 + 言語の版の一覧（1ページ分、新しい版から）
+    // --------------------------------------------------------
+    pub async fn find_page(
+        &self,
+        post_id: i32,
+        locale_code: &str,
+        params: &ListParams<RevisionSort>,
+    ) -> Result<Page<PostRevision>, sqlx::Error> {
+        let sql = format!(
+            "{REVISION_SELECT} WHERE r.post_id = $1 AND l.code = $2 {} LIMIT $3 OFFSET $4",
+            params.order_by("r.revision_id"),
+        );
+
+        let revisions = sqlx::query_as::<_, PostRevision>(&sql)
+            .bind(post_id)
+            .bind(locale_code)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*)
+            FROM blog_post_translation_revisions r
+            JOIN locales l ON l.locale_id = r.locale_id
+            WHERE r.post_id = $1 AND l.code = $2
+            "#
+        )
+        .bind(post_id)
+        .bind(locale_code)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(params.finish_offset(revisions, total))
+    }
+
+    // --------------------------------------------------------
+    // find: 版の番号で1件取得
+    // --------------------------------------------------------
+    pub async fn find(
+        &self,
+        post_id: i32,
+        locale_code: &str,
+        revision_number: i32,
+    ) -> Result<Option<PostRevision>, sqlx::Error> {
+        let sql = format!(
+            "{REVISION_SELECT} WHERE r.post_id = $1 AND l.code = $2 AND r.revision_number = $3"
+        );
+
+        let revision = sqlx::query_as::<_, PostRevision>(&sql)
+            .bind(post_id)
+            .bind(locale_code)
+            .bind(revision_number)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(revision)
+    }
+
+    // --------------------------------------------------------
+    // find_latest: 最新の版
+    // --------------------------------------------------------
+    pub async fn find_latest(
+        &self,
+        post_id: i32,
+        locale_code: &str,
+    ) -> Result<Option<PostRevision>, sqlx::Error> {
+        let sql = format!(
+            "{REVISION_SELECT} WHERE r.post_id = $1 AND l.code = $2 ORDER BY r.revision_number DESC LIMIT 1"
+        );
+
+        let revision = sqlx::query_as::<_, PostRevision>(&sql)
+            .bind(post_id)
+            .bind(locale_code)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(revision)
+    }
+}
+
+// --------------------------------------------------------
+// record: 翻訳の保存に合わせて版を追加する（翻訳と同じトランザクション）
+// --------------------------------------------------------
+//
+// 💡 最新の版と内容（タイトル・要約・SEO項目・本文）が同じなら追加しない
+//    （トピックだけ変えた更新などで、同じ版が並ばないように）
+//
+// 💡 revision_number は「最新の版 + 1」
+// - 呼び出し元（PostRepository）が記事の行をロックしているので、同じ記事の保存が同時に走っても番号は重複しない
+pub(crate) async fn record(
+    tx: &mut Transaction<'_, Postgres>,
+    post_id: i32,
+    translation: &PostTranslationInput,
+    audit: &AuditContext,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        WITH latest AS (
+            SELECT revision_number, title, summary, meta_title, meta_description, content
+            FROM blog_post_translation_revisions
+            WHERE post_id = $1 AND locale_id = $2
+            ORDER BY revision_number DESC
+            LIMIT 1
+        )
+        INSERT INTO blog_post_translation_revisions
+            (post_id, locale_id, revision_number, title, summary, meta_title, meta_description,
+             content, restored_from, created_by, api_key_id)
+        SELECT $1, $2, COALESCE((SELECT revision_number FROM latest), 0) + 1,
+               $3, $4, $5, $6, $7, $8, $9, $10
+        WHERE NOT EXISTS (
+            SELECT 1 FROM latest
+            WHERE (title, summary, meta_title, meta_description, content)
+                  IS NOT DISTINCT FROM ($3, $4, $5, $6, $7)
+        )
+        "#
+    )
+    .bind(post_id)
+    .bind(translation.locale_id)
+    .bind(&translation.title)
+    .bind(&translation.summary)
+    .bind(&translation.meta_title)
+    .bind(&translation.meta_description)
+    .bind(&translation.content)
+    .bind(translation.restored_from)
+    .bind(audit.changed_by)
+    .bind(audit.api_key_id)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
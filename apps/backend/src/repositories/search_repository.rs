@@ -0,0 +1,189 @@
+use sqlx::{FromRow, PgPool};
+
+use crate::{
+    entities::PostSearchHit,
+    pagination::{ListParams, Page, SortDirection, SortKey},
+    search::{SearchDocument, SearchQuery},
+};
+
+// ============================================
+// SearchRepository（記事の全文検索）
+// ============================================
+//
+// 💡 検索の対象:
+// - 解決済みの言語（ResolvedLocale）の翻訳だけ（別の言語の翻訳はヒットさせない）
+// - 公開済みの記事だけ
+//
+// 💡 search_vector の作り方は src/search.rs を参照
+
+// --------------------------------------------------------
+// search_vector_sql: 翻訳の search_vector を作るSQL式
+// --------------------------------------------------------
+//
+// 💡 引数はプレースホルダー（"$10" など）
+// - vector が NULL でなければ（日本語などのバイグラム）それをそのまま tsvector にする
+// - NULL なら config（english など）で title / summary / content を単語に分け、重みを付けてつなぐ
+pub(crate) fn search_vector_sql(
+    config: &str,
+    vector: &str,
+    title: &str,
+    summary: &str,
+    content: &str,
+) -> String {
+    format!(
+        "COALESCE({vector}::tsvector, \
+             setweight(to_tsvector({config}::regconfig, {title}), 'A') \
+             || setweight(to_tsvector({config}::regconfig, COALESCE({summary}, '')), 'B') \
+             || setweight(to_tsvector({config}::regconfig, {content}), 'C'))"
+    )
+}
+
+/// 検索結果の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchSort {
+    /// 一致度の高い順
+    Relevance,
+    PublishedAt,
+}
+
+impl SortKey for SearchSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("relevance", Self::Relevance),
+        ("published_at", Self::PublishedAt),
+    ];
+    const DEFAULT: Self = Self::Relevance;
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::Relevance => "rank",
+            Self::PublishedAt => "p.published_at",
+        }
+    }
+
+    fn default_direction(self) -> SortDirection {
+        SortDirection::Desc
+    }
+}
+
+/// search_vector を作り直す翻訳（backend search reindex）
+#[derive(Debug, Clone, FromRow)]
+pub struct SearchIndexSource {
+    pub translation_id: i32,
+    pub locale_code: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub content: String,
+}
+
+pub struct SearchRepository {
+    pool: PgPool,
+}
+
+impl SearchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // search_posts: 記事の翻訳を検索（1ページ分）
+    // --------------------------------------------------------
+    //
+    // 💡 ts_rank_cd(..., 1): 長い記事ほど有利にならないよう、文書の長さ（の対数）で割る
+    pub async fn search_posts(
+        &self,
+        query: &SearchQuery,
+        locale_code: &str,
+        params: &ListParams<SearchSort>,
+    ) -> Result<Page<PostSearchHit>, sqlx::Error> {
+        let tsquery = query.tsquery_sql("$1", "$2");
+        let from = format!(
+            r#"
+            FROM blog_post_translations tr
+            JOIN locales l ON l.locale_id = tr.locale_id
+            JOIN blog_posts p ON p.post_id = tr.post_id
+            CROSS JOIN (SELECT {tsquery} AS query) q
+            WHERE l.code = $3
+              AND l.is_active = TRUE
+              AND p.is_published = TRUE
+              AND tr.search_vector @@ q.query
+            "#
+        );
+
+        let sql = format!(
+            r#"
+            SELECT p.post_id, p.slug, p.published_at, tr.title,
+                   COALESCE(NULLIF(btrim(tr.summary), ''), tr.excerpt) AS excerpt,
+                   tr.content, l.code AS locale_code,
+                   ts_rank_cd(tr.search_vector, q.query, 1) AS rank
+            {from}
+            {}
+            LIMIT $4 OFFSET $5
+            "#,
+            params.order_by("p.post_id"),
+        );
+
+        let hits = sqlx::query_as::<_, PostSearchHit>(&sql)
+            .bind(query.analyzer.config())
+            .bind(&query.query)
+            .bind(locale_code)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) {from}"))
+            .bind(query.analyzer.config())
+            .bind(&query.query)
+            .bind(locale_code)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(params.finish_offset(hits, total))
+    }
+
+    // --------------------------------------------------------
+    // find_index_sources: すべての記事の翻訳（search_vector の作り直し用）
+    // --------------------------------------------------------
+    pub async fn find_index_sources(&self) -> Result<Vec<SearchIndexSource>, sqlx::Error> {
+        let sources = sqlx::query_as::<_, SearchIndexSource>(
+            r#"
+            SELECT tr.translation_id, l.code AS locale_code, tr.title, tr.summary, tr.content
+            FROM blog_post_translations tr
+            JOIN locales l ON l.locale_id = tr.locale_id
+            ORDER BY tr.translation_id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(sources)
+    }
+
+    // --------------------------------------------------------
+    // update_search_vector: 1つの翻訳の search_vector を作り直す
+    // --------------------------------------------------------
+    //
+    // 💡 updated_at は変えない（内容は変わっていないため）
+    pub async fn update_search_vector(
+        &self,
+        source: &SearchIndexSource,
+        document: &SearchDocument,
+    ) -> Result<(), sqlx::Error> {
+        let sql = format!(
+            "UPDATE blog_post_translations SET search_vector = {} WHERE translation_id = $1",
+            search_vector_sql("$2", "$3", "$4", "$5", "$6"),
+        );
+
+        sqlx::query(&sql)
+            .bind(source.translation_id)
+            .bind(document.config)
+            .bind(&document.vector)
+            .bind(&source.title)
+            .bind(&source.summary)
+            .bind(&document.content_text)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
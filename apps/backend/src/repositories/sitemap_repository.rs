@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+
+// ============================================
+// SitemapRepository（サイトマップに載せるページ）
+// ============================================
+//
+// 💡 載せるのは公開済みの記事・本の、有効な言語の翻訳だけ
+// 💡 1行 = 1つのページの1つの翻訳（同じページの行が続くように並べる）
+
+/// サイトマップの1行（ページの1つの翻訳）
+#[derive(Debug, Clone, FromRow)]
+pub struct SitemapEntry {
+    /// 公開サイトのパスの種類（blog / book）
+    pub section: String,
+    pub slug: String,
+    pub locale_code: String,
+    /// ページ・翻訳のうち新しいほうの更新日時
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct SitemapRepository {
+    pool: PgPool,
+}
+
+impl SitemapRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_entries: 公開済みの記事・本の翻訳（ページごとにまとまった順）
+    // --------------------------------------------------------
+    //
+    // 💡 分割したサイトマップ（/sitemaps/{n}.xml）は毎回ここから数え直すので、並び順を固定する
+    pub async fn find_entries(&self) -> Result<Vec<SitemapEntry>, sqlx::Error> {
+        let entries = sqlx::query_as::<_, SitemapEntry>(
+            r#"
+            SELECT 'blog' AS section, p.slug, l.code AS locale_code,
+                   GREATEST(p.updated_at, tr.updated_at) AS updated_at,
+                   p.post_id AS item_id
+            FROM blog_posts p
+            JOIN blog_post_translations tr ON tr.post_id = p.post_id
+            JOIN locales l ON l.locale_id = tr.locale_id AND l.is_active
+            WHERE p.is_published
+
+            UNION ALL
+
+            SELECT 'book' AS section, b.slug, l.code AS locale_code,
+                   GREATEST(b.updated_at, tr.updated_at) AS updated_at,
+                   b.book_id AS item_id
+            FROM books b
+            JOIN book_translations tr ON tr.book_id = b.book_id
+            JOIN locales l ON l.locale_id = tr.locale_id AND l.is_active
+            WHERE b.is_published
+
+            ORDER BY section ASC, item_id ASC, locale_code ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+}
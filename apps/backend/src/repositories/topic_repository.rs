@@ -0,0 +1,317 @@
+use sqlx::PgPool;
+
+use crate::{
+    entities::{AuditAction, AuditTable, LocalizedTopic, Topic, TopicTranslation},
+    pagination::{ListParams, Page, SortKey},
+    repositories::audit_log_repository::{self, AuditContext},
+};
+
+// ============================================
+// TopicRepository（トピックのデータアクセス）
+// ============================================
+//
+// 💡 多言語の解決ルール:
+// 1. 要求された言語（requested）の翻訳があればそれを使う
+// 2. なければデフォルト言語（fallback）の翻訳を使う
+// 3. どちらもなければ name = NULL
+//
+// 💡 fallback には LocaleRepository::find_default() の言語コードを渡す
+
+/// 翻訳の作成・更新用の入力
+#[derive(Debug, Clone)]
+pub struct TopicTranslationInput {
+    pub locale_id: i32,
+    pub name: String,
+}
+
+/// トピック一覧の並び替え項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicSort {
+    Slug,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl SortKey for TopicSort {
+    const FIELDS: &'static [(&'static str, Self)] = &[
+        ("slug", Self::Slug),
+        ("created_at", Self::CreatedAt),
+        ("updated_at", Self::UpdatedAt),
+    ];
+    const DEFAULT: Self = Self::Slug;
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::Slug => "t.slug",
+            Self::CreatedAt => "t.created_at",
+            Self::UpdatedAt => "t.updated_at",
+        }
+    }
+}
+
+// --------------------------------------------------------
+// LOCALIZED_SELECT: 言語解決付きのSELECT
+// --------------------------------------------------------
+//
+// 💡 LEFT JOIN LATERAL:
+// - topics の各行ごとにサブクエリを実行できるJOIN
+// - ここでは「$1 と $2 の翻訳のうち、$1 を優先して1件だけ」取り出す
+// - ORDER BY (l.code = $1) DESC → $1 に一致する行（TRUE）が先頭に来る
+//
+// 💡 LEFT なので翻訳が1つもないトピックも結果に残る（name = NULL）
+const LOCALIZED_SELECT: &str = r#"
+    SELECT
+        t.topic_id, t.slug, t.is_published, t.created_at, t.updated_at,
+        tr.name, tr.locale_code
+    FROM topics t
+    LEFT JOIN LATERAL (
+        SELECT tt.name, l.code AS locale_code
+        FROM topic_translations tt
+        JOIN locales l ON l.locale_id = tt.locale_id
+        WHERE tt.topic_id = t.topic_id
+          AND l.code IN ($1, $2)
+        ORDER BY (l.code = $1) DESC
+        LIMIT 1
+    ) tr ON TRUE
+"#;
+
+pub struct TopicRepository {
+    pool: PgPool,
+}
+
+impl TopicRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // --------------------------------------------------------
+    // find_page_localized: トピック一覧（1ページ分、言語解決済み）
+    // --------------------------------------------------------
+    //
+    // 💡 include_unpublished:
+    // - false: 公開中のトピックのみ（読者向け）
+    // - true : 非公開も含む（管理画面向け）
+    pub async fn find_page_localized(
+        &self,
+        requested: &str,
+        fallback: &str,
+        include_unpublished: bool,
+        params: &ListParams<TopicSort>,
+    ) -> Result<Page<LocalizedTopic>, sqlx::Error> {
+        let sql = format!(
+            "{LOCALIZED_SELECT} WHERE ($3 OR t.is_published) {} LIMIT $4 OFFSET $5",
+            params.order_by("t.topic_id")
+        );
+
+        let topics = sqlx::query_as::<_, LocalizedTopic>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(include_unpublished)
+            .bind(params.fetch_limit())
+            .bind(params.offset())
+            .fetch_all(&self.pool)
+            .await?;
+
+        let (total,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM topics t WHERE ($1 OR t.is_published)")
+                .bind(include_unpublished)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(params.finish_offset(topics, total))
+    }
+
+    // --------------------------------------------------------
+    // find_by_slug_localized: slugで1件取得（言語解決済み）
+    // --------------------------------------------------------
+    pub async fn find_by_slug_localized(
+        &self,
+        slug: &str,
+        requested: &str,
+        fallback: &str,
+    ) -> Result<Option<LocalizedTopic>, sqlx::Error> {
+        let sql = format!("{LOCALIZED_SELECT} WHERE t.slug = $3");
+
+        let topic = sqlx::query_as::<_, LocalizedTopic>(&sql)
+            .bind(requested)
+            .bind(fallback)
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(topic)
+    }
+
+    // --------------------------------------------------------
+    // find_by_slug: slugで1件取得（翻訳なし）
+    // --------------------------------------------------------
+    pub async fn find_by_slug(&self, slug: &str) -> Result<Option<Topic>, sqlx::Error> {
+        let topic = sqlx::query_as::<_, Topic>("SELECT * FROM topics WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(topic)
+    }
+
+    // --------------------------------------------------------
+    // find_translations: トピックの全翻訳
+    // --------------------------------------------------------
+    //
+    // 💡 JOIN locales で言語コードも一緒に取得する
+    pub async fn find_translations(&self, topic_id: i32) -> Result<Vec<TopicTranslation>, sqlx::Error> {
+        let translations = sqlx::query_as::<_, TopicTranslation>(
+            r#"
+            SELECT tt.translation_id, tt.topic_id, tt.locale_id, l.code AS locale_code,
+                   tt.name, tt.created_at, tt.updated_at
+            FROM topic_translations tt
+            JOIN locales l ON l.locale_id = tt.locale_id
+            WHERE tt.topic_id = $1
+            ORDER BY l.locale_id ASC
+            "#
+        )
+        .bind(topic_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(translations)
+    }
+
+    // --------------------------------------------------------
+    // create: トピックと翻訳をまとめて作成（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 なぜトランザクション?
+    // - 親（topics）だけ作成されて翻訳が0件、という中途半端な状態を防ぐ
+    pub async fn create(
+        &self,
+        slug: &str,
+        is_published: bool,
+        created_by: Option<i32>,
+        translations: &[TopicTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<Topic, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let topic = sqlx::query_as::<_, Topic>(
+            "INSERT INTO topics (slug, is_published, created_by) VALUES ($1, $2, $3) RETURNING *"
+        )
+        .bind(slug)
+        .bind(is_published)
+        .bind(created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_translation(&mut tx, topic.topic_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Topics, topic.topic_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Topics,
+            topic.topic_id,
+            AuditAction::Insert,
+            None,
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(topic)
+    }
+
+    // --------------------------------------------------------
+    // update: トピックの部分更新 + 翻訳のUPSERT（トランザクション）
+    // --------------------------------------------------------
+    //
+    // 💡 translations に含まれる言語:
+    // - 翻訳がなければ INSERT、あれば name を UPDATE
+    // - 含まれない言語の翻訳はそのまま残る
+    //
+    // 💡 戻り値:
+    // - Ok(None): 該当するトピックがない
+    pub async fn update(
+        &self,
+        topic_id: i32,
+        slug: Option<&str>,
+        is_published: Option<bool>,
+        translations: &[TopicTranslationInput],
+        audit: &AuditContext,
+    ) -> Result<Option<Topic>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let Some(old) = audit_log_repository::snapshot(&mut tx, AuditTable::Topics, topic_id).await? else {
+            tx.rollback().await?;
+            return Ok(None);
+        };
+
+        let topic = sqlx::query_as::<_, Topic>(
+            r#"
+            UPDATE topics
+            SET slug = COALESCE($2, slug),
+                is_published = COALESCE($3, is_published),
+                updated_at = NOW()
+            WHERE topic_id = $1
+            RETURNING *
+            "#
+        )
+        .bind(topic_id)
+        .bind(slug)
+        .bind(is_published)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for translation in translations {
+            upsert_translation(&mut tx, topic.topic_id, translation).await?;
+        }
+
+        let new = audit_log_repository::snapshot(&mut tx, AuditTable::Topics, topic_id).await?;
+        audit_log_repository::record(
+            &mut tx,
+            audit,
+            AuditTable::Topics,
+            topic_id,
+            AuditAction::Update,
+            Some(old),
+            new,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(topic))
+    }
+}
+
+// --------------------------------------------------------
+// upsert_translation: 翻訳の INSERT or UPDATE
+// --------------------------------------------------------
+//
+// 💡 ON CONFLICT (topic_id, locale_id) DO UPDATE:
+// - UNIQUE(topic_id, locale_id) に違反する = 既に翻訳がある
+// - その場合は INSERT の代わりに UPDATE する（UPSERT）
+// - EXCLUDED は「INSERTしようとした値」を表す
+async fn upsert_translation(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    topic_id: i32,
+    translation: &TopicTranslationInput,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO topic_translations (topic_id, locale_id, name)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (topic_id, locale_id)
+        DO UPDATE SET name = EXCLUDED.name, updated_at = NOW()
+        "#
+    )
+    .bind(topic_id)
+    .bind(translation.locale_id)
+    .bind(&translation.name)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -8,60 +8,218 @@
 // - .route(パス, メソッド(ハンドラー))で登録
 // - .merge()で他のルーターを統合
 
-use axum::{routing::get, Router};
-use sqlx::PgPool;
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{delete, get, patch, post},
+    Router,
+};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{handlers, models::ApiDoc};
+use crate::{handlers, models::ApiDoc, state::AppState};
 
 /// アプリケーション全体のルーターを作成
 /// 
 /// # 引数
-/// なし（State<PgPool>はmain.rsで.with_state()で渡す）
+/// なし（AppStateはmain.rsで.with_state()で渡す）
 /// 
 /// # 戻り値
-/// - `Router<PgPool>`: ルーター（PgPoolをStateとして持つ）
+/// - `Router<AppState>`: ルーター（PgPool・認証設定をStateとして持つ）
 /// 
 /// # ルーティング構成
 /// ```
-/// /health                       → ヘルスチェック
+/// /health                       → ヘルスチェック（/health/live と同じ）
+/// /health/live                  → ライブネスチェック（プロセスのみ）
+/// /health/ready                 → レディネスチェック（DB・接続プール・マイグレーション）
 /// /api/v1/health                → ヘルスチェック（バージョン付き）
 /// /api/v1/hello                 → 挨拶API
 /// /api/v1/hello/custom          → カスタム挨拶API
-/// /api/v1/locales               → 全言語取得
+/// /api/v1/auth/login            → ログイン（POST）
+/// /api/v1/auth/logout           → ログアウト（POST）
+/// /api/v1/auth/me               → ログイン中の管理者
+/// /api/v1/admins                → 管理者作成（POST）
+/// /api/v1/api-keys              → APIキー一覧 / 発行（POST）
+/// /api/v1/api-keys/{id}         → APIキー無効化（DELETE）
+/// /api/v1/audit-logs            → 監査ログ一覧
+/// /api/v1/locales               → 全言語取得 / 言語追加（POST）
 /// /api/v1/locales/active        → 有効な言語のみ取得
-/// /api/v1/locales/{code}        → 特定言語取得
+/// /api/v1/locales/{code}        → 特定言語取得 / 更新（PATCH） / 削除（DELETE）
+/// /api/v1/locales/{code}/default → デフォルト言語に設定（POST）
+/// /api/v1/locales/{code}/impact  → 無効化・削除の影響（参照している行）
+/// /api/v1/locales/{code}/deactivate → 参照を付け替えて無効化（POST）
+/// /api/v1/translation-coverage  → 翻訳の状況（言語ごとの割合・足りない翻訳・古い翻訳）
+/// /api/v1/topics                → トピック一覧 / 作成（POST）
+/// /api/v1/topics/{slug}         → トピック詳細 / 更新（PATCH）
+/// /api/v1/posts                 → 記事一覧 / 作成（POST）
+/// /api/v1/posts/{slug}          → 記事詳細 / 更新（PATCH）
+/// /api/v1/posts/{slug}/translations/{locale}/revisions            → 翻訳の版の一覧
+/// /api/v1/posts/{slug}/translations/{locale}/revisions/diff       → 2つの版の差分（?from=&to=）
+/// /api/v1/posts/{slug}/translations/{locale}/revisions/{revision} → 版の内容
+/// /api/v1/posts/{slug}/translations/{locale}/revisions/{revision}/restore → 古い版に戻す（POST）
+/// /api/v1/scheduled-posts       → 予約公開の一覧
+/// /api/v1/books                 → 本の一覧 / 作成（POST）
+/// /api/v1/books/{slug}          → 本の詳細（目次付き） / 更新（PATCH）
+/// /api/v1/books/{slug}/chapters → チャプター追加（POST）
+/// /api/v1/books/{slug}/chapters/{chapter}       → チャプター更新（PATCH）
+/// /api/v1/books/{slug}/chapters/{chapter}/move  → チャプターの並び替え・入れ子（POST）
+/// /api/v1/books/{slug}/chapters/{chapter}/posts → チャプターの記事一覧
+/// /api/v1/media                 → メディア一覧 / アップロード（POST、multipart）
+/// /api/v1/media/{id}            → メディア詳細 / 翻訳の更新（PATCH） / 削除（DELETE）
+/// /media/{*key}                 → メディアファイル本体
+/// /api/v1/search                → 記事の全文検索
+/// /feeds/{locale}/rss.xml       → 新着記事の RSS 2.0 フィード（atom.xml で Atom）
+/// /feeds/{locale}/topics/{topic}/rss.xml → トピックごとのフィード（atom.xml で Atom）
+/// /sitemap.xml                  → XML サイトマップ（50,000 URL を超えたらサイトマップインデックス）
+/// /sitemaps/{n}.xml             → 分割したサイトマップ
 /// /swagger-ui                   → Swagger UI
 /// /api-docs/openapi.json        → OpenAPI仕様
 /// ```
-pub fn create_router() -> Router<PgPool> {
+pub fn create_router() -> Router<AppState> {
     Router::new()
         // ルートレベル（Docker用）
         .route("/health", get(handlers::health::health_check))
+        .route("/health/live", get(handlers::health::liveness))
+        .route("/health/ready", get(handlers::health::readiness))
         
         // API v1 - Health & Greeting
         .route("/api/v1/health", get(handlers::health::health_check))
         .route("/api/v1/hello", get(handlers::greeting::hello_rust))
         .route("/api/v1/hello/custom", get(handlers::greeting::custom_hello))
         
+        // API v1 - Auth (ログイン・管理者)
+        .route("/api/v1/auth/login", post(handlers::auth::login))
+        .route("/api/v1/auth/logout", post(handlers::auth::logout))
+        .route("/api/v1/auth/me", get(handlers::auth::me))
+        .route("/api/v1/admins", post(handlers::admins::create_admin))
+        .route(
+            "/api/v1/api-keys",
+            get(handlers::api_keys::list_api_keys).post(handlers::api_keys::create_api_key),
+        )
+        .route("/api/v1/api-keys/{id}", delete(handlers::api_keys::revoke_api_key))
+        .route("/api/v1/audit-logs", get(handlers::audit_logs::list_audit_logs))
+
         // API v1 - Locales (言語情報) ← NEW!
-        .route("/api/v1/locales", get(handlers::locales::list_locales))
+        .route(
+            "/api/v1/locales",
+            get(handlers::locales::list_locales).post(handlers::locales::create_locale),
+        )
         .route("/api/v1/locales/active", get(handlers::locales::list_active_locales))
-        .route("/api/v1/locales/{code}", get(handlers::locales::get_locale_by_code))
+        .route(
+            "/api/v1/locales/{code}",
+            get(handlers::locales::get_locale_by_code)
+                .patch(handlers::locales::update_locale)
+                .delete(handlers::locales::delete_locale),
+        )
+        .route("/api/v1/locales/{code}/default", post(handlers::locales::set_default_locale))
+        .route("/api/v1/locales/{code}/impact", get(handlers::locales::get_locale_impact))
+        .route("/api/v1/locales/{code}/deactivate", post(handlers::locales::deactivate_locale))
+        .route(
+            "/api/v1/translation-coverage",
+            get(handlers::translation_coverage::get_translation_coverage),
+        )
+
+        // API v1 - Topics (技術トピック)
+        .route(
+            "/api/v1/topics",
+            get(handlers::topics::list_topics).post(handlers::topics::create_topic),
+        )
+        .route(
+            "/api/v1/topics/{slug}",
+            get(handlers::topics::get_topic).patch(handlers::topics::update_topic),
+        )
+
+        // API v1 - Posts (ブログ記事)
+        .route(
+            "/api/v1/posts",
+            get(handlers::posts::list_posts).post(handlers::posts::create_post),
+        )
+        .route(
+            "/api/v1/posts/{slug}",
+            get(handlers::posts::get_post).patch(handlers::posts::update_post),
+        )
+        .route(
+            "/api/v1/posts/{slug}/translations/{locale}/revisions",
+            get(handlers::post_revisions::list_revisions),
+        )
+        .route(
+            "/api/v1/posts/{slug}/translations/{locale}/revisions/diff",
+            get(handlers::post_revisions::diff_revisions),
+        )
+        .route(
+            "/api/v1/posts/{slug}/translations/{locale}/revisions/{revision}",
+            get(handlers::post_revisions::get_revision),
+        )
+        .route(
+            "/api/v1/posts/{slug}/translations/{locale}/revisions/{revision}/restore",
+            post(handlers::post_revisions::restore_revision),
+        )
+        .route("/api/v1/scheduled-posts", get(handlers::posts::list_scheduled_posts))
+
+        // API v1 - Books (本・チャプター)
+        .route(
+            "/api/v1/books",
+            get(handlers::books::list_books).post(handlers::books::create_book),
+        )
+        .route(
+            "/api/v1/books/{slug}",
+            get(handlers::books::get_book).patch(handlers::books::update_book),
+        )
+        .route("/api/v1/books/{slug}/chapters", post(handlers::books::create_chapter))
+        .route(
+            "/api/v1/books/{slug}/chapters/{chapter}",
+            patch(handlers::books::update_chapter),
+        )
+        .route(
+            "/api/v1/books/{slug}/chapters/{chapter}/move",
+            post(handlers::books::move_chapter),
+        )
+        .route(
+            "/api/v1/books/{slug}/chapters/{chapter}/posts",
+            get(handlers::books::list_chapter_posts),
+        )
+
+        // API v1 - Media (メディアライブラリ)
+        // 💡 アップロードは axum のボディ上限（2MB）を外し、ハンドラーで media.max_upload_bytes を確認する
+        .route(
+            "/api/v1/media",
+            get(handlers::media::list_media)
+                .post(handlers::media::upload_media)
+                .layer(DefaultBodyLimit::disable()),
+        )
+        .route(
+            "/api/v1/media/{id}",
+            get(handlers::media::get_media)
+                .patch(handlers::media::update_media)
+                .delete(handlers::media::delete_media),
+        )
+        .route("/media/{*key}", get(handlers::media::serve_media))
+
+        // API v1 - Search (記事の全文検索)
+        .route("/api/v1/search", get(handlers::search::search_posts))
+
+        // フィード（RSS 2.0 / Atom）
+        .route("/feeds/{locale}/rss.xml", get(handlers::feeds::locale_rss))
+        .route("/feeds/{locale}/atom.xml", get(handlers::feeds::locale_atom))
+        .route("/feeds/{locale}/topics/{topic}/rss.xml", get(handlers::feeds::topic_rss))
+        .route("/feeds/{locale}/topics/{topic}/atom.xml", get(handlers::feeds::topic_atom))
+        .route("/sitemap.xml", get(handlers::sitemap::get_sitemap))
+        .route("/sitemaps/{file}", get(handlers::sitemap::get_sitemap_part))
         
         // Swagger UI
         .merge(SwaggerUi::new("/swagger-ui")
             .url("/api-docs/openapi.json", ApiDoc::openapi()))
+
+        // どのルートにも一致しない場合も共通のエラーJSONを返す
+        .fallback(handlers::fallback::not_found)
 }
 
 // ============================================
 // 💡 用語解説
 // ============================================
 // 
-// Router<PgPool>
-//   → PgPool（データベース接続プール）を状態として持つルーター
-//   → ハンドラーでState<PgPool>として取得できる
+// Router<AppState>
+//   → AppState（データベース接続プール・認証設定）を状態として持つルーター
+//   → ハンドラーでState<PgPool>・State<AuthConfig>として個別に取得できる
 // 
 // .route(パス, メソッド(ハンドラー))
 //   → URLパスとHTTPメソッド、ハンドラー関数を紐付け
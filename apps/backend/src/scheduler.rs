@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tracing::{error, info};
+
+use crate::{
+    config::SchedulerConfig,
+    repositories::{AuditContext, PostRepository},
+};
+
+// ============================================
+// Scheduler（予約公開のバックグラウンド処理）
+// ============================================
+//
+// 💡 流れ:
+// 1. interval_secs ごとに、公開予定の日時（scheduled_at）を過ぎた下書きを探す
+// 2. is_published = TRUE にし、published_at に予約していた日時を入れる
+// 3. 1件ずつ監査ログを残す（changed_via = 'scheduler'）
+//
+// 💡 サーバーを複数台で動かしても安全:
+// - PostRepository::publish_due が FOR UPDATE SKIP LOCKED で行をロックする
+// - 同じ記事を2台が同時に公開することはない
+//
+// 💡 エラーが起きても止まらない（ログに出して次の確認で再試行）
+
+// --------------------------------------------------------
+// spawn: scheduler をバックグラウンドで開始
+// --------------------------------------------------------
+pub fn spawn(pool: PgPool, config: &SchedulerConfig) {
+    if !config.enabled {
+        info!("⏰ Scheduler: disabled");
+        return;
+    }
+
+    info!(
+        "⏰ Scheduler: every {}s (up to {} posts)",
+        config.interval.as_secs(),
+        config.batch_size
+    );
+    tokio::spawn(run(PostRepository::new(pool), config.interval, config.batch_size));
+}
+
+async fn run(repo: PostRepository, interval: Duration, batch_size: i64) {
+    let mut ticker = tokio::time::interval(interval);
+    // 💡 処理が間隔より長引いても、溜まった分をまとめて実行しない
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        publish_due(&repo, batch_size).await;
+    }
+}
+
+/// 公開予定の日時を過ぎた記事を公開する（batch_size 件ずつ、残りがなくなるまで）
+async fn publish_due(repo: &PostRepository, batch_size: i64) {
+    loop {
+        match repo.publish_due(batch_size, &AuditContext::scheduler()).await {
+            Ok(posts) => {
+                for post in &posts {
+                    info!("📢 Published scheduled post: {} (id: {})", post.slug, post.post_id);
+                }
+                if (posts.len() as i64) < batch_size {
+                    return;
+                }
+            }
+            Err(e) => {
+                error!("❌ Scheduler failed to publish due posts: {}", e);
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,363 @@
+// ============================================
+// Search（記事の全文検索）
+// ============================================
+//
+// 💡 PostgreSQL の全文検索（tsvector / tsquery）を使う
+// - blog_post_translations.search_vector にタイトル・要約・本文を入れておく（GINインデックス）
+// - 重み: タイトル A > 要約 B > 本文 C（ts_rank_cd の順位に効く）
+//
+// 💡 言語ごとの分け方（locales.code の最初の部分で決める）
+// | 言語         | 分け方                       | 例: 検索語 → tsquery              |
+// |--------------|------------------------------|-----------------------------------|
+// | en, de, fr … | 単語 + 語幹（english など）  | "running" → 'run'                 |
+// | ja, zh       | 2文字ずつ（バイグラム）      | "所有権" → '所有' <-> '有権'      |
+// | その他       | 単語（simple、語幹なし）     | "tietokanta" → 'tietokanta'       |
+//
+// 💡 なぜ日本語はバイグラム?
+// - 日本語は単語の間に空白がないので、PostgreSQL のパーサーでは単語に分けられない
+//   （データベースの LC_CTYPE が C だと、日本語の文字はそもそも捨てられる）
+// - 2文字ずつに区切った tsvector をここで作り、パーサーを通さずにそのまま保存する
+// - 検索語も2文字ずつに区切り、<->（隣り合う）でつなぐ → 文字列が連続して現れる記事だけが一致
+//
+// 💡 抜粋のハイライト（snippet）もここで作る（バイグラムの言語では ts_headline が使えないため）
+
+use std::collections::BTreeMap;
+
+/// tsvector の位置の最大値（PostgreSQL の制限）
+const MAX_POSITION: usize = 16383;
+
+/// 1つの語に記録する位置の最大数（PostgreSQL の制限）
+const MAX_POSITIONS_PER_LEXEME: usize = 256;
+
+/// 抜粋の文字数
+const SNIPPET_CHARS: usize = 160;
+
+/// 抜粋で、最初に一致した位置より前に残す文字数
+const SNIPPET_CONTEXT: usize = 40;
+
+// --------------------------------------------------------
+// Analyzer: 言語ごとの分け方
+// --------------------------------------------------------
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Analyzer {
+    /// PostgreSQL のテキスト検索設定（regconfig）で単語に分ける
+    Words(&'static str),
+    /// 2文字ずつに分ける
+    Bigrams,
+}
+
+impl Analyzer {
+    pub fn for_locale(locale_code: &str) -> Self {
+        let language = locale_code
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "ja" | "zh" => Self::Bigrams,
+            "en" => Self::Words("english"),
+            "de" => Self::Words("german"),
+            "fr" => Self::Words("french"),
+            "es" => Self::Words("spanish"),
+            "it" => Self::Words("italian"),
+            "pt" => Self::Words("portuguese"),
+            "nl" => Self::Words("dutch"),
+            "ru" => Self::Words("russian"),
+            "sv" => Self::Words("swedish"),
+            "tr" => Self::Words("turkish"),
+            _ => Self::Words("simple"),
+        }
+    }
+
+    /// SQL に渡すテキスト検索設定（バイグラムでは使わないが、型を揃えるため simple）
+    pub fn config(self) -> &'static str {
+        match self {
+            Self::Words(config) => config,
+            Self::Bigrams => "simple",
+        }
+    }
+}
+
+// --------------------------------------------------------
+// SearchDocument: 翻訳を保存するときの search_vector の材料
+// --------------------------------------------------------
+//
+// 💡 Words: SQL の to_tsvector(config, ...) で作る（vector は None）
+// 💡 Bigrams: ここで tsvector の文字列（'所有':1A '有権':2A ...）を作る
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchDocument {
+    pub config: &'static str,
+    pub vector: Option<String>,
+    /// 本文のプレーンテキスト（Markdown の記号を除いたもの）
+    pub content_text: String,
+}
+
+impl SearchDocument {
+    pub fn new(locale_code: &str, title: &str, summary: Option<&str>, content_text: String) -> Self {
+        let analyzer = Analyzer::for_locale(locale_code);
+        let vector = match analyzer {
+            Analyzer::Words(_) => None,
+            Analyzer::Bigrams => Some(bigram_vector(&[
+                (title, 'A'),
+                (summary.unwrap_or_default(), 'B'),
+                (&content_text, 'C'),
+            ])),
+        };
+        Self {
+            config: analyzer.config(),
+            vector,
+            content_text,
+        }
+    }
+}
+
+/// 文字・数字の連続ごとに、2文字ずつの語にする（1文字だけの連続はそのまま）
+///   "Rustの所有権" → ["ru", "us", "st", "tの", "の所", "所有", "有権"]
+fn bigrams(text: &str) -> Vec<Vec<String>> {
+    let mut runs = Vec::new();
+    let mut current: Vec<char> = Vec::new();
+    for c in text.chars().chain(std::iter::once(' ')) {
+        if c.is_alphanumeric() {
+            current.extend(c.to_lowercase());
+            continue;
+        }
+        match current.len() {
+            0 => {}
+            1 => runs.push(vec![current[0].to_string()]),
+            _ => runs.push(current.windows(2).map(|pair| pair.iter().collect()).collect()),
+        }
+        current.clear();
+    }
+    runs
+}
+
+/// 重み付きのテキスト → tsvector の文字列
+fn bigram_vector(parts: &[(&str, char)]) -> String {
+    let mut positions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut position = 0usize;
+
+    for (text, weight) in parts {
+        for token in bigrams(text).into_iter().flatten() {
+            position = (position + 1).min(MAX_POSITION);
+            let entry = positions.entry(token).or_default();
+            if entry.len() < MAX_POSITIONS_PER_LEXEME {
+                entry.push(format!("{}{}", position, weight));
+            }
+        }
+    }
+
+    // 💡 語は文字・数字だけなので ' や \ のエスケープは要らない
+    positions
+        .into_iter()
+        .map(|(token, positions)| format!("'{}':{}", token, positions.join(",")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// --------------------------------------------------------
+// SearchQuery: 検索語 → tsquery
+// --------------------------------------------------------
+//
+// 💡 Words: websearch_to_tsquery に検索語をそのまま渡す（"フレーズ"・-除外・OR が使える）
+// 💡 Bigrams: ここで tsquery の文字列を作る（語はすべて AND、1文字の語は前方一致）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub analyzer: Analyzer,
+    /// Words なら検索語、Bigrams なら tsquery の文字列
+    pub query: String,
+    /// 抜粋でハイライトする語（小文字）
+    pub terms: Vec<String>,
+}
+
+impl SearchQuery {
+    /// 検索できる語がなければ None
+    pub fn parse(q: &str, locale_code: &str) -> Option<Self> {
+        let terms = highlight_terms(q);
+        if terms.is_empty() {
+            return None;
+        }
+
+        let analyzer = Analyzer::for_locale(locale_code);
+        let query = match analyzer {
+            Analyzer::Words(_) => q.trim().to_string(),
+            Analyzer::Bigrams => terms
+                .iter()
+                .flat_map(|term| bigrams(term))
+                .map(|run| match run.as_slice() {
+                    [single] if single.chars().count() == 1 => format!("'{}':*", single),
+                    _ => run
+                        .iter()
+                        .map(|token| format!("'{}'", token))
+                        .collect::<Vec<_>>()
+                        .join(" <-> "),
+                })
+                .collect::<Vec<_>>()
+                .join(" & "),
+        };
+
+        Some(Self {
+            analyzer,
+            query,
+            terms,
+        })
+    }
+
+    /// SQL の tsquery 式（$config, $query はプレースホルダー）
+    pub fn tsquery_sql(&self, config: &str, query: &str) -> String {
+        match self.analyzer {
+            Analyzer::Words(_) => format!("websearch_to_tsquery({}::regconfig, {})", config, query),
+            Analyzer::Bigrams => format!("{}::tsquery", query),
+        }
+    }
+}
+
+/// 検索語 → ハイライトする語（除外の -語・OR・記号は使わない）
+fn highlight_terms(q: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in q.split_whitespace() {
+        if word.starts_with('-') || word == "OR" || word.eq_ignore_ascii_case("or") {
+            continue;
+        }
+        let term = word
+            .trim_matches(|c: char| !c.is_alphanumeric())
+            .to_lowercase();
+        if !term.is_empty() && !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms
+}
+
+// --------------------------------------------------------
+// snippet: 本文から一致した部分の前後を切り出し、<mark> で囲む
+// --------------------------------------------------------
+//
+// 💡 返す値はHTML（本文はエスケープ済み、タグは <mark> だけ）
+// 💡 単語で分ける言語は「語の先頭」から一致を探し、語の終わりまでを囲む
+//   検索語 "run" → "<mark>running</mark>"（語幹で一致した語も目立たせる）
+// 💡 一致がなければ本文の先頭を返す（タイトル・要約だけが一致した場合）
+pub fn snippet(text: &str, query: &SearchQuery) -> String {
+    let chars: Vec<char> = text.split_whitespace().collect::<Vec<_>>().join(" ").chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let whole_words = matches!(query.analyzer, Analyzer::Words(_));
+
+    let mut matches: Vec<(usize, usize)> = Vec::new();
+    let mut i = 0;
+    while i < lower.len() {
+        let at_word_start = i == 0 || !lower[i - 1].is_alphanumeric();
+        let found = query.terms.iter().find_map(|term| {
+            let term: Vec<char> = term.chars().collect();
+            let matched = lower[i..].starts_with(&term) && (!whole_words || at_word_start);
+            matched.then_some(term.len())
+        });
+        match found {
+            Some(len) => {
+                let mut end = i + len;
+                if whole_words {
+                    while end < lower.len() && lower[end].is_alphanumeric() {
+                        end += 1;
+                    }
+                }
+                matches.push((i, end));
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    let start = matches
+        .first()
+        .map_or(0, |(first, _)| first.saturating_sub(SNIPPET_CONTEXT));
+    let end = (start + SNIPPET_CHARS).min(chars.len());
+
+    let mut html = String::new();
+    if start > 0 {
+        html.push('…');
+    }
+    let mut pos = start;
+    for &(match_start, match_end) in &matches {
+        if match_start >= end {
+            break;
+        }
+        if match_start < start {
+            continue;
+        }
+        push_escaped(&mut html, &chars[pos..match_start]);
+        html.push_str("<mark>");
+        push_escaped(&mut html, &chars[match_start..match_end.min(end)]);
+        html.push_str("</mark>");
+        pos = match_end.min(end);
+    }
+    push_escaped(&mut html, &chars[pos..end]);
+    if end < chars.len() {
+        html.push('…');
+    }
+    html
+}
+
+fn push_escaped(html: &mut String, chars: &[char]) {
+    for c in chars {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            _ => html.push(*c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyzer_for_locale() {
+        assert_eq!(Analyzer::for_locale("ja"), Analyzer::Bigrams);
+        assert_eq!(Analyzer::for_locale("zh-TW"), Analyzer::Bigrams);
+        assert_eq!(Analyzer::for_locale("en"), Analyzer::Words("english"));
+        assert_eq!(Analyzer::for_locale("fi"), Analyzer::Words("simple"));
+    }
+
+    #[test]
+    fn test_bigram_vector() {
+        let vector = bigram_vector(&[("所有権", 'A'), ("所有", 'C')]);
+        assert_eq!(vector, "'所有':1A,3C '有権':2A");
+        // 1文字だけの語はそのまま、記号は区切り
+        assert_eq!(bigram_vector(&[("C と Go", 'C')]), "'c':1C 'go':3C 'と':2C");
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let query = SearchQuery::parse("Rustの所有権 型", "ja").unwrap();
+        assert_eq!(
+            query.query,
+            "'ru' <-> 'us' <-> 'st' <-> 'tの' <-> 'の所' <-> '所有' <-> '有権' & '型':*"
+        );
+
+        let query = SearchQuery::parse("\"borrow checker\" -unsafe", "en").unwrap();
+        assert_eq!(query.query, "\"borrow checker\" -unsafe");
+        assert_eq!(query.terms, ["borrow", "checker"]);
+
+        assert!(SearchQuery::parse("  --- ", "en").is_none());
+    }
+
+    #[test]
+    fn test_snippet() {
+        let query = SearchQuery::parse("run", "en").unwrap();
+        let text = "Some <b> words. The runner is running, not rerun.";
+        assert_eq!(
+            snippet(text, &query),
+            "Some &lt;b&gt; words. The <mark>runner</mark> is <mark>running</mark>, not rerun."
+        );
+
+        let query = SearchQuery::parse("所有権", "ja").unwrap();
+        let text = format!("{}Rustの所有権とは", "あ".repeat(100));
+        let html = snippet(&text, &query);
+        assert!(html.starts_with('…'));
+        assert!(html.contains("Rustの<mark>所有権</mark>とは"));
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::feed::escape;
+
+// ============================================
+// XML サイトマップの組み立て
+// ============================================
+//
+// 💡 1つのページ（記事・本）の翻訳ごとに1つの <url> を出す
+// - ja の記事と en の記事は別のURL（/ja/blog/... と /en/blog/...）
+// - それぞれに同じページの全翻訳を <xhtml:link rel="alternate" hreflang="..."> で並べる（自分自身も含む）
+// - デフォルト言語の翻訳があれば hreflang="x-default" としても並べる
+//
+// 💡 1つのサイトマップに入れられるのは 50,000 URL まで（sitemaps.org のプロトコル）
+// - 超える場合はサイトマップインデックス（<sitemapindex>）から分割したサイトマップを参照する
+
+/// 1つのサイトマップに入れられるURLの最大数
+pub const MAX_URLS_PER_SITEMAP: usize = 50_000;
+
+/// サイトマップに載せる1ページ（翻訳をまとめたもの）
+#[derive(Debug, Clone)]
+pub struct SitemapPage {
+    /// 言語コードより後ろのパス（例: blog/rust-ownership）
+    pub path: String,
+    pub translations: Vec<PageTranslation>,
+}
+
+/// ページの1つの言語の翻訳
+#[derive(Debug, Clone)]
+pub struct PageTranslation {
+    pub locale: String,
+    pub lastmod: DateTime<Utc>,
+}
+
+/// サイトマップの <url> 1つ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: DateTime<Utc>,
+    /// (hreflang, URL)
+    pub alternates: Vec<(String, String)>,
+}
+
+// --------------------------------------------------------
+// expand: ページ → 翻訳ごとの <url>
+// --------------------------------------------------------
+pub fn expand(
+    base_url: &str,
+    pages: &[SitemapPage],
+    default_locale: Option<&str>,
+) -> Vec<SitemapUrl> {
+    let mut urls = Vec::new();
+
+    for page in pages {
+        let href = |locale: &str| format!("{}/{}/{}", base_url, locale, page.path);
+
+        let mut alternates: Vec<(String, String)> = page
+            .translations
+            .iter()
+            .map(|tr| (tr.locale.clone(), href(&tr.locale)))
+            .collect();
+        if let Some(default) =
+            default_locale.filter(|code| page.translations.iter().any(|tr| tr.locale == *code))
+        {
+            alternates.push(("x-default".to_string(), href(default)));
+        }
+
+        for tr in &page.translations {
+            urls.push(SitemapUrl {
+                loc: href(&tr.locale),
+                lastmod: tr.lastmod,
+                alternates: alternates.clone(),
+            });
+        }
+    }
+
+    urls
+}
+
+// --------------------------------------------------------
+// urlset: 通常のサイトマップ（<urlset>）
+// --------------------------------------------------------
+pub fn urlset(urls: &[SitemapUrl]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(
+        "<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" \
+         xmlns:xhtml=\"http://www.w3.org/1999/xhtml\">\n",
+    );
+
+    for url in urls {
+        xml.push_str("<url>\n");
+        let _ = writeln!(xml, "<loc>{}</loc>", escape(&url.loc));
+        let _ = writeln!(xml, "<lastmod>{}</lastmod>", w3c_datetime(url.lastmod));
+        for (hreflang, href) in &url.alternates {
+            let _ = writeln!(
+                xml,
+                "<xhtml:link rel=\"alternate\" hreflang=\"{}\" href=\"{}\"/>",
+                escape(hreflang),
+                escape(href)
+            );
+        }
+        xml.push_str("</url>\n");
+    }
+
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+// --------------------------------------------------------
+// index: サイトマップインデックス（<sitemapindex>）
+// --------------------------------------------------------
+//
+// 💡 sitemaps: (分割したサイトマップのURL, その中で最新の lastmod)
+pub fn index(sitemaps: &[(String, DateTime<Utc>)]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n");
+
+    for (loc, lastmod) in sitemaps {
+        xml.push_str("<sitemap>\n");
+        let _ = writeln!(xml, "<loc>{}</loc>", escape(loc));
+        let _ = writeln!(xml, "<lastmod>{}</lastmod>", w3c_datetime(*lastmod));
+        xml.push_str("</sitemap>\n");
+    }
+
+    xml.push_str("</sitemapindex>\n");
+    xml
+}
+
+fn w3c_datetime(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_expand_alternates() {
+        let pages = vec![
+            SitemapPage {
+                path: "blog/rust-ownership".to_string(),
+                translations: vec![
+                    PageTranslation { locale: "en".to_string(), lastmod: at(2) },
+                    PageTranslation { locale: "ja".to_string(), lastmod: at(1) },
+                ],
+            },
+            SitemapPage {
+                path: "blog/english-only".to_string(),
+                translations: vec![PageTranslation { locale: "en".to_string(), lastmod: at(3) }],
+            },
+        ];
+
+        let urls = expand("https://blog.example.com", &pages, Some("ja"));
+        assert_eq!(urls.len(), 3);
+        assert_eq!(urls[0].loc, "https://blog.example.com/en/blog/rust-ownership");
+        assert_eq!(urls[0].lastmod, at(2));
+        assert_eq!(
+            urls[1].alternates,
+            vec![
+                ("en".to_string(), "https://blog.example.com/en/blog/rust-ownership".to_string()),
+                ("ja".to_string(), "https://blog.example.com/ja/blog/rust-ownership".to_string()),
+                ("x-default".to_string(), "https://blog.example.com/ja/blog/rust-ownership".to_string()),
+            ]
+        );
+        // デフォルト言語の翻訳がないページには x-default を付けない
+        assert_eq!(urls[2].alternates.len(), 1);
+    }
+
+    #[test]
+    fn test_urlset_and_index() {
+        let xml = urlset(&[SitemapUrl {
+            loc: "https://blog.example.com/ja/blog/a&b".to_string(),
+            lastmod: at(1),
+            alternates: vec![("x-default".to_string(), "https://blog.example.com/ja/blog/a&b".to_string())],
+        }]);
+        assert!(xml.contains("<loc>https://blog.example.com/ja/blog/a&amp;b</loc>"));
+        assert!(xml.contains("<lastmod>2026-10-01T00:00:00Z</lastmod>"));
+        assert!(xml.contains("hreflang=\"x-default\""));
+
+        let xml = index(&[("https://api.example.com/sitemaps/1.xml".to_string(), at(2))]);
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<sitemap>\n<loc>https://api.example.com/sitemaps/1.xml</loc>"));
+    }
+}
@@ -0,0 +1,40 @@
+// ============================================
+// AppState（アプリケーション共通の状態）
+// ============================================
+// すべてのハンドラーから参照できる状態をまとめる
+//
+// 💡 #[derive(FromRef)] とは?
+// - AppState の各フィールドを State<T> で個別に取り出せるようにする
+// - 既存のハンドラーは State<PgPool> のままで動く
+//
+// 💡 使用例:
+//   pub async fn handler(State(pool): State<PgPool>) -> ... { ... }
+//   pub async fn handler(State(auth): State<AuthConfig>) -> ... { ... }
+//   pub async fn handler(State(storage): State<SharedStorage>) -> ... { ... }
+//   pub async fn handler(State(markdown): State<MarkdownRenderer>) -> ... { ... }
+//   pub async fn handler(State(site): State<SiteConfig>) -> ... { ... }
+
+use axum::extract::FromRef;
+use sqlx::PgPool;
+
+use crate::{
+    config::{AuthConfig, MediaConfig, SiteConfig},
+    markdown::MarkdownRenderer,
+    storage::SharedStorage,
+};
+
+#[derive(Clone, FromRef)]
+pub struct AppState {
+    /// データベース接続プール
+    pub pool: PgPool,
+    /// 認証（セッション）の設定
+    pub auth: AuthConfig,
+    /// メディア（アップロード）の設定
+    pub media: MediaConfig,
+    /// メディアファイルの保存先（今はローカルディスク）
+    pub storage: SharedStorage,
+    /// 本文の Markdown → HTML 変換（結果をキャッシュする）
+    pub markdown: MarkdownRenderer,
+    /// 公開サイトのURL・サイト名（フィード・サイトマップ）
+    pub site: SiteConfig,
+}
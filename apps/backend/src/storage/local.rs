@@ -0,0 +1,80 @@
+use std::{io::ErrorKind, path::PathBuf};
+
+use anyhow::Context;
+use async_trait::async_trait;
+
+use super::{is_valid_key, MediaStorage};
+
+// ============================================
+// LocalStorage（ローカルディスクに保存）
+// ============================================
+//
+// 💡 保存先: {root}/{key}（例: uploads/2026/10/3f2a....png）
+// 💡 公開URL: {public_base_url}/{key}（例: /media/2026/10/3f2a....png）
+//    → GET /media/{*key}（handlers::media::serve_media）がこのディレクトリから返す
+//
+// 💡 書き込みは一時ファイル → rename
+// - 書き込み途中のファイルが読まれたり、失敗して壊れたファイルが残ったりしないようにする
+pub struct LocalStorage {
+    root: PathBuf,
+    public_base_url: String,
+}
+
+impl LocalStorage {
+    /// 保存先のディレクトリを作成して使えるようにする
+    pub async fn new(root: PathBuf, public_base_url: String) -> anyhow::Result<Self> {
+        tokio::fs::create_dir_all(&root)
+            .await
+            .with_context(|| format!("failed to create media directory {}", root.display()))?;
+
+        Ok(Self {
+            root,
+            public_base_url,
+        })
+    }
+
+    fn path_for(&self, key: &str) -> anyhow::Result<PathBuf> {
+        anyhow::ensure!(is_valid_key(key), "invalid media key '{}'", key);
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl MediaStorage for LocalStorage {
+    async fn put(&self, key: &str, bytes: &[u8], _content_type: &str) -> anyhow::Result<()> {
+        let path = self.path_for(key)?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+
+        let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, bytes)
+            .await
+            .with_context(|| format!("failed to write {}", tmp.display()))?;
+        if let Err(e) = tokio::fs::rename(&tmp, &path).await {
+            let _ = tokio::fs::remove_file(&tmp).await;
+            return Err(e).with_context(|| format!("failed to move file to {}", path.display()));
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> anyhow::Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.path_for(key)?).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> anyhow::Result<()> {
+        match tokio::fs::remove_file(self.path_for(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_base_url, key)
+    }
+}
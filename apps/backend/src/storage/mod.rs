@@ -0,0 +1,99 @@
+// ============================================
+// Storage Module（メディアファイルの保存先）
+// ============================================
+//
+// 💡 MediaStorage トレイトとは?
+// - ファイル本体の保存・取得・削除をまとめたインターフェース
+// - ハンドラーは State<SharedStorage> で受け取り、保存先の種類を意識しない
+//
+// 💡 実装:
+// - LocalStorage: ローカルディスク（media.storage_dir）
+// - 将来: S3互換ストレージ（同じトレイトを実装して AppState の中身を差し替える）
+//
+// 💡 キー（media.file_path）:
+// - 例: 2026/10/3f2a9c1e-....png（アップロードした年/月/UUID.拡張子）
+// - 元のファイル名は使わない（日本語・空白・../ などを含むことがあるため）
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Utc};
+
+pub mod local;
+
+pub use local::LocalStorage;
+
+/// ファイル本体の保存先
+#[async_trait]
+pub trait MediaStorage: Send + Sync {
+    /// キーにファイルを保存する
+    async fn put(&self, key: &str, bytes: &[u8], content_type: &str) -> anyhow::Result<()>;
+
+    /// キーのファイルを読む（なければ None）
+    async fn get(&self, key: &str) -> anyhow::Result<Option<Vec<u8>>>;
+
+    /// キーのファイルを削除する（なければ何もしない）
+    async fn delete(&self, key: &str) -> anyhow::Result<()>;
+
+    /// 読者がアクセスするURL
+    fn public_url(&self, key: &str) -> String;
+}
+
+/// AppState に入れる共有のストレージ
+pub type SharedStorage = Arc<dyn MediaStorage>;
+
+// --------------------------------------------------------
+// new_key: アップロードするファイルのキーを作る
+// --------------------------------------------------------
+pub fn new_key(now: DateTime<Utc>, extension: &str) -> String {
+    format!(
+        "{:04}/{:02}/{}.{}",
+        now.year(),
+        now.month(),
+        uuid::Uuid::new_v4(),
+        extension
+    )
+}
+
+// --------------------------------------------------------
+// is_valid_key: キーとして安全な文字列か
+// --------------------------------------------------------
+//
+// 💡 GET /media/{*key} の値をそのままパスに使うため、
+//    英小文字・数字・「-」「.」だけの区切りに限り、「..」や先頭の「/」を拒否する
+pub fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= 500
+        && key.split('/').all(|segment| {
+            !segment.is_empty()
+                && !segment.starts_with('.')
+                && segment
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_key_is_valid() {
+        let now = "2026-03-05T12:00:00Z".parse().unwrap();
+        let key = new_key(now, "png");
+        assert!(key.starts_with("2026/03/"));
+        assert!(key.ends_with(".png"));
+        assert!(is_valid_key(&key));
+    }
+
+    #[test]
+    fn test_is_valid_key() {
+        assert!(is_valid_key("2026/10/abc-123.webp"));
+        assert!(!is_valid_key(""));
+        assert!(!is_valid_key("/etc/passwd"));
+        assert!(!is_valid_key("2026/../secret.png"));
+        assert!(!is_valid_key("2026//a.png"));
+        assert!(!is_valid_key(".env"));
+        assert!(!is_valid_key("2026/10/A.PNG"));
+    }
+}
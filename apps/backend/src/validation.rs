@@ -0,0 +1,98 @@
+// ============================================
+// 入力チェック（複数のハンドラーで共通）
+// ============================================
+
+/// slug の最大長（topics.slug は VARCHAR(100)）
+pub const MAX_SLUG_LEN: usize = 100;
+
+/// 記事 slug の最大長（blog_posts.slug は VARCHAR(255)）
+pub const MAX_POST_SLUG_LEN: usize = 255;
+
+/// 本・チャプター slug の最大長（books.slug / book_chapters.slug は VARCHAR(255)）
+pub const MAX_BOOK_SLUG_LEN: usize = 255;
+
+// --------------------------------------------------------
+// is_valid_slug: URL用スラッグの形式チェック
+// --------------------------------------------------------
+//
+// 💡 許可する形式:
+// - 小文字英数字とハイフンのみ（例: rust, getting-started-with-rust）
+// - 先頭・末尾のハイフン、連続したハイフンは不可
+pub fn is_valid_slug(slug: &str, max_len: usize) -> bool {
+    !slug.is_empty()
+        && slug.len() <= max_len
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && !slug.contains("--")
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// 管理者ユーザー名の最大長（administrators.username は VARCHAR(100)）
+pub const MAX_USERNAME_LEN: usize = 100;
+
+/// メールアドレスの最大長（administrators.email は VARCHAR(255)）
+pub const MAX_EMAIL_LEN: usize = 255;
+
+// --------------------------------------------------------
+// is_valid_username: 管理者ユーザー名の形式チェック
+// --------------------------------------------------------
+//
+// 💡 英数字・ハイフン・アンダースコア・ドットのみ（3文字以上）
+pub fn is_valid_username(username: &str) -> bool {
+    (3..=MAX_USERNAME_LEN).contains(&username.len())
+        && username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+// --------------------------------------------------------
+// is_valid_email: メールアドレスの簡易チェック
+// --------------------------------------------------------
+//
+// 💡 厳密なRFCチェックはしない（@ の前後が空でなく、ドメインに . がある程度）
+pub fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+    email.len() <= MAX_EMAIL_LEN
+        && !local.is_empty()
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !email.chars().any(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_slug() {
+        assert!(is_valid_slug("rust", MAX_SLUG_LEN));
+        assert!(is_valid_slug("getting-started-with-rust", MAX_SLUG_LEN));
+        assert!(is_valid_slug("web3", MAX_SLUG_LEN));
+
+        assert!(!is_valid_slug("", MAX_SLUG_LEN));
+        assert!(!is_valid_slug("Rust", MAX_SLUG_LEN));
+        assert!(!is_valid_slug("-rust", MAX_SLUG_LEN));
+        assert!(!is_valid_slug("rust-", MAX_SLUG_LEN));
+        assert!(!is_valid_slug("rust--lang", MAX_SLUG_LEN));
+        assert!(!is_valid_slug("rust lang", MAX_SLUG_LEN));
+        assert!(!is_valid_slug("abc", 2));
+    }
+
+    #[test]
+    fn test_is_valid_username_and_email() {
+        assert!(is_valid_username("kohta"));
+        assert!(is_valid_username("kohta_9521.dev"));
+        assert!(!is_valid_username("ko"));
+        assert!(!is_valid_username("kohta san"));
+
+        assert!(is_valid_email("kohta@example.com"));
+        assert!(!is_valid_email("kohta@localhost"));
+        assert!(!is_valid_email("@example.com"));
+        assert!(!is_valid_email("kohta example@example.com"));
+    }
+}
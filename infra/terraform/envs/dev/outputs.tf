@@ -105,7 +105,7 @@ output "deployment_instructions" {
     
     2. データベースマイグレーション実行
        cd apps/backend
-       DATABASE_URL="${module.database.database_url}" sqlx migrate run
+       DATABASE_URL="${module.database.database_url}" cargo run -- migrate up
     
     3. バックエンドのDockerイメージをビルド＆プッシュ
        aws ecr get-login-password --region ${var.aws_region} | docker login --username AWS --password-stdin <ECR_URI>
@@ -120,6 +120,10 @@ resource "aws_ecs_task_definition" "backend" {
           name  = "RUST_LOG"
           value = var.environment == "prod" ? "info" : "debug"
         },
+        {
+          name  = "ENVIRONMENT"
+          value = var.environment
+        },
         {
           name  = "SERVER_HOST"
           value = "0.0.0.0"
@@ -156,7 +160,7 @@ resource "aws_ecs_task_definition" "backend" {
       }
 
       healthCheck = {
-        command     = ["CMD-SHELL", "curl -f http://localhost:${var.container_port}/health || exit 1"]
+        command     = ["CMD-SHELL", "curl -f http://localhost:${var.container_port}/health/live || exit 1"]
         interval    = 30
         timeout     = 5
         retries     = 3
@@ -205,7 +209,7 @@ resource "aws_lb_target_group" "backend" {
     unhealthy_threshold = 3
     timeout             = 5
     interval            = 30
-    path                = "/health"
+    path                = "/health/ready" # DB・マイグレーションまで確認（down なら 503）
     matcher             = "200"
   }
 